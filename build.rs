@@ -0,0 +1,76 @@
+// build.rs
+//
+// Embeds every recording under `assets/langs/<lang>/pronunciation/` into the
+// binary. Writes `$OUT_DIR/audio_registry.rs`, a table of
+// (lang, path under pronunciation/, bytes) that `assets.rs` includes — so a new
+// letter sound is a file drop, not a Rust edit.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const AUDIO_EXTS: [&str; 3] = ["wav", "ogg", "mp3"];
+
+/// Every audio file below `dir`, recursively, sorted for a stable table.
+fn audio_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    // Re-run when a file is added to / removed from any scanned folder.
+    println!("cargo:rerun-if-changed={}", dir.display());
+    let mut paths: Vec<PathBuf> = entries.flatten().map(|e| e.path()).collect();
+    paths.sort();
+    for p in paths {
+        if p.is_dir() {
+            audio_files(&p, out);
+        } else if p
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| AUDIO_EXTS.contains(&e.to_ascii_lowercase().as_str()))
+        {
+            out.push(p);
+        }
+    }
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let langs = manifest_dir.join("assets").join("langs");
+    println!("cargo:rerun-if-changed={}", langs.display());
+
+    let mut lang_dirs: Vec<PathBuf> = fs::read_dir(&langs)
+        .map(|rd| rd.flatten().map(|e| e.path()).filter(|p| p.is_dir()).collect())
+        .unwrap_or_default();
+    lang_dirs.sort();
+
+    let mut rows = Vec::new();
+    for lang_dir in lang_dirs {
+        let lang = lang_dir.file_name().unwrap().to_string_lossy().into_owned();
+        let pron = lang_dir.join("pronunciation");
+        let mut files = Vec::new();
+        audio_files(&pron, &mut files);
+        for file in files {
+            let rel = file
+                .strip_prefix(&pron)
+                .unwrap()
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            // `{:?}` quotes and escapes, so Windows backslashes survive.
+            rows.push(format!(
+                "    ({lang:?}, {rel:?}, include_bytes!({:?})),",
+                file.display().to_string()
+            ));
+        }
+    }
+
+    let out = PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("audio_registry.rs");
+    let mut f = fs::File::create(&out).expect("create audio_registry.rs");
+    writeln!(f, "// @generated by build.rs — one row per file under assets/langs/*/pronunciation/").unwrap();
+    writeln!(f, "pub static EMBEDDED_AUDIO: &[(&str, &str, &[u8])] = &[").unwrap();
+    for row in rows {
+        writeln!(f, "{row}").unwrap();
+    }
+    writeln!(f, "];").unwrap();
+}
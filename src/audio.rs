@@ -179,6 +179,62 @@ fn cleanup_id(id: &str) {
     }
 }
 
+/// Play a letter's recording (`audio` filename from alphabet.json): a runtime
+/// pack's file if one is installed, otherwise the embedded WAV. Returns false
+/// when the language has no recording by that name.
+pub fn play_letter(lang: &str, file: &str, volume: f32) -> bool {
+    if let Some(path) = crate::langpack::letter_audio_path(lang, file) {
+        play_audio(path, volume);
+        true
+    } else if let Some(bytes) = crate::assets::letter_audio_bytes(lang, file) {
+        play_audio_bytes(&format!("{lang}/{file}"), bytes, volume);
+        true
+    } else {
+        false
+    }
+}
+
+/// What `say` started, so the caller can tell when it has finished.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Playback {
+    /// A recorded clip from the pack.
+    Clip(std::path::PathBuf),
+    /// espeak-ng, keyed by its PLAYING_IDS entry.
+    Speech(String),
+    /// No recording and no voice: nothing was played.
+    Silent,
+}
+
+/// Say a word or phrase: its `recording` (a `LexEntry.audio` filename) when
+/// the pack has it, otherwise espeak-ng if `tts` is on.
+pub fn say(lang: &str, recording: Option<&str>, text: &str, volume: f32, tts: bool) -> Playback {
+    if let Some(path) = recording.and_then(|f| crate::langpack::word_audio_path(lang, f)) {
+        play_audio(&path, volume);
+        return Playback::Clip(path);
+    }
+    match speech_id(lang, text).filter(|_| tts) {
+        Some(id) => {
+            speak(lang, text, volume);
+            Playback::Speech(id)
+        }
+        None => Playback::Silent,
+    }
+}
+
+/// Is a `say` still going? TTS stays "playing" through synthesis and a short
+/// tail, so this turns false once the learner has heard the whole thing.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn still_playing(playback: &Playback) -> bool {
+    match playback {
+        Playback::Clip(path) => is_playing(path),
+        Playback::Speech(id) => PLAYING_IDS
+            .lock()
+            .map(|set| set.contains(id))
+            .unwrap_or(false),
+        Playback::Silent => false,
+    }
+}
+
 // ─── ADD TO src/audio.rs ───────────────────────────────────────────────────
 //
 // Cross-platform whole-word TTS via the espeak-ng CLI (Windows + Linux/macOS).
@@ -237,15 +293,10 @@ fn espeak_command() -> std::process::Command {
 
 #[cfg(not(target_arch = "wasm32"))]
 pub fn speak(lang: &str, text: &str, volume: f32) {
-    let Some(voice) = espeak_voice(lang) else {
+    let (Some(voice), Some(id)) = (espeak_voice(lang), speech_id(lang, text)) else {
         return;
     };
     let text = text.trim().to_string();
-    if text.is_empty() {
-        return;
-    }
-
-    let id = format!("tts:{voice}:{text}");
     {
         let mut set = PLAYING_IDS.lock().expect("PLAYING_IDS mutex poisoned");
         if set.contains(&id) {
@@ -287,7 +338,7 @@ pub fn speak(lang: &str, text: &str, volume: f32) {
             cmd.arg("--path").arg(dir);
         }
         cmd.arg("-v")
-            .arg(voice)
+            .arg(&voice)
             .arg("-s")
             .arg("150")
             .arg("-w")
@@ -324,14 +375,19 @@ pub fn speak(lang: &str, text: &str, volume: f32) {
     });
 }
 
-/// App language string -> espeak-ng voice code. Add a line per language.
+/// The PLAYING_IDS key `speak` uses for `text`; `None` when there's no voice
+/// or nothing to say.
 #[cfg(not(target_arch = "wasm32"))]
-fn espeak_voice(lang: &str) -> Option<&'static str> {
-    match lang {
-        "georgian" => Some("ka"),
-        "russian" => Some("ru"),
-        _ => None,
-    }
+fn speech_id(lang: &str, text: &str) -> Option<String> {
+    let voice = espeak_voice(lang)?;
+    let text = text.trim();
+    (!text.is_empty()).then(|| format!("tts:{voice}:{text}"))
+}
+
+/// App language string -> espeak-ng voice code, from the pack's manifest.
+#[cfg(not(target_arch = "wasm32"))]
+fn espeak_voice(lang: &str) -> Option<String> {
+    crate::langpack::manifest(lang).espeak_voice
 }
 
 /// espeak data dir to pass via `--path`: a downloaded copy under our data dir,
@@ -392,6 +448,14 @@ pub fn espeak_present() -> bool {
 pub fn voice_available(_lang: &str) -> bool {
     false
 }
+#[cfg(target_arch = "wasm32")]
+fn speech_id(_lang: &str, _text: &str) -> Option<String> {
+    None
+}
+#[cfg(target_arch = "wasm32")]
+pub fn still_playing(_playback: &Playback) -> bool {
+    false
+}
 // ─── WASM STUBS ───────────────────────────────────────────────────────────────
 
 #[cfg(target_arch = "wasm32")]
@@ -0,0 +1,77 @@
+//! `lang-sprint-fit` — tune the learner model to your own history.
+//!
+//!   lang-sprint-fit [--log PATH] [--out PATH] [--dry-run]
+//!
+//! Replays the archived events (`archive/` beside the log) and then the
+//! evidence log (default: `<data root>/evidence.jsonl`), searches
+//! the `learning::Config` tunables for the lowest prediction log-loss, and
+//! writes the result to `<data root>/learner_config.json`, which the app loads
+//! at startup. `--dry-run` prints the fit without saving.
+
+// The app's pure learner brain, shared by path so fitting and the app fold the
+// log identically. Not every helper (or re-export) is used here.
+#[allow(dead_code, unused_imports)]
+#[path = "../../learning/mod.rs"]
+mod learning;
+#[path = "../../paths.rs"]
+mod paths;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use learning::fit::{fit, MIN_EVENTS};
+use learning::store;
+
+fn main() -> ExitCode {
+    let root = paths::data_root().unwrap_or_default();
+    let mut log_path = root.join(store::LOG_FILE);
+    let mut out_path = root.join(store::CONFIG_FILE);
+    let mut dry_run = false;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(a) = args.next() {
+        match a.as_str() {
+            "--log" => log_path = args.next().map(PathBuf::from).unwrap_or(log_path),
+            "--out" => out_path = args.next().map(PathBuf::from).unwrap_or(out_path),
+            "--dry-run" => dry_run = true,
+            _ => {
+                println!("usage: lang-sprint-fit [--log PATH] [--out PATH] [--dry-run]");
+                return if a == "-h" || a == "--help" { ExitCode::SUCCESS } else { ExitCode::FAILURE };
+            }
+        }
+    }
+
+    let archive = log_path.with_file_name(store::ARCHIVE_DIR);
+    let log = store::load_history(&log_path, &archive);
+    // Start from the current config so the scheduler choice etc. carry over.
+    let start = store::load_config(&out_path).unwrap_or_default();
+    let Some(f) = fit(start, &log) else {
+        eprintln!(
+            "{}: {} events — need at least {MIN_EVENTS} to fit; keeping the current config",
+            log_path.display(),
+            log.len()
+        );
+        return ExitCode::FAILURE;
+    };
+
+    println!("{} events from {}", f.events, log_path.display());
+    println!("log-loss  {:.4} → {:.4}", f.baseline_loss, f.loss);
+    println!("q_per_day      {:>6.3} → {:.3}", start.q_per_day, f.cfg.q_per_day);
+    println!("prior_var      {:>6.3} → {:.3}", start.prior_var, f.cfg.prior_var);
+    println!("floor_margin   {:>6.3} → {:.3}", start.floor_margin, f.cfg.floor_margin);
+    println!("conservative_k {:>6.3} → {:.3}", start.conservative_k, f.cfg.conservative_k);
+
+    if dry_run {
+        return ExitCode::SUCCESS;
+    }
+    match store::save_config(&out_path, &f.cfg) {
+        Ok(()) => {
+            println!("saved {}", out_path.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("couldn't write {}: {e}", out_path.display());
+            ExitCode::FAILURE
+        }
+    }
+}
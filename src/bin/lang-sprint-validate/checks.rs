@@ -0,0 +1,236 @@
+//! The pack checks themselves. Pure: they take file contents (and a probe for
+//! "does this WAV exist") and return issues, so they're testable without a
+//! pack on disk.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::models::letter::Letter;
+use crate::models::lexicon::LexEntry;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Issue {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Issue {
+    fn error(message: impl Into<String>) -> Self {
+        Issue { severity: Severity::Error, message: message.into() }
+    }
+
+    fn warning(message: impl Into<String>) -> Self {
+        Issue { severity: Severity::Warning, message: message.into() }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tag = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{tag}: {}", self.message)
+    }
+}
+
+/// Parse a JSON array element by element, so one bad entry is reported with
+/// its index (and `name_field`, if present) instead of sinking the whole file.
+fn parse_each<T: DeserializeOwned>(json: &str, name_field: &str, issues: &mut Vec<Issue>) -> Vec<T> {
+    let values: Vec<Value> = match serde_json::from_str(json) {
+        Ok(v) => v,
+        Err(e) => {
+            issues.push(Issue::error(format!(
+                "not a JSON array of entries (line {}, column {}): {e}",
+                e.line(),
+                e.column()
+            )));
+            return Vec::new();
+        }
+    };
+    let mut out = Vec::with_capacity(values.len());
+    for (i, v) in values.into_iter().enumerate() {
+        let name = v.get(name_field).and_then(Value::as_str).map(str::to_string);
+        match serde_json::from_value::<T>(v) {
+            Ok(t) => out.push(t),
+            Err(e) => {
+                let what = match name {
+                    Some(n) => format!("entry #{i} (\"{n}\")"),
+                    None => format!("entry #{i}"),
+                };
+                issues.push(Issue::error(format!("{what}: {e}")));
+            }
+        }
+    }
+    out
+}
+
+/// `[3, 4, 5, 9]` → `"3–5, 9"`.
+fn ranges(nums: &[u32]) -> String {
+    let mut parts = Vec::new();
+    let mut i = 0;
+    while i < nums.len() {
+        let start = nums[i];
+        let mut end = start;
+        while i + 1 < nums.len() && nums[i + 1] == end + 1 {
+            end += 1;
+            i += 1;
+        }
+        parts.push(if start == end { start.to_string() } else { format!("{start}–{end}") });
+        i += 1;
+    }
+    parts.join(", ")
+}
+
+/// Check `alphabet.json`: schema, duplicate `key_code`+`shifted` pairs, and
+/// `audio` filenames that `wav_exists` can't find.
+pub fn check_alphabet(json: &str, wav_exists: impl Fn(&str) -> bool) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let letters: Vec<Letter> = parse_each(json, "letter", &mut issues);
+    if letters.is_empty() && issues.is_empty() {
+        issues.push(Issue::error("alphabet is empty"));
+    }
+
+    let mut keys: BTreeMap<(&str, bool), Vec<&str>> = BTreeMap::new();
+    for l in &letters {
+        if l.key_code.trim().is_empty() {
+            issues.push(Issue::warning(format!("\"{}\" has no key_code", l.letter)));
+            continue;
+        }
+        keys.entry((l.key_code.as_str(), l.shifted)).or_default().push(&l.letter);
+    }
+    for ((code, shifted), on_key) in keys {
+        if on_key.len() > 1 {
+            let shift = if shifted { " (shifted)" } else { "" };
+            issues.push(Issue::error(format!(
+                "{code}{shift} is mapped to more than one letter: {}",
+                on_key.join(", ")
+            )));
+        }
+    }
+
+    for l in &letters {
+        if let Some(file) = l.audio.as_deref() {
+            if !wav_exists(file) {
+                issues.push(Issue::error(format!("\"{}\": audio file {file} not found", l.letter)));
+            }
+        }
+    }
+    issues
+}
+
+/// Check `1000.json`: schema, duplicate and missing ranks, and declension
+/// cells filled on one side (singular/plural) only.
+pub fn check_lexicon(json: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let entries: Vec<LexEntry> = parse_each(json, "word", &mut issues);
+
+    let mut by_rank: BTreeMap<u32, Vec<&str>> = BTreeMap::new();
+    for e in &entries {
+        by_rank.entry(e.rank).or_default().push(e.head());
+    }
+    for (rank, words) in &by_rank {
+        if *rank == 0 {
+            issues.push(Issue::error(format!("rank 0 (ranks start at 1): {}", words.join(", "))));
+        } else if words.len() > 1 {
+            issues.push(Issue::error(format!("rank {rank} is used {} times: {}", words.len(), words.join(", "))));
+        }
+    }
+    let present: BTreeSet<u32> = by_rank.keys().copied().collect();
+    if let Some(&max) = present.iter().next_back() {
+        let missing: Vec<u32> = (1..=max).filter(|r| !present.contains(r)).collect();
+        if !missing.is_empty() {
+            issues.push(Issue::warning(format!("missing ranks: {}", ranges(&missing))));
+        }
+    }
+
+    let bare: Vec<&str> = entries
+        .iter()
+        .filter(|e| e.senses.iter().all(|s| s.glosses.is_empty()))
+        .map(|e| e.head())
+        .collect();
+    if !bare.is_empty() {
+        issues.push(Issue::warning(format!("no English meaning: {}", bare.join(", "))));
+    }
+
+    for e in &entries {
+        let Some(d) = &e.declension else {
+            continue;
+        };
+        for (case, f) in &d.cases {
+            // an adjective's singular is split by gender
+            let singular = [&f.singular, &f.masculine, &f.feminine, &f.neuter];
+            let sg = singular.iter().all(|s| s.trim().is_empty());
+            let pl = f.plural.trim().is_empty();
+            if sg != pl {
+                let (has, lacks) = if sg { ("plural", "singular") } else { ("singular", "plural") };
+                issues.push(Issue::warning(format!("\"{}\" {case}: {has} filled but {lacks} empty", e.head())));
+            }
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letter(l: &str, code: &str, shifted: bool, audio: &str) -> String {
+        format!(
+            r#"{{"letter":"{l}","name":"","pron":"","audio":"{audio}","finger":"","key_code":"{code}","shifted":{shifted}}}"#
+        )
+    }
+
+    #[test]
+    fn alphabet_flags_key_clashes_and_missing_audio() {
+        let json = format!(
+            "[{},{},{}]",
+            letter("ა", "KeyA", false, "a.wav"),
+            letter("ბ", "KeyA", false, "b.wav"),
+            letter("ჭ", "KeyA", true, "ch'.wav"),
+        );
+        let issues = check_alphabet(&json, |f| f != "b.wav");
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message.contains("KeyA is mapped"));
+        assert!(issues[1].message.contains("b.wav"));
+    }
+
+    #[test]
+    fn bad_entries_are_reported_by_index_and_name() {
+        let issues = check_alphabet(r#"[{"letter":"ა","name":"ani"}]"#, |_| true);
+        assert_eq!(issues[0].severity, Severity::Error);
+        assert!(issues[0].message.starts_with("entry #0 (\"ა\")"));
+        assert_eq!(check_lexicon("{not json").len(), 1);
+    }
+
+    #[test]
+    fn lexicon_flags_rank_problems_and_one_sided_cells() {
+        let json = r#"[
+            {"rank":1,"en":"man","word":"კაცი",
+             "declension":{"nominative":{"singular":"კაცი","plural":""}}},
+            {"rank":2,"en":"a","word":"x"},
+            {"rank":2,"en":"b","word":"y"},
+            {"rank":5,"en":" ; ","word":"z"}
+        ]"#;
+        let msgs: Vec<String> = check_lexicon(json).into_iter().map(|i| i.message).collect();
+        assert!(msgs.iter().any(|m| m.contains("rank 2 is used 2 times")));
+        assert!(msgs.iter().any(|m| m == "missing ranks: 3–4"));
+        assert!(msgs.iter().any(|m| m.contains("singular filled but plural empty")));
+        assert!(msgs.iter().any(|m| m == "no English meaning: z"));
+    }
+
+    #[test]
+    fn ranges_collapse_runs() {
+        assert_eq!(ranges(&[3, 4, 5, 9]), "3–5, 9");
+        assert_eq!(ranges(&[]), "");
+    }
+}
@@ -0,0 +1,93 @@
+//! `lang-sprint-validate` — check language packs before shipping them.
+//!
+//!   lang-sprint-validate [PACK_DIR ...]
+//!
+//! Each PACK_DIR is a `langs/<code>/` folder. With no arguments, every pack
+//! under `assets/langs/` is checked. Problems are printed per file; the exit
+//! status is non-zero if any pack has an error (warnings alone pass).
+
+// The app's data models, shared by path so the validator and the app can never
+// disagree about the schema. Not every helper is used here.
+#[allow(dead_code)]
+#[path = "../../models/mod.rs"]
+mod models;
+
+mod checks;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use checks::{check_alphabet, check_lexicon, Issue, Severity};
+
+const ALPHABET_FILE: &str = "alphabet.json";
+const LEXICON_FILE: &str = "1000.json";
+const LETTER_AUDIO_DIR: &str = "pronunciation/alphabet";
+
+/// Run every check on one pack; returns `(file, issues)` per checked file.
+fn validate_pack(dir: &Path) -> Vec<(&'static str, Vec<Issue>)> {
+    let mut report = Vec::new();
+
+    let audio_dir = dir.join(LETTER_AUDIO_DIR);
+    let alphabet = match fs::read_to_string(dir.join(ALPHABET_FILE)) {
+        Ok(json) => check_alphabet(&json, |f| audio_dir.join(f).is_file()),
+        Err(e) => vec![Issue { severity: Severity::Error, message: format!("can't read: {e}") }],
+    };
+    report.push((ALPHABET_FILE, alphabet));
+
+    let lexicon = match fs::read_to_string(dir.join(LEXICON_FILE)) {
+        Ok(json) => check_lexicon(&json),
+        Err(e) => vec![Issue { severity: Severity::Warning, message: format!("can't read: {e}") }],
+    };
+    report.push((LEXICON_FILE, lexicon));
+
+    report
+}
+
+/// Pack folders to check when none are given on the command line.
+fn default_packs() -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir("assets/langs")
+        .map(|rd| rd.flatten().map(|e| e.path()).filter(|p| p.is_dir()).collect())
+        .unwrap_or_default();
+    dirs.sort();
+    dirs
+}
+
+fn main() -> ExitCode {
+    let args: Vec<PathBuf> = std::env::args_os().skip(1).map(PathBuf::from).collect();
+    if args.iter().any(|a| a == "-h" || a == "--help") {
+        println!("usage: lang-sprint-validate [PACK_DIR ...]   (default: assets/langs/*)");
+        return ExitCode::SUCCESS;
+    }
+    let packs = if args.is_empty() { default_packs() } else { args };
+    if packs.is_empty() {
+        eprintln!("no packs found (pass a langs/<code>/ folder, or run from the repo root)");
+        return ExitCode::FAILURE;
+    }
+
+    let (mut errors, mut warnings) = (0usize, 0usize);
+    for pack in &packs {
+        println!("{}", pack.display());
+        for (file, issues) in validate_pack(pack) {
+            if issues.is_empty() {
+                println!("  {file}: ok");
+                continue;
+            }
+            println!("  {file}:");
+            for issue in &issues {
+                match issue.severity {
+                    Severity::Error => errors += 1,
+                    Severity::Warning => warnings += 1,
+                }
+                println!("    {issue}");
+            }
+        }
+    }
+
+    println!("{} pack(s) checked: {errors} error(s), {warnings} warning(s)", packs.len());
+    if errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,370 @@
+//! Per-user key calibration: which physical key the learner actually uses for
+//! each letter, and what's printed on their keys. Packs assume a standard
+//! layout (`Letter.key_code`), and the chosen `layouts` profile says what a
+//! stock keyboard of that shape prints; someone on AZERTY / QWERTZ, or on a
+//! non-standard Georgian layout, records the difference here — by pressing
+//! keys in the calibration wizard — and the on-screen keyboard and the typing
+//! hints follow it.
+//!
+//! Saved to `calibrations.json` under the data root. Key labels fall back to
+//! the layout profile, then to the shipped `calibrations/calibrations.json`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::layouts::LayoutProfile;
+use crate::models::letter::Letter;
+
+pub const CALIBRATION_FILE: &str = "calibrations.json";
+
+/// Shipped per-language key labels (KeyCode → label).
+static DEFAULT_LABELS: Lazy<BTreeMap<String, BTreeMap<String, String>>> = Lazy::new(|| {
+    serde_json::from_str(include_str!("../calibrations/calibrations.json")).unwrap_or_else(|e| {
+        eprintln!("[calibration] shipped calibrations.json doesn't parse: {e}");
+        BTreeMap::new()
+    })
+});
+
+/// Where a letter lives on the learner's keyboard.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyBinding {
+    /// `KeyboardEvent.code`, e.g. `"KeyQ"`.
+    pub code: String,
+    #[serde(default)]
+    pub shifted: bool,
+}
+
+/// Everything the learner has calibrated.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Calibrations {
+    /// Printed label per physical key, where it isn't the profile's. One
+    /// keyboard serves every language, so these aren't per language.
+    pub labels: BTreeMap<String, String>,
+    /// language → letter → the key used for it, where it isn't the pack's.
+    pub letters: BTreeMap<String, BTreeMap<String, KeyBinding>>,
+}
+
+impl Calibrations {
+    /// Read the user's calibration, falling back to none if missing/corrupt.
+    pub fn load() -> Self {
+        let Some(s) = calibration_path().and_then(|p| fs::read_to_string(p).ok()) else {
+            return Calibrations::default();
+        };
+        serde_json::from_str(&s).unwrap_or_else(|e| {
+            eprintln!("[calibration] couldn't parse {CALIBRATION_FILE}: {e}");
+            Calibrations::default()
+        })
+    }
+
+    /// Write to disk (best-effort; failures are logged).
+    pub fn save(&self) {
+        let Some(path) = calibration_path() else {
+            return;
+        };
+        let result = serde_json::to_string_pretty(self)
+            .map_err(std::io::Error::other)
+            .and_then(|json| crate::paths::write_atomic(&path, &json));
+        if let Err(e) = result {
+            eprintln!("[calibration] couldn't save {}: {e}", path.display());
+        }
+    }
+
+    /// Record the key used for `glyph`; a binding that matches the pack's
+    /// default is dropped rather than stored. Its other case, where the pack
+    /// puts it on the same key (Russian А over а), moves along with it. A
+    /// letter already on the key it lands on swaps onto the key it left, so
+    /// no key ever types two letters.
+    pub fn bind(&mut self, lang: &str, pack: &[Letter], glyph: &str, key: KeyBinding) {
+        let Some(target) = pack.iter().find(|l| l.letter == glyph) else {
+            return;
+        };
+        let now = self.keymap(lang, "").apply(pack.to_vec());
+        let at = |l: &Letter| KeyBinding {
+            code: l.key_code.clone(),
+            shifted: l.shifted,
+        };
+        let moves: Vec<(usize, KeyBinding)> = pack
+            .iter()
+            .enumerate()
+            .filter_map(|(i, l)| {
+                let same = l.letter == target.letter;
+                let other_case = !same
+                    && l.key_code == target.key_code
+                    && l.letter.to_lowercase() == target.letter.to_lowercase();
+                (same || other_case).then(|| {
+                    let k = KeyBinding {
+                        code: key.code.clone(),
+                        shifted: if same { key.shifted } else { l.shifted },
+                    };
+                    (i, k)
+                })
+            })
+            .collect();
+        let displaced: Vec<(usize, KeyBinding)> = now
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| !moves.iter().any(|(i, _)| i == j))
+            .filter_map(|(j, l)| {
+                let (i, _) = moves.iter().find(|(_, to)| *to == at(l))?;
+                Some((j, at(&now[*i])))
+            })
+            .collect();
+
+        let map = self.letters.entry(lang.to_string()).or_default();
+        for (i, k) in moves.into_iter().chain(displaced) {
+            let l = &pack[i];
+            if k.code == l.key_code && k.shifted == l.shifted {
+                map.remove(&l.letter);
+            } else {
+                map.insert(l.letter.clone(), k);
+            }
+        }
+        if map.is_empty() {
+            self.letters.remove(lang);
+        }
+    }
+
+    /// Forget every letter binding for `lang`.
+    pub fn reset_letters(&mut self, lang: &str) {
+        self.letters.remove(lang);
+    }
+
+    /// Record what's printed on a key; an empty label or the default clears it.
+    pub fn set_label(&mut self, lang: &str, layout: &str, code: &str, label: &str) {
+        let label = label.trim();
+        if label.is_empty() || label == default_label(lang, layout, code) {
+            self.labels.remove(code);
+        } else {
+            self.labels.insert(code.to_string(), label.to_string());
+        }
+    }
+
+    /// The calibrated view for one language on one layout profile.
+    pub fn keymap(&self, lang: &str, layout: &str) -> KeyMap {
+        KeyMap {
+            lang: lang.to_string(),
+            layout: layout.to_string(),
+            labels: self.labels.clone(),
+            letters: self.letters.get(lang).cloned().unwrap_or_default(),
+        }
+    }
+}
+
+/// One language's calibration, ready for rendering.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeyMap {
+    lang: String,
+    layout: String,
+    labels: BTreeMap<String, String>,
+    letters: BTreeMap<String, KeyBinding>,
+}
+
+impl KeyMap {
+    /// The label printed on the learner's physical key `code`.
+    pub fn label(&self, code: &str) -> String {
+        match self.labels.get(code) {
+            Some(l) => l.clone(),
+            None => default_label(&self.lang, &self.layout, code),
+        }
+    }
+
+    /// The pack's letters moved to the keys the learner calibrated.
+    pub fn apply(&self, letters: Vec<Letter>) -> Vec<Letter> {
+        letters
+            .into_iter()
+            .map(|mut l| {
+                if let Some(k) = self.letters.get(&l.letter) {
+                    l.key_code = k.code.clone();
+                    l.shifted = k.shifted;
+                }
+                l
+            })
+            .collect()
+    }
+
+    /// The physical keyboard being drawn.
+    pub fn layout(&self) -> Option<&'static LayoutProfile> {
+        crate::layouts::profile(&self.layout)
+    }
+
+    /// True when any letter or label differs from the defaults.
+    pub fn is_calibrated(&self) -> bool {
+        !self.labels.is_empty() || !self.letters.is_empty()
+    }
+}
+
+/// The profile's label for `code`, else the shipped one for `lang`.
+fn default_label(lang: &str, layout: &str, code: &str) -> String {
+    crate::layouts::profile(layout)
+        .and_then(|p| p.label(code))
+        .map(str::to_string)
+        .or_else(|| DEFAULT_LABELS.get(lang).and_then(|m| m.get(code)).cloned())
+        .unwrap_or_default()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn calibration_path() -> Option<PathBuf> {
+    crate::paths::data_root().map(|d| d.join(CALIBRATION_FILE))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn calibration_path() -> Option<PathBuf> {
+    None
+}
+
+// ─── Dioxus glue ───────────────────────────────────────────────────────────
+
+use dioxus::prelude::*;
+
+/// Call ONCE at the App root. Loads the calibration, provides it via context,
+/// and saves it whenever it changes.
+pub fn provide_calibration() {
+    let cal = use_signal(Calibrations::load);
+    use_context_provider(|| cal);
+    // skip the initial run: nothing has changed yet
+    let mut first = use_signal(|| true);
+    use_effect(move || {
+        let snapshot = cal();
+        if *first.peek() {
+            first.set(false);
+            return;
+        }
+        snapshot.save();
+    });
+}
+
+/// The whole calibration, for the wizard to edit.
+pub fn use_calibrations() -> Signal<Calibrations> {
+    use_context::<Signal<Calibrations>>()
+}
+
+/// The active language's calibration on the learner's keyboard; follows the
+/// language switcher and the layout setting.
+pub fn use_keymap() -> Memo<KeyMap> {
+    let cal = use_calibrations();
+    let lang = use_context::<Signal<String>>();
+    let settings = crate::settings::use_settings();
+    use_memo(move || {
+        cal.read()
+            .keymap(&lang.read(), &settings.read().keyboard_layout)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::letter::LetterKind;
+
+    fn letter(glyph: &str, code: &str) -> Letter {
+        Letter {
+            letter: glyph.into(),
+            name: String::new(),
+            pron: String::new(),
+            kind: LetterKind::Consonant,
+            audio: None,
+            finger: String::new(),
+            key_code: code.into(),
+            shifted: false,
+        }
+    }
+
+    #[test]
+    fn bindings_move_letters_and_defaults_are_not_stored() {
+        let pack = [letter("ა", "KeyA"), letter("ბ", "KeyB")];
+        let key = |code: &str| KeyBinding {
+            code: code.into(),
+            shifted: false,
+        };
+        let mut cal = Calibrations::default();
+        cal.bind("georgian", &pack, "ა", key("KeyQ"));
+        let moved = cal.keymap("georgian", "ansi").apply(pack.to_vec());
+        assert_eq!(moved[0].key_code, "KeyQ");
+        assert_eq!(moved[1].key_code, "KeyB");
+        assert!(!cal.keymap("russian", "ansi").is_calibrated());
+
+        cal.bind("georgian", &pack, "ა", key("KeyA"));
+        assert_eq!(cal, Calibrations::default());
+    }
+
+    #[test]
+    fn a_taken_key_swaps_with_the_letter_moving_onto_it() {
+        let pack = [
+            letter("ა", "KeyA"),
+            letter("ბ", "KeyB"),
+            letter("გ", "KeyG"),
+        ];
+        let key = |code: &str| KeyBinding {
+            code: code.into(),
+            shifted: false,
+        };
+        let codes = |cal: &Calibrations| -> Vec<String> {
+            cal.keymap("georgian", "ansi")
+                .apply(pack.to_vec())
+                .into_iter()
+                .map(|l| l.key_code)
+                .collect()
+        };
+        let mut cal = Calibrations::default();
+        cal.bind("georgian", &pack, "ა", key("KeyB"));
+        assert_eq!(codes(&cal), ["KeyB", "KeyA", "KeyG"]);
+
+        // ბ now sits on KeyA, so it's the one that makes way for გ
+        cal.bind("georgian", &pack, "გ", key("KeyA"));
+        assert_eq!(codes(&cal), ["KeyB", "KeyG", "KeyA"]);
+
+        // a shifted slot is its own place: nothing there, nothing moves
+        cal.bind(
+            "georgian",
+            &pack,
+            "გ",
+            KeyBinding {
+                code: "KeyB".into(),
+                shifted: true,
+            },
+        );
+        assert_eq!(codes(&cal), ["KeyB", "KeyG", "KeyB"]);
+        let shifted = cal.keymap("georgian", "ansi").apply(pack.to_vec())[2].shifted;
+        assert!(shifted);
+    }
+
+    #[test]
+    fn capitals_follow_their_lowercase() {
+        let mut upper = letter("А", "KeyF");
+        upper.shifted = true;
+        let pack = [letter("а", "KeyF"), upper];
+        let mut cal = Calibrations::default();
+        cal.bind(
+            "russian",
+            &pack,
+            "а",
+            KeyBinding {
+                code: "KeyA".into(),
+                shifted: false,
+            },
+        );
+        let moved = cal.keymap("russian", "ansi").apply(pack.to_vec());
+        assert_eq!(
+            (moved[1].key_code.as_str(), moved[1].shifted),
+            ("KeyA", true)
+        );
+    }
+
+    #[test]
+    fn labels_fall_back_to_the_layout_profile() {
+        let mut cal = Calibrations::default();
+        assert_eq!(cal.keymap("georgian", "ansi").label("KeyQ"), "Q");
+        assert_eq!(cal.keymap("klingon", "ansi").label("Semicolon"), ";");
+        assert_eq!(cal.keymap("georgian", "dvorak").label("KeyQ"), "'");
+
+        // AZERTY: the key QWERTY calls Q says A
+        cal.set_label("georgian", "ansi", "KeyQ", "A");
+        assert_eq!(cal.keymap("russian", "ansi").label("KeyQ"), "A");
+        cal.set_label("georgian", "ansi", "KeyQ", "Q");
+        assert!(cal.labels.is_empty());
+    }
+}
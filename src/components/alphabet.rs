@@ -2,8 +2,8 @@ use dioxus::prelude::*;
 use std::time::Duration;
 
 use crate::{
-    assets::letter_audio_bytes,
     audio,
+    langpack::use_manifest,
     models::letter::{Letter, LetterKind},
     settings::use_settings,
 };
@@ -16,6 +16,8 @@ pub fn Alphabet(letters: Vec<Letter>, lang: Signal<String>) -> Element {
     let mut flashing = use_signal(|| None::<String>);
     // Volume now lives in the shared settings store (set from the navbar gear).
     let settings = use_settings();
+    // Packs without letter recordings stay silent on click.
+    let has_audio = use_manifest()().features.audio;
 
     let title = match lang.read().as_str() {
         "georgian" => "ანბანი",
@@ -67,14 +69,12 @@ pub fn Alphabet(letters: Vec<Letter>, lang: Signal<String>) -> Element {
                 class: "{base} {border}",
 
                 onclick: move |_| {
-                    if let Some(file) = audio_file.as_deref() {
+                    if let Some(file) = audio_file.as_deref().filter(|_| has_audio) {
                         let id = format!("{lang_name}/{file}");
-                        if let Some(bytes) = letter_audio_bytes(&lang_name, file) {
-                            #[cfg(not(target_arch = "wasm32"))]
-                            audio::play_audio_bytes(&id, bytes, settings.read().volume);
+                        if audio::play_letter(&lang_name, file, settings.read().volume) {
                             flashing.set(Some(id));
                         } else {
-                            eprintln!("No embedded audio for {lang_name}/{file}");
+                            eprintln!("No audio for {lang_name}/{file}");
                         }
                     }
                 },
@@ -1,15 +1,90 @@
 // src/components/dict_search.rs
 //
 // Persistent navbar search. Reads the active language from context, searches
-// the lexicon (L2 head form + L1 gloss), shows a results dropdown, and on
+// the lexicon's index (`search::Searcher`: L2 head form or its Latin spelling,
+// declension forms, L1 glosses, example sentences; typos forgiven), shows a
+// ranked results dropdown with each word's romanization and the matched words
+// highlighted — under the row when it was a form or an example — and on
 // selection opens the shared WordDetail card (which carries the declension
 // table). Drop `DictSearch {}` anywhere beneath the lang context provider.
 
 use dioxus::prelude::*;
 
-use crate::assets::freq_json_for;
 use crate::components::WordDetail;
-use crate::models::lexicon::{LexEntry, Lexicon};
+use crate::langpack::use_manifest;
+use crate::models::lexicon::LexEntry;
+use crate::models::translit::romanize;
+use crate::search::{Field, Hit, Match, Searcher};
+
+/// A result prepared for rendering: each shown text as highlight runs.
+#[derive(Clone, PartialEq)]
+struct ResultRow {
+    key: String,
+    /// Found through a typo or a guessed inflection, so maybe not what was meant.
+    approx: bool,
+    head: Vec<(String, bool)>,
+    roman: Vec<(String, bool)>,
+    gloss: Vec<(String, bool)>,
+    /// "Genitive · plural" / "example", and the matched text, for a match
+    /// outside the row's own head word, spelling and meanings.
+    detail: Option<(String, Vec<(String, bool)>)>,
+    entry: LexEntry,
+}
+
+impl ResultRow {
+    fn new(lang: &str, hit: Hit) -> Self {
+        let plain = |s: String| vec![(s, false)];
+        let e = &hit.entry;
+        let matched = |f: Field| (hit.field == f).then(|| hit.parts.clone());
+        let roman = romanize(lang, e.head()).into_iter().next().unwrap_or_default();
+        // only the sense that matched is marked
+        let matched_gloss: String = hit.parts.iter().map(|(s, _)| s.as_str()).collect();
+        let mut gloss: Vec<(String, bool)> = Vec::new();
+        for (i, s) in e.senses.iter().enumerate() {
+            if i > 0 {
+                gloss.push(("; ".to_string(), false));
+            }
+            match matched(Field::Gloss) {
+                Some(parts) if s.gloss() == matched_gloss => gloss.extend(parts),
+                _ => gloss.push((s.gloss(), false)),
+            }
+        }
+        let detail = match hit.field {
+            Field::Form | Field::Example => {
+                let label = if hit.label.is_empty() {
+                    hit.field.label().to_string()
+                } else {
+                    hit.label.clone()
+                };
+                Some((label, hit.parts.clone()))
+            }
+            _ => None,
+        };
+        ResultRow {
+            key: format!("r{}", e.rank),
+            approx: matches!(hit.how, Match::Typo(_) | Match::Guess),
+            head: matched(Field::Head).unwrap_or_else(|| plain(e.head().to_string())),
+            roman: matched(Field::Romanized).unwrap_or_else(|| plain(roman)),
+            gloss,
+            detail,
+            entry: hit.entry,
+        }
+    }
+}
+
+/// Text runs with the matched words marked.
+#[component]
+fn Marked(parts: Vec<(String, bool)>) -> Element {
+    rsx! {
+        for (i, (text, on)) in parts.into_iter().enumerate() {
+            if on {
+                mark { key: "{i}", style: "background:#e3c98a; color:inherit; border-radius:2px; padding:0 0.08rem;", "{text}" }
+            } else {
+                span { key: "{i}", "{text}" }
+            }
+        }
+    }
+}
 
 #[component]
 pub fn DictSearch() -> Element {
@@ -20,12 +95,13 @@ pub fn DictSearch() -> Element {
     let mut open = use_signal(|| false); // results dropdown visible
     let mut selected = use_signal(|| None::<LexEntry>); // focused word card
 
-    // Lexicon for the active language; reloads on switch.
-    let lex_res = use_resource(move || {
-        let l = lang.read().clone();
-        async move { Lexicon::from_json(freq_json_for(&l)).unwrap_or_default() }
+    // Lexicon for the active language; reloads on switch and after edits.
+    let lex_res = crate::langpack::use_lexicon(lang);
+    let manifest = use_manifest();
+    let searcher = use_memo(move || {
+        let lexicon = lex_res.read().clone().unwrap_or_default();
+        Searcher::new(&lang.read(), &manifest.read().inventory.cases, &lexicon)
     });
-    let lex = lex_res.read().clone().unwrap_or_default();
 
     // Reset when the language changes.
     use_effect(move || {
@@ -43,17 +119,18 @@ pub fn DictSearch() -> Element {
     let l2 = "'Noto Serif Georgian','Noto Serif',Georgia,'Times New Roman',serif";
 
     let q = query();
-    let results: Vec<LexEntry> = if q.trim().is_empty() {
+    let results: Vec<Hit> = if q.trim().is_empty() {
         Vec::new()
     } else {
-        lex.search(&q).into_iter().take(8).collect()
+        searcher.read().search(&q, 8)
     };
     let show_results = open() && !results.is_empty();
 
     // Prepare result rows so the template has no method calls.
-    let rows: Vec<(u32, String, String, LexEntry)> = results
-        .iter()
-        .map(|e| (e.rank, e.head().to_string(), e.en.clone(), e.clone()))
+    let l = lang();
+    let rows: Vec<ResultRow> = results
+        .into_iter()
+        .map(|h| ResultRow::new(&l, h))
         .collect();
 
     rsx! {
@@ -86,17 +163,27 @@ pub fn DictSearch() -> Element {
                 div {
                     class: "absolute left-0 z-50 mt-1 w-72 max-h-80 overflow-y-auto rounded-md shadow-xl",
                     style: "background:{paper}; border:1px solid {rule};",
-                    for (rank, head, en, entry) in rows.iter().cloned() {
+                    for row in rows.iter().cloned() {
                         button {
-                            key: "r{rank}",
+                            key: "{row.key}",
                             class: "w-full text-left px-3 py-2 focus:outline-none",
                             style: "background:transparent; border:none; border-bottom:1px solid {rule}; cursor:pointer;",
                             onclick: move |_| {
-                                selected.set(Some(entry.clone()));
+                                selected.set(Some(row.entry.clone()));
                                 open.set(false);
                             },
-                            span { style: "font-family:{l2}; font-weight:700; color:{ink};", "{head}" }
-                            span { style: "color:{secondary}; margin-left:0.5rem; font-size:0.85rem;", "{en}" }
+                            if row.approx {
+                                span { style: "color:{secondary}; margin-right:0.25rem;", title: "close match", "≈" }
+                            }
+                            span { style: "font-family:{l2}; font-weight:700; color:{ink};", Marked { parts: row.head.clone() } }
+                            span { style: "color:{secondary}; margin-left:0.35rem; font-size:0.75rem; font-style:italic;", Marked { parts: row.roman.clone() } }
+                            span { style: "color:{secondary}; margin-left:0.5rem; font-size:0.85rem;", Marked { parts: row.gloss.clone() } }
+                            if let Some((label, parts)) = row.detail.clone() {
+                                div { style: "font-size:0.78rem; color:{secondary}; margin-top:0.15rem;",
+                                    span { style: "font-style:italic; margin-right:0.35rem;", "{label}" }
+                                    span { style: "font-family:{l2}; color:{ink};", Marked { parts } }
+                                }
+                            }
                         }
                     }
                 }
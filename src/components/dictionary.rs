@@ -3,9 +3,10 @@
 // A self-contained dictionary view you can wire anywhere: `Dictionary { lang }`.
 //
 // Everything is data-driven. The A–Z order is taken from
-// `assets/langs/<lang>/alphabet.json` (its array order *is* the collation
-// order), and the entries come from the `Lexicon` (currently seeded by
-// `1000.json`, but it grows automatically as the lexicon grows). Adding a new
+// the language pack's `alphabet.json` (its array order *is* the collation
+// order), and the entries come from the `Lexicon` (seeded by `1000.json`,
+// plus the words the user adds — "Add word" in the running head, or edits from
+// the word card). Adding a new
 // language is just dropping in its `alphabet.json` + `1000.json`; the
 // dictionary then sorts and paginates itself.
 //
@@ -13,16 +14,16 @@
 // deliberate flourish is the thumb index — the stair-stepped letter tabs down
 // the right edge, in the language's own script — which doubles as jump
 // navigation, plus guide words (first/last headword) in the running head.
+// A word with several senses lists them run-in and numbered, as print does.
 //
 // Requires `src/models/lexicon.rs` (the LexEntry / Lexicon module).
 
 use dioxus::prelude::*;
 use std::collections::{HashMap, HashSet};
 
-use crate::assets::{alphabet_json_for, freq_json_for};
 use crate::components::WordDetail;
-use crate::models::letter::Letter;
-use crate::models::lexicon::{LexEntry, Lexicon};
+use crate::langpack::{self, use_manifest};
+use crate::models::lexicon::LexEntry;
 
 /// First lowercased scalar of a string (the collation / leading-letter key).
 fn first_key_char(s: &str) -> Option<char> {
@@ -64,7 +65,8 @@ struct Row {
     key: String,
     head: String,
     pos: Option<String>,
-    gloss: String,
+    /// (number — empty for a single sense, gloss, italic aside: POS / note)
+    senses: Vec<(String, String, Option<String>)>,
     example: Option<String>,
     /// Full entry, opened in the word card on click.
     entry: LexEntry,
@@ -90,20 +92,12 @@ pub fn Dictionary(lang: Signal<String>, #[props(default = 28)] per_page: usize)
     });
 
     // ── load lexicon + alphabet for the active language ───────────────────
-    let lex_res = use_resource(move || {
-        let l = lang.read().clone();
-        async move {
-            Lexicon::from_json(freq_json_for(&l)).unwrap_or_else(|e| {
-                eprintln!("dictionary: failed to parse lexicon for {l}: {e}");
-                Lexicon::default()
-            })
-        }
-    });
+    let lex_res = langpack::use_lexicon(lang);
     let alpha_res = use_resource(move || {
         let l = lang.read().clone();
         async move {
-            serde_json::from_str::<Vec<Letter>>(alphabet_json_for(&l)).unwrap_or_else(|e| {
-                eprintln!("dictionary: failed to parse alphabet for {l}: {e}");
+            langpack::alphabet(&l).unwrap_or_else(|e| {
+                eprintln!("dictionary: {e}");
                 Vec::new()
             })
         }
@@ -113,6 +107,8 @@ pub fn Dictionary(lang: Signal<String>, #[props(default = 28)] per_page: usize)
     let lex = lex_res.read().clone().unwrap_or_default();
     let alphabet = alpha_res.read().clone().unwrap_or_default();
     let lang_now = lang.read().clone();
+    let manifest = use_manifest();
+    let dir = manifest.read().direction.as_attr();
 
     // ── paperback palette / type (inline to dodge Tailwind v4 JIT gaps) ───
     let paper = "#efe7d3";
@@ -126,11 +122,18 @@ pub fn Dictionary(lang: Signal<String>, #[props(default = 28)] per_page: usize)
     let l2 = "'Noto Serif Georgian','Noto Serif',Georgia,'Times New Roman',serif";
     let body = "Georgia,'Times New Roman','Noto Serif',serif";
 
-    // ── alphabet order: distinct leading glyphs in alphabet.json order ────
+    // ── alphabet order: distinct leading glyphs, from the manifest's
+    //    collation if it declares one, else in alphabet.json order ────────
+    let collation = manifest.read().collation.clone();
+    let glyph_source: Vec<String> = if collation.is_empty() {
+        alphabet.iter().map(|l| l.letter.clone()).collect()
+    } else {
+        collation
+    };
     let mut order: HashMap<char, usize> = HashMap::new();
     let mut alpha_glyphs: Vec<char> = Vec::new();
-    for letter in &alphabet {
-        if let Some(c) = first_key_char(&letter.letter) {
+    for glyph in &glyph_source {
+        if let Some(c) = first_key_char(glyph) {
             if !order.contains_key(&c) {
                 order.insert(c, alpha_glyphs.len());
                 alpha_glyphs.push(c);
@@ -165,7 +168,7 @@ pub fn Dictionary(lang: Signal<String>, #[props(default = 28)] per_page: usize)
                             padding:2.5rem; border:1px solid {rule}; border-radius:2px;",
                     div { style: "font-family:{l2}; font-size:1.2rem; margin-bottom:0.4rem;", "No entries" }
                     div { style: "font-size:0.9rem; color:{secondary};",
-                        "Add assets/langs/{lang_now}/1000.json and alphabet.json, and the dictionary fills itself in."
+                        "Add langs/{lang_now}/1000.json and alphabet.json (in your data folder or next to the app), and the dictionary fills itself in."
                     }
                 }
             }
@@ -219,7 +222,19 @@ pub fn Dictionary(lang: Signal<String>, #[props(default = 28)] per_page: usize)
             key: format!("e{}", e.rank),
             head: e.head().to_string(),
             pos: e.pos.clone().map(|p| pos_abbr(&p)),
-            gloss: e.en.clone(),
+            senses: e
+                .senses
+                .iter()
+                .enumerate()
+                .map(|(i, s)| {
+                    let n = if e.senses.len() > 1 { (i + 1).to_string() } else { String::new() };
+                    let aside = match (s.pos.as_deref().map(pos_abbr), s.note.clone()) {
+                        (Some(p), Some(n)) => Some(format!("{p}, {n}")),
+                        (p, n) => p.or(n),
+                    };
+                    (n, s.gloss(), aside)
+                })
+                .collect(),
             example: e.example.clone(),
             entry: e.clone(),
         });
@@ -259,14 +274,8 @@ pub fn Dictionary(lang: Signal<String>, #[props(default = 28)] per_page: usize)
     let prev_cursor = if prev_disabled { "default" } else { "pointer" };
     let next_cursor = if next_disabled { "default" } else { "pointer" };
 
-    // Title-cased language label for the running head.
-    let lang_title = {
-        let mut ch = lang_now.chars();
-        match ch.next() {
-            Some(f) => f.to_uppercase().collect::<String>() + ch.as_str(),
-            None => String::new(),
-        }
-    };
+    // Language display name (from the pack manifest) for the running head.
+    let lang_title = manifest.read().name.clone();
 
     rsx! {
         style { dangerous_inner_html: ENTRY_CSS }
@@ -292,6 +301,16 @@ pub fn Dictionary(lang: Signal<String>, #[props(default = 28)] per_page: usize)
                                 style: "font-size:0.6rem; letter-spacing:0.3em; text-transform:uppercase; color:{secondary};",
                                 "Dictionary"
                             }
+                            button {
+                                class: "focus:outline-none focus-visible:ring-2 focus-visible:ring-amber-700",
+                                style: "background:none; border:none; color:{accent}; cursor:pointer; \
+                                        font-size:0.72rem; font-family:{body};",
+                                // a blank entry opens the word card ready to fill in
+                                onclick: move |_| {
+                                    selected.set(Some(LexEntry { user_added: true, ..Default::default() }));
+                                },
+                                "+ Add word"
+                            }
                         }
                         span { style: "font-family:{l2}; font-size:0.95rem; color:{accent};",
                             "{guide_first}  —  {guide_last}"
@@ -322,13 +341,23 @@ pub fn Dictionary(lang: Signal<String>, #[props(default = 28)] per_page: usize)
                                         margin-bottom:0.35rem; text-indent:-0.9rem; \
                                         padding:0.12rem 0.4rem 0.12rem 0.9rem; line-height:1.3;",
                                 onclick: move |_| { selected.set(Some(row.entry.clone())); },
-                                span { style: "font-family:{l2}; font-weight:700; font-size:1.02rem; color:{ink};", "{row.head}" }
+                                span { dir: "{dir}", style: "font-family:{l2}; font-weight:700; font-size:1.02rem; color:{ink};", "{row.head}" }
                                 if let Some(p) = row.pos.clone() {
                                     span { style: "font-style:italic; font-size:0.76rem; color:{secondary}; margin-left:0.4rem;", "{p}" }
                                 }
-                                span { style: "font-size:0.9rem; color:{ink}; margin-left:0.4rem;", "{row.gloss}" }
+                                for (n, gloss, aside) in row.senses.clone() {
+                                    span { key: "{n}", style: "font-size:0.9rem; color:{ink}; margin-left:0.4rem;",
+                                        if !n.is_empty() {
+                                            b { style: "color:{accent}; margin-right:0.2rem;", "{n}" }
+                                        }
+                                        if let Some(a) = aside {
+                                            i { style: "font-size:0.76rem; color:{secondary}; margin-right:0.25rem;", "{a}" }
+                                        }
+                                        "{gloss}"
+                                    }
+                                }
                                 if let Some(ex) = row.example.clone() {
-                                    div { style: "font-family:{l2}; font-style:italic; font-size:0.82rem; color:{secondary}; margin-top:0.08rem;", "{ex}" }
+                                    div { dir: "{dir}", style: "font-family:{l2}; font-style:italic; font-size:0.82rem; color:{secondary}; margin-top:0.08rem;", "{ex}" }
                                 }
                             }
                         }
@@ -2,30 +2,12 @@
 //! testable. The WPM test can adopt the timing/cursor side of this in a later
 //! pass; for now it carries what the reading drill needs that the speed test
 //! doesn't — turning a dictionary gloss into accepted answers and grading a
-//! typed response against them.
+//! typed response against them, sense by sense.
 
-/// Levenshtein edit distance over characters (script-agnostic).
-pub fn levenshtein(a: &str, b: &str) -> usize {
-    let a: Vec<char> = a.chars().collect();
-    let b: Vec<char> = b.chars().collect();
-    if a.is_empty() {
-        return b.len();
-    }
-    if b.is_empty() {
-        return a.len();
-    }
-    let mut prev: Vec<usize> = (0..=b.len()).collect();
-    let mut cur = vec![0usize; b.len() + 1];
-    for (i, &ca) in a.iter().enumerate() {
-        cur[0] = i + 1;
-        for (j, &cb) in b.iter().enumerate() {
-            let cost = usize::from(ca != cb);
-            cur[j + 1] = (prev[j + 1] + 1).min(cur[j] + 1).min(prev[j] + cost);
-        }
-        std::mem::swap(&mut prev, &mut cur);
-    }
-    prev[b.len()]
-}
+use crate::models::lexicon::Sense;
+// the text helpers live in `models` for search and ingest; the drills keep
+// using them from here
+pub use crate::models::text::{levenshtein, tokenize};
 
 /// Normalize for fair comparison: trim, lowercase, collapse inner whitespace.
 pub fn normalize(s: &str) -> String {
@@ -55,13 +37,12 @@ fn strip_parens(s: &str) -> String {
     out.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
-/// Split a dictionary gloss into individual accepted answers, plus a
-/// parenthetical-stripped variant of each. `"way / road / path"` ->
-/// `["way","road","path"]`; `"long (in length)"` -> `["long (in length)","long"]`.
-pub fn accepted_answers(gloss: &str) -> Vec<String> {
+/// One sense's accepted answers: each gloss plus its parenthetical-stripped
+/// variant. `"long (in length)"` -> `["long (in length)","long"]`.
+pub fn sense_answers(sense: &Sense) -> Vec<String> {
     let mut out: Vec<String> = Vec::new();
-    for part in gloss.split(|c| c == '/' || c == ',' || c == ';') {
-        let p = part.trim();
+    for g in &sense.glosses {
+        let p = g.trim();
         if p.is_empty() {
             continue;
         }
@@ -69,13 +50,39 @@ pub fn accepted_answers(gloss: &str) -> Vec<String> {
             out.push(p.to_string());
         }
         let bare = strip_parens(p);
-        if !bare.is_empty() && bare != p && !out.iter().any(|x| *x == bare) {
+        if !bare.is_empty() && bare != p && !out.contains(&bare) {
             out.push(bare);
         }
     }
     out
 }
 
+/// Split a one-line gloss (or an L2 word with `/` alternates) into accepted
+/// answers across all its senses. `"way / road / path"` ->
+/// `["way","road","path"]`.
+pub fn accepted_answers(gloss: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for a in Sense::parse(gloss).iter().flat_map(sense_answers) {
+        if !out.contains(&a) {
+            out.push(a);
+        }
+    }
+    out
+}
+
+/// Grade against each sense on its own: the best score and the sense that
+/// earned it (`None` when nothing scored at all).
+pub fn grade_senses(typed: &str, senses: &[Sense]) -> (f32, Option<usize>) {
+    let mut best = (0.0f32, None);
+    for (i, s) in senses.iter().enumerate() {
+        let g = grade_answer(typed, &sense_answers(s));
+        if g > best.0 {
+            best = (g, Some(i));
+        }
+    }
+    best
+}
+
 /// Grade a typed answer against accepted forms. Exact (normalized) match -> 1.0;
 /// otherwise scaled by the best edit distance relative to length (a single typo
 /// on a longer word still scores high). Range `0.0..=1.0`.
@@ -103,27 +110,23 @@ pub fn grade_answer(typed: &str, accepted: &[String]) -> f32 {
     best.clamp(0.0, 1.0)
 }
 
-/// Tokenize an L2 sentence into word surfaces, stripping surrounding punctuation
-/// but keeping letters/marks. Works for Georgian, Cyrillic, and Latin.
-pub fn tokenize(sentence: &str) -> Vec<String> {
-    sentence
-        .split(|c: char| c.is_whitespace())
-        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()).to_string())
-        .filter(|w| !w.is_empty())
-        .collect()
+/// The key press behind an edit of a copy-typing field: `(expected, typed)`
+/// when `after` is `before` plus one character and `target` has a character
+/// at that spot. Deletions, pastes and IME bursts give `None`.
+pub fn keystroke(target: &str, before: &str, after: &str) -> Option<(char, char)> {
+    let mut added = after.strip_prefix(before)?.chars();
+    let typed = added.next()?;
+    if added.next().is_some() {
+        return None;
+    }
+    let expected = target.chars().nth(before.chars().count())?;
+    Some((expected, typed))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn edit_distance_basics() {
-        assert_eq!(levenshtein("kitten", "sitting"), 3);
-        assert_eq!(levenshtein("", "abc"), 3);
-        assert_eq!(levenshtein("same", "same"), 0);
-    }
-
     #[test]
     fn gloss_splits_into_answers() {
         assert_eq!(
@@ -139,6 +142,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parenthetical_commas_stay_in_one_answer() {
+        let a = accepted_answers("beat (of a heart, drums), hit");
+        assert_eq!(a, vec!["beat (of a heart, drums)", "beat", "hit"]);
+    }
+
+    #[test]
+    fn grading_picks_the_matching_sense() {
+        let senses = Sense::parse("face; person (legal)");
+        assert_eq!(grade_senses("person", &senses), (1.0, Some(1)));
+        assert_eq!(grade_senses("fase", &senses).1, Some(0));
+        assert_eq!(grade_senses("", &senses), (0.0, None));
+    }
+
     #[test]
     fn grading_exact_typo_and_wrong() {
         let acc = accepted_answers("house / home");
@@ -150,11 +167,11 @@ mod tests {
     }
 
     #[test]
-    fn tokenize_strips_punctuation() {
-        assert_eq!(
-            tokenize("კაცი ქუჩაში დადიოდა."),
-            vec!["კაცი", "ქუჩაში", "დადიოდა"]
-        );
-        assert_eq!(tokenize("  hello,  world!  "), vec!["hello", "world"]);
+    fn only_single_appended_characters_are_keystrokes() {
+        assert_eq!(keystroke("ფური", "", "პ"), Some(('ფ', 'პ')));
+        assert_eq!(keystroke("ფური", "ფუ", "ფურ"), Some(('რ', 'რ')));
+        assert_eq!(keystroke("ფური", "ფურ", "ფუ"), None); // backspace
+        assert_eq!(keystroke("ფური", "", "ფუ"), None); // paste
+        assert_eq!(keystroke("ფუ", "ფუ", "ფუი"), None); // past the end
     }
 }
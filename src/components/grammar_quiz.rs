@@ -2,27 +2,22 @@
 //
 // The grammar "Practice" mode: walks a language's authored Drill bank one
 // question at a time, grades with the shared typing core (type-in) or exact
-// match (choice), and logs Skill::Grammar evidence per language. Keyed on the
-// language by the caller, so switching language resets the quiz cleanly.
+// match (choice), and logs Skill::Grammar evidence per language (as planner
+// probes when `probe` is set). Keyed on the language by the caller, so
+// switching language resets the quiz cleanly.
 
 use dioxus::events::KeyboardEvent;
 use dioxus::prelude::*;
 
 use crate::components::drills::typing_engine::grade_answer;
 use crate::grammar::{doc_for, Answer};
-use crate::learner::{now_ms, use_learner};
-use crate::learning::{Evidence, Skill, Source};
-
-fn drill_id(lang: &str, prompt: &str) -> String {
-    use std::hash::{Hash, Hasher};
-    let mut h = std::collections::hash_map::DefaultHasher::new();
-    prompt.hash(&mut h);
-    format!("{lang}:grammar:{:x}", h.finish())
-}
+use crate::learner::{as_probe, grammar_evidence, now_ms, use_learner};
 
 #[component]
-pub fn GrammarQuiz(lang: String) -> Element {
+pub fn GrammarQuiz(lang: String, #[props(default)] probe: bool) -> Element {
     let learner = use_learner();
+    // tag as a planner probe when the session asked the question
+    let tag = move |ev: Vec<_>| if probe { as_probe(ev) } else { ev };
     let drills = doc_for(&lang).drills;
     let total = drills.len();
 
@@ -127,10 +122,9 @@ pub fn GrammarQuiz(lang: String) -> Element {
                                                     {
                                                         let score = grade_answer(&typed(), &answers);
                                                         let latency = now_ms().saturating_sub(started()) as u32;
-                                                        learner.emit(Evidence::new(
-                                                            now_ms(), lang.clone(), drill_id(&lang, &prompt),
-                                                            Skill::Grammar, score, 0.0, latency, Source::Grammar,
-                                                        ));
+                                                        learner.emit_all(tag(grammar_evidence(
+                                                            &lang, &prompt, answers.first().map_or("", |a| a.as_str()), true, score, latency,
+                                                        )));
                                                         scores.with_mut(|v| v.push(score));
                                                         feedback.set(Some((score >= 0.85, answers.join(" / "), note.clone())));
                                                     }
@@ -150,10 +144,9 @@ pub fn GrammarQuiz(lang: String) -> Element {
                                                         if feedback().is_none() && !typed().trim().is_empty() {
                                                             let score = grade_answer(&typed(), &answers);
                                                             let latency = now_ms().saturating_sub(started()) as u32;
-                                                            learner.emit(Evidence::new(
-                                                                now_ms(), lang.clone(), drill_id(&lang, &prompt),
-                                                                Skill::Grammar, score, 0.0, latency, Source::Grammar,
-                                                            ));
+                                                            learner.emit_all(tag(grammar_evidence(
+                                                                &lang, &prompt, answers.first().map_or("", |a| a.as_str()), true, score, latency,
+                                                            )));
                                                             scores.with_mut(|v| v.push(score));
                                                             feedback.set(Some((score >= 0.85, answers.join(" / "), note.clone())));
                                                         }
@@ -182,10 +175,9 @@ pub fn GrammarQuiz(lang: String) -> Element {
                                                                 if feedback().is_none() {
                                                                     let score = if oi == correct { 1.0 } else { 0.0 };
                                                                     let latency = now_ms().saturating_sub(started()) as u32;
-                                                                    learner.emit(Evidence::new(
-                                                                        now_ms(), lang.clone(), drill_id(&lang, &prompt),
-                                                                        Skill::Grammar, score, 0.0, latency, Source::Grammar,
-                                                                    ));
+                                                                    learner.emit_all(tag(grammar_evidence(
+                                                                        &lang, &prompt, &correct_text, false, score, latency,
+                                                                    )));
                                                                     scores.with_mut(|v| v.push(score));
                                                                     feedback.set(Some((oi == correct, correct_text.clone(), note.clone())));
                                                                 }
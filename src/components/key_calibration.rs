@@ -0,0 +1,212 @@
+// src/components/key_calibration.rs
+//
+// Key calibration wizard, opened from the on-screen keyboard ("Calibrate Keys",
+// or right-click a key for just its letter). Two passes:
+//   - Letters:    "press the key you use for ა" for each letter in turn; the
+//                 physical key (and Shift) you press becomes that letter's key
+//   - Key labels: press every key once with your usual Latin layout; what it
+//                 types becomes the label shown on that key and in the hints
+// Everything lands in `calibration::Calibrations` and is saved as you go. Only
+// the keys the wizard takes are swallowed: Tab and shortcuts still work, and
+// Escape closes it.
+
+use dioxus::events::KeyboardEvent;
+use dioxus::html::{Key, Modifiers};
+use dioxus::prelude::*;
+
+use crate::calibration::{use_calibrations, use_keymap, KeyBinding};
+use crate::models::letter::Letter;
+
+/// Keys that only modify another key; the wizard waits for the real one.
+fn is_modifier(code: &str) -> bool {
+    ["Shift", "Control", "Alt", "Meta", "CapsLock", "Fn"]
+        .iter()
+        .any(|m| code.starts_with(m))
+}
+
+/// The letters to ask about: one per key, since a capital that shares its
+/// lowercase's key follows it (`Calibrations::bind`).
+fn prompts(letters: &[Letter]) -> Vec<Letter> {
+    letters
+        .iter()
+        .filter(|l| {
+            let lower = l.letter.to_lowercase();
+            lower == l.letter
+                || !letters
+                    .iter()
+                    .any(|o| o.letter == lower && o.key_code == l.key_code)
+        })
+        .cloned()
+        .collect()
+}
+
+#[component]
+pub fn KeyCalibration(
+    letters: Vec<Letter>,
+    /// Calibrate just this letter rather than the whole alphabet.
+    start: Option<String>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let lang = use_context::<Signal<String>>();
+    let mut cal = use_calibrations();
+    let keymap = use_keymap();
+    let settings = crate::settings::use_settings();
+    let mut labels_mode = use_signal(|| false);
+    let mut step = use_signal(|| 0usize);
+    let mut note = use_signal(String::new);
+
+    let queue: Vec<Letter> = match &start {
+        Some(g) => letters.iter().filter(|l| &l.letter == g).cloned().collect(),
+        None => prompts(&letters),
+    };
+    let labels = labels_mode();
+    let at = step();
+    let current = queue.get(at).cloned();
+    let progress = format!("{} / {}", (at + 1).min(queue.len()), queue.len());
+    let now_on = current
+        .as_ref()
+        .and_then(|l| keymap().apply(vec![l.clone()]).pop())
+        .map(|l| {
+            let key = keymap().label(&l.key_code);
+            if l.shifted {
+                format!("⇧{key}")
+            } else {
+                key
+            }
+        })
+        .unwrap_or_default();
+    let note_now = note();
+    let calibrated = keymap().is_calibrated();
+
+    let on_key = {
+        let pack = letters.clone();
+        let queue = queue.clone();
+        move |evt: KeyboardEvent| {
+            let code = evt.code().to_string();
+            if code == "Escape" {
+                evt.prevent_default();
+                evt.stop_propagation();
+                on_close.call(());
+                return;
+            }
+            // AltGr reports as Ctrl+Alt on Windows, and types letters
+            let mods = evt.modifiers();
+            let shortcut = mods.intersects(Modifiers::CONTROL | Modifiers::META)
+                && !mods.contains(Modifiers::ALT);
+            if code == "Tab" || shortcut || is_modifier(&code) {
+                return;
+            }
+            if labels_mode() {
+                if let Key::Character(c) = evt.key() {
+                    evt.prevent_default();
+                    evt.stop_propagation();
+                    let label = c.to_uppercase();
+                    let layout = settings.read().keyboard_layout.clone();
+                    cal.with_mut(|c| c.set_label(&lang(), &layout, &code, &label));
+                    note.set(format!("{code} → {label}"));
+                }
+                return;
+            }
+            let Some(l) = queue.get(step()) else {
+                return;
+            };
+            evt.prevent_default();
+            evt.stop_propagation();
+            let shifted = evt.modifiers().contains(Modifiers::SHIFT);
+            cal.with_mut(|c| {
+                c.bind(
+                    &lang(),
+                    &pack,
+                    &l.letter,
+                    KeyBinding {
+                        code: code.clone(),
+                        shifted,
+                    },
+                )
+            });
+            let key = keymap.peek().label(&code);
+            note.set(format!(
+                "{} → {}{key}",
+                l.letter,
+                if shifted { "⇧" } else { "" }
+            ));
+            step.with_mut(|s| *s += 1);
+        }
+    };
+
+    rsx! {
+        div {
+            class: "mt-4 p-4 rounded-lg bg-gray-900 border border-indigo-500 flex flex-col items-center gap-3 outline-none",
+            tabindex: "0",
+            onmounted: move |e| {
+                let el = e.data();
+                spawn(async move { let _ = el.set_focus(true).await; });
+            },
+            onkeydown: on_key,
+
+            div { class: "flex items-center gap-2",
+                button {
+                    class: format!("px-3 py-1 rounded text-sm {}", if !labels { "bg-indigo-600 text-white" } else { "bg-gray-700 text-gray-300" }),
+                    onclick: move |_| labels_mode.set(false),
+                    "Letters"
+                }
+                button {
+                    class: format!("px-3 py-1 rounded text-sm {}", if labels { "bg-indigo-600 text-white" } else { "bg-gray-700 text-gray-300" }),
+                    onclick: move |_| labels_mode.set(true),
+                    "Key labels"
+                }
+            }
+
+            if labels {
+                div { class: "text-sm text-gray-300 text-center max-w-md",
+                    "Switch your system keyboard to the Latin layout printed on your keys, then press each key once. What it types becomes its label here and in the hints."
+                }
+                button {
+                    class: "text-xs text-gray-500 hover:text-gray-300",
+                    onclick: move |_| cal.with_mut(|c| c.labels.clear()),
+                    "Reset labels"
+                }
+            } else if let Some(l) = &current {
+                div { class: "text-sm text-gray-400", "Press the key you use for" }
+                span { class: "text-6xl font-bold", "{l.letter}" }
+                div { class: "text-xs text-gray-500",
+                    "{progress} · now on {now_on} · hold Shift if you type it shifted"
+                }
+                div { class: "flex gap-3",
+                    button {
+                        class: "px-3 py-1 rounded bg-gray-700 hover:bg-gray-600 text-gray-200 text-sm",
+                        disabled: at == 0,
+                        onclick: move |_| step.with_mut(|s| *s = s.saturating_sub(1)),
+                        "Back"
+                    }
+                    button {
+                        class: "px-3 py-1 rounded bg-gray-700 hover:bg-gray-600 text-gray-200 text-sm",
+                        onclick: move |_| step.with_mut(|s| *s += 1),
+                        "Skip"
+                    }
+                }
+            } else {
+                div { class: "text-sm text-green-400", "All done." }
+            }
+
+            if !note_now.is_empty() {
+                div { class: "text-xs text-gray-400", "Saved: {note_now}" }
+            }
+
+            div { class: "flex gap-3",
+                if !labels && calibrated {
+                    button {
+                        class: "text-xs text-gray-500 hover:text-gray-300",
+                        onclick: move |_| cal.with_mut(|c| c.reset_letters(&lang())),
+                        "Reset letters to the pack layout"
+                    }
+                }
+                button {
+                    class: "px-4 py-1 rounded bg-green-600 hover:bg-green-500 text-white text-sm",
+                    onclick: move |_| on_close.call(()),
+                    "Done"
+                }
+            }
+        }
+    }
+}
@@ -1,30 +1,26 @@
 use dioxus::{events::KeyboardEvent, html::div};
 use dioxus::prelude::*;
 use std::collections::{HashMap, HashSet};
+use crate::components::key_calibration::KeyCalibration;
+use crate::layouts::PhysicalKey;
 use crate::models::letter::Letter;
 
-// QWERTY rows: physical keys
-const ROW1: [&str; 12] = [
-    "KeyQ", "KeyW", "KeyE", "KeyR", "KeyT", "KeyY",
-    "KeyU", "KeyI", "KeyO", "KeyP", "BracketLeft", "BracketRight",
-];
-
-const ROW2: [&str; 11] = [
-    "KeyA", "KeyS", "KeyD", "KeyF", "KeyG", "KeyH",
-    "KeyJ", "KeyK", "KeyL", "Semicolon", "Quote",
-];
-
-const ROW3: [&str; 11] = [
-    "ShiftLeft", "KeyZ", "KeyX", "KeyC", "KeyV", "KeyB",
-    "KeyN", "KeyM", "Comma", "Period", "Slash",
-];
-
 #[derive(Clone, PartialEq)]
 struct KeySlot {
     key_code: String,
-    qwerty_label: String,
+    label: String,           // what's printed on the learner's key
+    width: f32,              // in key units, from the layout profile
+    finger: String,          // who strikes this key, from its position
     base: Option<Letter>,    // shifted == false
     shifted: Option<Letter>, // shifted == true
+    heat: Option<Heat>,      // set while the mistake overlay is on
+}
+
+/// How often this key's letters come out wrong in the typing drills.
+#[derive(Clone, PartialEq)]
+struct Heat {
+    rate: f32,
+    note: String, // tooltip: "ფ → პ ×3, ბ ×1"
 }
 
 #[component]
@@ -32,9 +28,23 @@ pub fn Keyboard(letters: Vec<Letter>, children: Element) -> Element {
     // track currently pressed key codes (e.g. "KeyA", "ShiftLeft", "Space")
     let mut pressed = use_signal(|| HashSet::<String>::new());
 
+    // mistake overlay: confusion tables from the typing drills' keystrokes
+    let lang = use_context::<Signal<String>>();
+    let keystrokes = crate::keystrokes::use_keystrokes();
+    let mut show_heat = use_signal(|| false);
+    let confusions = use_memo(move || show_heat().then(|| keystrokes.confusions(&lang())));
+    let confusions_now = confusions();
+
+    // calibration: letters on the keys the learner uses, labels as printed
+    let keymap = crate::calibration::use_keymap();
+    let keymap_now = keymap();
+    // None = wizard closed; Some(None) = every letter; Some(Some(ა)) = just one
+    let mut calibrating = use_signal(|| None::<Option<String>>);
+    let pack_letters = letters.clone();
+
     // Build lookup: (key_code, shifted) -> Letter
     let mut map: HashMap<(String, bool), Letter> = HashMap::new();
-    for l in letters.into_iter() {
+    for l in keymap_now.apply(letters).into_iter() {
         map.insert((l.key_code.clone(), l.shifted), l);
     }
 
@@ -48,29 +58,80 @@ pub fn Keyboard(letters: Vec<Letter>, children: Element) -> Element {
          hover:bg-gray-400 transition ring-1"
     };
 
-    let make_row = |row_codes: &[&str]| -> Vec<KeySlot> {
-        row_codes
+    let make_row = |row: &[PhysicalKey]| -> Vec<KeySlot> {
+        row
             .iter()
-            .map(|code| {
-                let base = map.get(&((*code).to_string(), false)).cloned();
-                let shifted = map.get(&((*code).to_string(), true)).cloned();
+            .map(|key| {
+                let code = key.code.as_str();
+                let base = map.get(&(code.to_string(), false)).cloned();
+                let shifted = map.get(&(code.to_string(), true)).cloned();
+                let heat = confusions_now.as_ref().and_then(|c| {
+                    let glyphs: Vec<char> = [&base, &shifted]
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|l| l.letter.chars().next())
+                        .collect();
+                    let rate = c.miss_rate(&glyphs)?;
+                    let note = glyphs
+                        .iter()
+                        .map(|&g| {
+                            let instead: Vec<String> = c
+                                .typed_for(g)
+                                .iter()
+                                .map(|(t, n)| format!("{t} ×{n}"))
+                                .collect();
+                            if instead.is_empty() {
+                                format!("{g}: no misses")
+                            } else {
+                                format!("{g} → {}", instead.join(", "))
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    Some(Heat { rate, note })
+                });
 
                 KeySlot {
-                    key_code: (*code).to_string(),
-                    qwerty_label: code_to_qwerty_label(code).to_string(),
+                    key_code: code.to_string(),
+                    label: keymap_now.label(code),
+                    width: key.width,
+                    finger: crate::layouts::finger(code).unwrap_or_default().to_string(),
                     base,
                     shifted,
+                    heat,
                 }
             })
             .collect()
     };
 
-    let row1 = make_row(&ROW1);
-    let row2 = make_row(&ROW2);
-    let row3 = make_row(&ROW3);
+    // the learner's physical keyboard, number row first
+    let layout = keymap_now.layout();
+    let rows: Vec<Vec<KeySlot>> = layout
+        .map(|p| p.rows.iter().map(|r| make_row(r)).collect())
+        .unwrap_or_default();
+    // letters the pack puts on keys this keyboard doesn't have
+    let offboard: Vec<String> = map
+        .iter()
+        .filter(|((code, _), _)| layout.is_some_and(|p| p.key(code).is_none()))
+        .map(|((code, _), l)| format!("{} ({code})", l.letter))
+        .collect();
+    let offboard_note = offboard.join(", ");
 
     let mut show_legend = use_signal(|| false);
 
+    let worst = confusions_now
+        .as_ref()
+        .map(|c| {
+            c.worst_pairs(5)
+                .iter()
+                .map(|(e, t, n)| format!("{e}→{t} ×{n}"))
+                .collect::<Vec<_>>()
+                .join(" · ")
+        })
+        .unwrap_or_default();
+    let calibrated = keymap_now.is_calibrated();
+    let open_one = move |glyph: String| calibrating.set(Some(Some(glyph)));
+
     rsx! {
         // outer "global" listener: focusable container
         div {
@@ -90,25 +151,14 @@ pub fn Keyboard(letters: Vec<Letter>, children: Element) -> Element {
             // The keyboard will always show below the children
             {children}
 
-            // Row 1
-            div { class: "flex justify-center gap-1 mb-1",
-                {row1.iter().map(|slot| rsx! {
-                    KeySlotView { slot: slot.clone(), pressed: pressed() }
-                })}
-            }
-
-            // Row 2
-            div { class: "flex justify-center gap-1 mb-1",
-                {row2.iter().map(|slot| rsx! {
-                    KeySlotView { slot: slot.clone(), pressed: pressed() }
-                })}
-            }
-
-            // Row 3
-            div { class: "flex justify-center gap-1 mb-1",
-                {row3.iter().map(|slot| rsx! {
-                    KeySlotView { slot: slot.clone(), pressed: pressed() }
-                })}
+            // Key rows, as laid out on the learner's keyboard
+            for (r, row) in rows.into_iter().enumerate() {
+                div { key: "{r}", class: "flex justify-center gap-1 mb-1",
+                    // ISO / JIS list Enter twice (its two halves), so key by position
+                    {row.into_iter().enumerate().map(|(i, slot)| rsx! {
+                        KeySlotView { key: "{i}", slot, pressed: pressed(), on_calibrate: open_one }
+                    })}
+                }
             }
 
             // Space bar
@@ -120,6 +170,54 @@ pub fn Keyboard(letters: Vec<Letter>, children: Element) -> Element {
                 }
             }
 
+            if !offboard_note.is_empty() {
+                div { class: "text-center text-xs text-amber-400 mt-1",
+                    "Not on this keyboard: {offboard_note}. Calibrate Keys to move them."
+                }
+            }
+
+            // Calibration wizard
+            if let Some(start) = calibrating() {
+                KeyCalibration {
+                    // a fresh wizard per letter right-clicked
+                    key: "{start:?}",
+                    letters: pack_letters.clone(),
+                    start,
+                    on_close: move |_| calibrating.set(None),
+                }
+            }
+
+            // Mistake overlay toggle
+            div { class: "flex flex-col items-center mt-3 gap-1",
+                button {
+                    class: "text-center opacity-50 hover:opacity-100 transition-all duration-300 hover:scale-105 hover:cursor-pointer",
+                    onclick: move |_| show_heat.set(!show_heat()),
+                    if show_heat() { b{"Hide Mistakes"} } else { b{"Show Mistakes"} }
+                }
+                if show_heat() {
+                    if worst.is_empty() {
+                        span { class: "text-xs text-gray-400", "No mix-ups recorded yet — the word drill and WPM test log every keystroke." }
+                    } else {
+                        span { class: "text-xs text-gray-400", "Most confused: {worst}" }
+                    }
+                }
+            }
+
+            // Calibration toggle
+            div { class: "flex flex-col items-center mt-3 gap-1",
+                button {
+                    class: "text-center opacity-50 hover:opacity-100 transition-all duration-300 hover:scale-105 hover:cursor-pointer",
+                    title: "Right-click a key to recalibrate just that letter",
+                    onclick: move |_| {
+                        if calibrating().is_some() { calibrating.set(None) } else { calibrating.set(Some(None)) }
+                    },
+                    if calibrating().is_some() { b{"Close Calibration"} } else { b{"Calibrate Keys"} }
+                }
+                if calibrated && calibrating().is_none() {
+                    span { class: "text-xs text-gray-400", "Using your key calibration." }
+                }
+            }
+
             // Legend toggle
             if show_legend() {
                 div { class:"flex justify-center white",
@@ -168,7 +266,7 @@ pub fn Keyboard(letters: Vec<Letter>, children: Element) -> Element {
 }
 
 #[component]
-fn KeySlotView(slot: KeySlot, pressed: HashSet<String>) -> Element {
+fn KeySlotView(slot: KeySlot, pressed: HashSet<String>, on_calibrate: EventHandler<String>) -> Element {
     let is_pressed = pressed.contains(&slot.key_code);
 
     let shift_down =
@@ -181,13 +279,17 @@ fn KeySlotView(slot: KeySlot, pressed: HashSet<String>) -> Element {
         slot.base.as_ref()
     };
 
-    // No mapping at all → gray placeholder with QWERTY label
+    // wide keys (Tab, Shift, Enter…) span their gaps too
+    let width = format!("width: {:.2}rem;", slot.width * 3.25 - 0.25);
+
+    // No mapping at all → gray placeholder with the key's label
     if active_letter.is_none() && slot.base.is_none() && slot.shifted.is_none() {
         return rsx! {
             div {
-                class: "key w-12 h-12 flex items-center justify-center rounded \
+                class: "key h-12 flex items-center justify-center rounded \
                         bg-gray-700 text-gray-500 opacity-50 text-sm",
-                "{slot.qwerty_label}"
+                style: "{width}",
+                "{slot.label}"
             }
         };
     }
@@ -199,7 +301,9 @@ fn KeySlotView(slot: KeySlot, pressed: HashSet<String>) -> Element {
         .or(slot.shifted.as_ref())
         .unwrap();
 
-    let finger_class = match letter.finger.as_str() {
+    // the key's position decides the finger; the pack's guess is a fallback
+    let finger = if slot.finger.is_empty() { &letter.finger } else { &slot.finger };
+    let finger_class = match finger.as_str() {
         "index_left"   => "index_left",
         "index_right"  => "index_right",
         "middle_left"  => "middle_left",
@@ -216,61 +320,35 @@ fn KeySlotView(slot: KeySlot, pressed: HashSet<String>) -> Element {
         format!("bg-white hover:bg-gray-200 shadow {finger_class} text-gray-800")
     };
 
+    // heat tints the key red by miss rate and swaps the key label for it
+    let (heat_style, heat_title, corner) = match (&slot.heat, is_pressed) {
+        (Some(h), false) => (
+            format!("background-color: rgba(239, 68, 68, {:.2});", 0.15 + 0.75 * h.rate),
+            h.note.clone(),
+            format!("{:.0}%", h.rate * 100.0),
+        ),
+        _ => (String::new(), String::new(), slot.label.clone()),
+    };
+
     rsx! {
         div {
             class: format!(
-                "key w-12 h-12 flex flex-col items-center justify-center rounded \
+                "key h-12 flex flex-col items-center justify-center rounded \
                  font-bold transition {}",
                 classes
             ),
+            style: "{width}{heat_style}",
+            title: "{heat_title}",
+            // right-click: recalibrate this letter
+            oncontextmenu: {
+                let glyph = letter.letter.clone();
+                move |evt: MouseEvent| {
+                    evt.prevent_default();
+                    on_calibrate.call(glyph.clone());
+                }
+            },
             span { class: "text-xl leading-none", "{letter.letter}" }
-            span { class: "text-[0.6rem] text-gray-500 mt-1", "{slot.qwerty_label}" }
+            span { class: "text-[0.6rem] text-gray-500 mt-1", "{corner}" }
         }
     }
 }
-
-// QWERTY label for physical key codes
-pub fn code_to_qwerty_label(code: &str) -> &'static str {
-    match code {
-        "KeyQ" => "Q",
-        "KeyW" => "W",
-        "KeyE" => "E",
-        "KeyR" => "R",
-        "KeyT" => "T",
-        "KeyY" => "Y",
-        "KeyU" => "U",
-        "KeyI" => "I",
-        "KeyO" => "O",
-        "KeyP" => "P",
-        "BracketLeft"  => "[",
-        "BracketRight" => "]",
-
-        "KeyA" => "A",
-        "KeyS" => "S",
-        "KeyD" => "D",
-        "KeyF" => "F",
-        "KeyG" => "G",
-        "KeyH" => "H",
-        "KeyJ" => "J",
-        "KeyK" => "K",
-        "KeyL" => "L",
-        "Semicolon" => ";",
-        "Quote"     => "'",
-
-        "KeyZ" => "Z",
-        "KeyX" => "X",
-        "KeyC" => "C",
-        "KeyV" => "V",
-        "KeyB" => "B",
-        "KeyN" => "N",
-        "KeyM" => "M",
-        "Comma"  => ",",
-        "Period" => ".",
-        "Slash"  => "/",
-
-        "ShiftLeft"  => "Shift",
-        "ShiftRight" => "Shift",
-
-        _ => "",
-    }
-}
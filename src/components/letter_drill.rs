@@ -0,0 +1,375 @@
+// src/components/letter_drill.rs
+//
+// Letter-sound drill for the alphabet page, in two directions:
+//   - hear → glyph: a letter's recording plays; pick the glyph (or type it)
+//   - glyph → sound: a glyph is shown; pick its name / IPA
+// "Confusable" rounds draw from the pack's `confusables` sets (Georgian ფ/პ,
+// თ/ტ, ქ/კ) and offer only the set, so the choice is between sounds that
+// really are hard to tell apart. Every answer is logged as Script & Sound
+// evidence for that letter.
+
+use dioxus::events::{FormEvent, KeyboardEvent};
+use dioxus::prelude::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::langpack::use_manifest;
+use crate::models::letter::Letter;
+use crate::settings::use_settings;
+
+/// Choices offered in an ordinary round.
+const CHOICES: usize = 4;
+
+/// One question. Holds its letters rather than pool positions, since the pool
+/// is rebuilt every render (the alphabet loading, the direction changing).
+#[derive(Clone, PartialEq)]
+struct Round {
+    target: Letter,
+    options: Vec<Letter>,
+    confusable: bool,
+}
+
+/// One card per sound: Russian lists А and а separately with the same
+/// recording, so keep the lowercase one.
+fn drill_pool(letters: &[Letter]) -> Vec<Letter> {
+    let mut out: Vec<Letter> = Vec::new();
+    for l in letters {
+        let same = |o: &Letter| o.name == l.name && o.pron == l.pron;
+        match out.iter().position(same) {
+            Some(i) if l.letter == l.letter.to_lowercase() => out[i] = l.clone(),
+            Some(_) => {}
+            None => out.push(l.clone()),
+        }
+    }
+    out
+}
+
+/// The manifest's confusable sets as pool letters, keeping sets that still
+/// have two letters in this pack's alphabet.
+fn confusable_sets(pool: &[Letter], sets: &[Vec<String>]) -> Vec<Vec<Letter>> {
+    sets.iter()
+        .map(|set| {
+            set.iter()
+                .filter_map(|g| pool.iter().find(|l| &l.letter == g).cloned())
+                .collect::<Vec<_>>()
+        })
+        .filter(|s| s.len() >= 2)
+        .collect()
+}
+
+fn new_round(pool: &[Letter], sets: &[Vec<Letter>], confusable: bool) -> Option<Round> {
+    let mut rng = rand::rng();
+    if confusable && !sets.is_empty() {
+        let mut options = sets[rng.random_range(0..sets.len())].clone();
+        let target = options[rng.random_range(0..options.len())].clone();
+        options.shuffle(&mut rng);
+        return Some(Round {
+            target,
+            options,
+            confusable: true,
+        });
+    }
+    if pool.len() < 2 {
+        return None;
+    }
+    let mut options = pool.to_vec();
+    options.shuffle(&mut rng);
+    options.truncate(CHOICES);
+    let target = options[rng.random_range(0..options.len())].clone();
+    Some(Round {
+        target,
+        options,
+        confusable: false,
+    })
+}
+
+#[component]
+pub fn LetterDrill(
+    letters: Vec<Letter>,
+    lang: Signal<String>,
+    /// Log answers as planner probes (`Source::Probe`).
+    #[props(default)]
+    probe: bool,
+) -> Element {
+    let mut from_sound = use_signal(|| true);
+    let mut confusable = use_signal(|| false);
+    let mut round = use_signal(|| None::<Round>);
+    // (glyph picked, correct?)
+    let mut picked = use_signal(|| None::<(String, bool)>);
+    let mut typed = use_signal(String::new);
+    let mut shown_at = use_signal(crate::learner::now_ms);
+    let mut correct = use_signal(|| 0u32);
+    let mut attempts = use_signal(|| 0u32);
+
+    let settings = use_settings();
+    let manifest = use_manifest();
+    let learner = crate::learner::use_learner();
+
+    // back to the setup screen when the language changes
+    use_effect(move || {
+        let _ = lang();
+        round.set(None);
+        picked.set(None);
+        correct.set(0);
+        attempts.set(0);
+    });
+
+    let has_audio = manifest().features.audio;
+    let hear = from_sound() && has_audio;
+    // hearing a letter needs its recording; reading one doesn't
+    let pool: Vec<Letter> = drill_pool(&letters)
+        .into_iter()
+        .filter(|l| !hear || l.audio.is_some())
+        .collect();
+    let sets = confusable_sets(&pool, &manifest().confusables);
+    let has_sets = !sets.is_empty();
+    let in_confusable = confusable() && has_sets;
+
+    let play = move |l: &Letter| {
+        if let Some(file) = l.audio.as_deref() {
+            crate::audio::play_letter(&lang(), file, settings.read().volume);
+        }
+    };
+
+    let begin = {
+        let sets = sets.clone();
+        let pool = pool.clone();
+        move || {
+            let r = new_round(&pool, &sets, in_confusable);
+            if let Some(r) = &r {
+                if hear {
+                    play(&r.target);
+                }
+            }
+            round.set(r);
+            picked.set(None);
+            typed.set(String::new());
+            shown_at.set(crate::learner::now_ms());
+        }
+    };
+
+    let answer = move |choice: String| {
+        let Some(r) = round.peek().clone() else {
+            return;
+        };
+        if picked.peek().is_some() {
+            return;
+        }
+        let ok = choice == r.target.letter;
+        let latency = crate::learner::now_ms().saturating_sub(shown_at()) as u32;
+        let evidence = crate::learner::letter_sound_evidence(
+            &lang(),
+            &r.target.letter,
+            hear,
+            r.confusable,
+            if ok { 1.0 } else { 0.0 },
+            latency,
+        );
+        learner.emit_all(if probe {
+            crate::learner::as_probe(evidence)
+        } else {
+            evidence
+        });
+        attempts.with_mut(|a| *a += 1);
+        if ok {
+            correct.with_mut(|c| *c += 1);
+        }
+        picked.set(Some((choice, ok)));
+        // reading: now let them hear the answer
+        if !hear {
+            play(&r.target);
+        }
+    };
+
+    let cor = correct();
+    let att = attempts();
+    let typed_now = typed();
+    let current = round();
+    let prompt = current.as_ref().map(|r| r.target.clone());
+    let choice_rows: Vec<(String, String, &'static str)> = match &current {
+        Some(r) => r
+            .options
+            .iter()
+            .map(|l| {
+                let label = if hear {
+                    l.letter.clone()
+                } else {
+                    format!("{} {}", l.name, l.pron)
+                };
+                let style = match picked() {
+                    Some(_) if l.letter == r.target.letter => "border-green-500 text-green-300",
+                    Some((p, false)) if p == l.letter => "border-red-500 text-red-300",
+                    _ => "border-gray-600 hover:border-indigo-500",
+                };
+                (l.letter.clone(), label, style)
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+    let choice_text = if hear { "text-3xl" } else { "text-base" };
+    let verdict = match (picked(), &prompt) {
+        (Some((_, true)), Some(l)) => {
+            Some((true, format!("✓ {} — {} {}", l.letter, l.name, l.pron)))
+        }
+        (Some((_, false)), Some(l)) => Some((
+            false,
+            format!("✗ it was {} — {} {}", l.letter, l.name, l.pron),
+        )),
+        _ => None,
+    };
+    let dir_explain = if hear {
+        "Hear a letter, pick (or type) it."
+    } else {
+        "See a letter, pick its sound."
+    };
+
+    rsx! {
+        div { class: "w-full max-w-xl mx-auto flex flex-col items-center gap-4 mt-2",
+            div { class: "flex items-center gap-2",
+                span { class: "text-xs text-gray-400", "Direction:" }
+                button {
+                    class: format!("px-3 py-1 rounded text-sm {}", if hear { "bg-indigo-600 text-white" } else { "bg-gray-700 text-gray-300" }),
+                    disabled: !has_audio,
+                    onclick: move |_| {
+                        from_sound.set(true);
+                        round.set(None);
+                    },
+                    "Sound → letter"
+                }
+                button {
+                    class: format!("px-3 py-1 rounded text-sm {}", if !hear { "bg-indigo-600 text-white" } else { "bg-gray-700 text-gray-300" }),
+                    onclick: move |_| {
+                        from_sound.set(false);
+                        round.set(None);
+                    },
+                    "Letter → sound"
+                }
+            }
+            if has_sets {
+                div { class: "flex items-center gap-2",
+                    span { class: "text-xs text-gray-400", "Letters:" }
+                    button {
+                        class: format!("px-3 py-1 rounded text-sm {}", if !in_confusable { "bg-indigo-600 text-white" } else { "bg-gray-700 text-gray-300" }),
+                        onclick: move |_| {
+                            confusable.set(false);
+                            round.set(None);
+                        },
+                        "All"
+                    }
+                    button {
+                        class: format!("px-3 py-1 rounded text-sm {}", if in_confusable { "bg-indigo-600 text-white" } else { "bg-gray-700 text-gray-300" }),
+                        onclick: move |_| {
+                            confusable.set(true);
+                            round.set(None);
+                        },
+                        "Confusable pairs"
+                    }
+                }
+            }
+            div { class: "text-xs text-gray-500", "{dir_explain}" }
+
+            if current.is_none() {
+                if pool.len() < 2 {
+                    div { class: "text-sm text-gray-400 text-center", "Not enough letters to drill." }
+                } else {
+                    button {
+                        class: "mt-2 px-5 py-2 rounded bg-green-600 hover:bg-green-500 text-white font-semibold",
+                        onclick: {
+                            let mut begin = begin.clone();
+                            move |_| begin()
+                        },
+                        "Start"
+                    }
+                }
+            } else {
+                div { class: "flex justify-end w-full text-sm text-gray-400", "{cor} / {att}" }
+
+                div { class: "w-full rounded-lg bg-gray-900/60 border border-gray-700 p-6 flex flex-col items-center gap-2",
+                    if hear {
+                        button {
+                            class: "text-4xl hover:cursor-pointer hover:scale-110 transition-transform",
+                            title: "Play again",
+                            onclick: {
+                                let target = current.as_ref().map(|r| r.target.clone());
+                                move |_| {
+                                    if let Some(t) = &target {
+                                        play(t);
+                                    }
+                                }
+                            },
+                            "🔊"
+                        }
+                    } else if let Some(l) = &prompt {
+                        span { class: "text-6xl font-bold", "{l.letter}" }
+                    }
+                }
+
+                div { class: "flex flex-wrap justify-center gap-3",
+                    for (glyph, label, style) in choice_rows {
+                        button {
+                            key: "{glyph}",
+                            class: "px-4 py-2 rounded-lg border-2 bg-gray-800 hover:cursor-pointer {style} {choice_text}",
+                            onclick: {
+                                let mut answer = answer.clone();
+                                move |_| answer(glyph.clone())
+                            },
+                            "{label}"
+                        }
+                    }
+                }
+
+                if hear {
+                    input {
+                        r#type: "text",
+                        value: "{typed_now}",
+                        placeholder: "or type it",
+                        autocomplete: "off",
+                        autocorrect: "off",
+                        spellcheck: "false",
+                        class: "w-32 text-center text-2xl bg-transparent border-b-2 border-gray-600 focus:border-indigo-400 outline-none py-1",
+                        oninput: move |e: FormEvent| typed.set(e.value()),
+                        onkeydown: {
+                            let pool = pool.clone();
+                            let mut answer = answer.clone();
+                            let mut begin = begin.clone();
+                            move |e: KeyboardEvent| {
+                                if e.key().to_string() != "Enter" {
+                                    return;
+                                }
+                                if picked.peek().is_some() {
+                                    begin();
+                                    return;
+                                }
+                                let t = typed().trim().to_string();
+                                if t.is_empty() {
+                                    return;
+                                }
+                                // a typed glyph that isn't among the choices is still wrong
+                                let choice = pool.iter().find(|l| l.letter == t || l.letter.to_lowercase() == t.to_lowercase());
+                                answer(choice.map_or(t, |l| l.letter.clone()));
+                            }
+                        },
+                    }
+                }
+
+                if let Some((ok, text)) = verdict {
+                    div { class: if ok { "text-sm text-center text-green-400" } else { "text-sm text-center text-red-400" }, "{text}" }
+                    button {
+                        class: "px-4 py-1 rounded bg-gray-700 hover:bg-gray-600 text-gray-200 text-sm",
+                        onclick: {
+                            let mut begin = begin.clone();
+                            move |_| begin()
+                        },
+                        "Next"
+                    }
+                }
+
+                button {
+                    class: "text-xs text-gray-500 hover:text-gray-300 mt-1",
+                    onclick: move |_| round.set(None),
+                    "Stop"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,372 @@
+// src/components/listening_test.rs
+//
+// Dictation: hear a word (the pack's recording if it has one, else espeak) and
+// type what you heard. The answer toggle picks what to type back:
+//   - in the target language → spell what you heard (words or example phrases)
+//   - in English              → say what the word means
+// Graded by the shared typing_engine core and logged as Listening evidence,
+// with latency counted from the end of playback rather than from the prompt,
+// so a long clip doesn't read as a slow answer. A clip that played nothing
+// (no recording, no voice) is skipped rather than graded.
+
+use dioxus::events::{FormEvent, KeyboardEvent};
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::audio::Playback;
+use crate::components::drills::typing_engine::{
+    accepted_answers, grade_answer, grade_senses, tokenize,
+};
+use crate::langpack::use_manifest;
+use crate::models::lexicon::{LexEntry, Sense};
+
+/// One thing to listen to: a head word, or an example phrase.
+#[derive(Clone, PartialEq)]
+struct Clip {
+    text: String,
+    /// Frequency rank for words; `None` for phrases.
+    rank: Option<u32>,
+    audio: Option<String>,
+    senses: Vec<Sense>,
+}
+
+/// The pool for one mode: head words, or every L2 example among them.
+fn clips(words: &[LexEntry], phrases: bool) -> Vec<Clip> {
+    if !phrases {
+        return words
+            .iter()
+            .map(|e| Clip {
+                text: e.word.clone(),
+                rank: Some(e.rank),
+                audio: e.audio.clone(),
+                senses: e.senses.clone(),
+            })
+            .collect();
+    }
+    let mut out: Vec<Clip> = Vec::new();
+    for e in words {
+        let examples = e
+            .example
+            .iter()
+            .chain(e.senses.iter().flat_map(|s| &s.examples));
+        for ex in examples {
+            let text = ex.trim();
+            if !text.is_empty() && !out.iter().any(|c| c.text == text) {
+                out.push(Clip {
+                    text: text.to_string(),
+                    rank: None,
+                    audio: None,
+                    senses: Vec::new(),
+                });
+            }
+        }
+    }
+    out
+}
+
+/// What a dictation is compared on: the words alone, without stress marks,
+/// case or punctuation, none of which can be heard.
+fn heard(s: &str) -> String {
+    tokenize(&crate::models::translit::fold(s)).join(" ")
+}
+
+/// Playback state shared by the handlers that start a clip.
+#[derive(Clone, Copy)]
+struct Player {
+    playback: Signal<Playback>,
+    /// When the current clip finished; `None` while it's still playing.
+    ended_at: Signal<Option<u64>>,
+    /// Bumped per play so a stale watcher doesn't stamp a newer clip.
+    generation: Signal<u32>,
+}
+
+impl Player {
+    fn play(mut self, lang: &str, clip: &Clip, volume: f32, tts: bool) {
+        let gen = *self.generation.peek() + 1;
+        self.generation.set(gen);
+        let p = crate::audio::say(lang, clip.audio.as_deref(), &clip.text, volume, tts);
+        self.ended_at.set(None);
+        self.playback.set(p.clone());
+        spawn(async move {
+            while crate::audio::still_playing(&p) {
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                if *self.generation.peek() != gen {
+                    return;
+                }
+            }
+            if *self.generation.peek() == gen {
+                self.ended_at.set(Some(crate::learner::now_ms()));
+            }
+        });
+    }
+}
+
+#[component]
+pub fn ListeningTest(
+    words: Vec<LexEntry>,
+    lang: Signal<String>,
+    /// Log answers as planner probes (`Source::Probe`).
+    #[props(default)]
+    probe: bool,
+) -> Element {
+    let mut running = use_signal(|| false);
+    let mut phrases = use_signal(|| false);
+    let mut to_english = use_signal(|| false);
+    let mut idx = use_signal(|| 0usize);
+    let mut typed = use_signal(String::new);
+    let mut correct = use_signal(|| 0u32);
+    let mut attempts = use_signal(|| 0u32);
+    // (correct, what you typed, the answer)
+    let mut answered = use_signal(|| None::<(bool, String, String)>);
+    let player = Player {
+        playback: use_signal(|| Playback::Silent),
+        ended_at: use_signal(|| None::<u64>),
+        generation: use_signal(|| 0u32),
+    };
+
+    let learner = crate::learner::use_learner();
+    let settings = crate::settings::use_settings();
+    let manifest = use_manifest();
+
+    // reset to the setup screen when the language changes
+    use_effect(move || {
+        let _ = lang();
+        running.set(false);
+        typed.set(String::new());
+        answered.set(None);
+        correct.set(0);
+        attempts.set(0);
+    });
+
+    let in_phrases = phrases();
+    let dir_en = to_english() && !in_phrases;
+    let pool = clips(&words, in_phrases);
+    let pool_len = pool.len();
+    let cur = (pool_len > 0).then(|| pool[idx().min(pool_len - 1)].clone());
+    let target_name = manifest().name;
+
+    let typed_now = typed();
+    let cor = correct();
+    let att = attempts();
+    let silent = running() && *player.playback.read() == Playback::Silent;
+    let playing = !silent && player.ended_at.read().is_none();
+    let answer_hint = if dir_en {
+        "type what it means in English".to_string()
+    } else {
+        format!("type what you heard in {target_name}")
+    };
+    let empty_note = if in_phrases {
+        "None of these words has an example phrase yet."
+    } else {
+        "No words match the current filters / rank range."
+    };
+
+    let start = {
+        let pool = pool.clone();
+        move |_| {
+            if pool.is_empty() {
+                return;
+            }
+            correct.set(0);
+            attempts.set(0);
+            answered.set(None);
+            typed.set(String::new());
+            let i = rand::rng().random_range(0..pool.len());
+            idx.set(i);
+            running.set(true);
+            let s = settings.read();
+            player.play(&lang(), &pool[i], s.volume, s.tts_enabled);
+        }
+    };
+    let replay = {
+        let cur = cur.clone();
+        move |_| {
+            if let Some(clip) = &cur {
+                let s = settings.read();
+                player.play(&lang(), clip, s.volume, s.tts_enabled);
+            }
+        }
+    };
+    let on_key = {
+        let pool = pool.clone();
+        move |e: KeyboardEvent| {
+            if e.key().to_string() != "Enter" || pool.is_empty() {
+                return;
+            }
+            // second Enter, or a clip that played nothing: on to the next one.
+            // An unheard clip is skipped, not graded.
+            if answered.peek().is_some() || *player.playback.peek() == Playback::Silent {
+                let cur_i = idx();
+                let mut n = rand::rng().random_range(0..pool.len());
+                if pool.len() > 1 && n == cur_i {
+                    n = (n + 1) % pool.len();
+                }
+                idx.set(n);
+                typed.set(String::new());
+                answered.set(None);
+                let s = settings.read();
+                player.play(&lang(), &pool[n], s.volume, s.tts_enabled);
+                return;
+            }
+            let ans = typed();
+            if ans.trim().is_empty() {
+                return;
+            }
+            let clip = &pool[idx().min(pool.len() - 1)];
+            let (g, shown) = if dir_en {
+                let (g, _) = grade_senses(&ans, &clip.senses);
+                let gloss = clip
+                    .senses
+                    .iter()
+                    .map(Sense::gloss)
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                (g, format!("{} — {gloss}", clip.text))
+            } else {
+                let accepted: Vec<String> = if clip.rank.is_some() {
+                    accepted_answers(&clip.text)
+                        .iter()
+                        .map(|a| heard(a))
+                        .collect()
+                } else {
+                    vec![heard(&clip.text)]
+                };
+                (grade_answer(&heard(&ans), &accepted), clip.text.clone())
+            };
+            let was_correct = g >= 0.85;
+            // answering before playback ends counts as instant
+            let latency = player
+                .ended_at
+                .peek()
+                .map_or(0, |t| crate::learner::now_ms().saturating_sub(t) as u32);
+            let evidence = crate::learner::listening_evidence(
+                &lang(),
+                &clip.text,
+                clip.rank,
+                dir_en,
+                g,
+                latency,
+            );
+            learner.emit_all(if probe {
+                crate::learner::as_probe(evidence)
+            } else {
+                evidence
+            });
+            attempts.with_mut(|a| *a += 1);
+            if was_correct {
+                correct.with_mut(|c| *c += 1);
+            }
+            answered.set(Some((was_correct, ans, shown)));
+        }
+    };
+
+    rsx! {
+        div { class: "w-full max-w-xl mx-auto flex flex-col items-center gap-4 mt-2",
+
+            if !running() {
+                div { class: "text-center text-gray-300 text-sm",
+                    "Hear it, type it. Enter checks your answer; Enter again plays the next one."
+                }
+
+                div { class: "flex items-center gap-2",
+                    span { class: "text-xs text-gray-400", "Listen to:" }
+                    button {
+                        class: format!("px-3 py-1 rounded text-sm {}", if !in_phrases { "bg-indigo-600 text-white" } else { "bg-gray-700 text-gray-300" }),
+                        onclick: move |_| phrases.set(false),
+                        "Words"
+                    }
+                    button {
+                        class: format!("px-3 py-1 rounded text-sm {}", if in_phrases { "bg-indigo-600 text-white" } else { "bg-gray-700 text-gray-300" }),
+                        onclick: move |_| phrases.set(true),
+                        "Phrases"
+                    }
+                }
+
+                if !in_phrases {
+                    div { class: "flex items-center gap-2",
+                        span { class: "text-xs text-gray-400", "Answer in:" }
+                        button {
+                            class: format!("px-3 py-1 rounded text-sm {}", if !dir_en { "bg-indigo-600 text-white" } else { "bg-gray-700 text-gray-300" }),
+                            onclick: move |_| to_english.set(false),
+                            "{target_name}"
+                        }
+                        button {
+                            class: format!("px-3 py-1 rounded text-sm {}", if dir_en { "bg-indigo-600 text-white" } else { "bg-gray-700 text-gray-300" }),
+                            onclick: move |_| to_english.set(true),
+                            "English"
+                        }
+                    }
+                }
+
+                if att > 0 {
+                    div { class: "text-sm text-gray-400", "Last round: {cor} / {att}" }
+                }
+
+                if pool_len == 0 {
+                    div { class: "text-sm text-gray-400 text-center", "{empty_note}" }
+                } else {
+                    button {
+                        class: "mt-2 px-5 py-2 rounded bg-green-600 hover:bg-green-500 text-white font-semibold",
+                        onclick: start,
+                        "Start"
+                    }
+                }
+            } else {
+                div { class: "flex justify-between w-full text-sm",
+                    span { class: "text-gray-500 text-xs", "{answer_hint}" }
+                    span { class: "text-gray-400", "{cor} / {att}" }
+                }
+
+                div { class: "w-full rounded-lg bg-gray-900/60 border border-gray-700 p-6 flex flex-col items-center gap-2",
+                    button {
+                        class: "text-4xl hover:cursor-pointer hover:scale-110 transition-transform",
+                        title: "Play again",
+                        onclick: replay,
+                        "🔊"
+                    }
+                    if silent {
+                        span { class: "text-xs text-amber-400 text-center",
+                            "Nothing to play: no recording for this one, and pronunciation is off or has no {target_name} voice. Turn it on in Settings, or press Enter to skip."
+                        }
+                    } else if playing {
+                        span { class: "text-xs text-gray-500", "playing…" }
+                    } else {
+                        span { class: "text-xs text-gray-500", "click to hear it again" }
+                    }
+                }
+
+                input {
+                    r#type: "text",
+                    value: "{typed_now}",
+                    autocomplete: "off",
+                    autocorrect: "off",
+                    spellcheck: "false",
+                    class: "w-full text-center text-2xl bg-transparent border-b-2 border-gray-600 focus:border-indigo-400 outline-none py-2",
+                    oninput: move |e: FormEvent| typed.set(e.value()),
+                    onmounted: move |e| {
+                        let el = e.data();
+                        spawn(async move { let _ = el.set_focus(true).await; });
+                    },
+                    onkeydown: on_key,
+                }
+
+                if let Some((ok, your, answer)) = answered() {
+                    if ok {
+                        div { class: "text-sm text-center text-green-400", "✓ {answer}" }
+                    } else {
+                        div { class: "text-sm text-center text-red-400",
+                            "✗ you typed '{your}' — it was {answer}"
+                        }
+                    }
+                    div { class: "text-xs text-gray-500", "Enter for the next one" }
+                }
+
+                button {
+                    class: "text-xs text-gray-500 hover:text-gray-300 mt-1",
+                    onclick: move |_| running.set(false),
+                    "Stop"
+                }
+            }
+        }
+    }
+}
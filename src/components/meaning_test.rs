@@ -11,7 +11,7 @@ use dioxus::events::{FormEvent, KeyboardEvent};
 use dioxus::prelude::*;
 use rand::Rng;
 
-use crate::components::drills::typing_engine::{accepted_answers, grade_answer};
+use crate::components::drills::typing_engine::{accepted_answers, grade_answer, grade_senses};
 use crate::models::lexicon::LexEntry;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -97,7 +97,7 @@ pub fn MeaningTest(words: Vec<LexEntry>, lang: Signal<String>) -> Element {
         ),
         Some(e) => (
             "English".to_string(),
-            e.en.clone(),
+            e.gloss(),
             format!("type the word in {target_name}"),
         ),
         None => (String::new(), String::new(), String::new()),
@@ -210,21 +210,30 @@ pub fn MeaningTest(words: Vec<LexEntry>, lang: Signal<String>) -> Element {
                         if e.key().to_string() == "Enter" && !typed().trim().is_empty() {
                             if let Some(entry) = cur_for_submit.clone() {
                                 let ans = typed();
-                                let accepted = if dir_en {
-                                    accepted_answers(&entry.en)
+                                // meanings grade per sense; a hit on a later sense says which
+                                let (g, shown) = if dir_en {
+                                    let (g, sense) = grade_senses(&ans, &entry.senses);
+                                    let shown = match sense {
+                                        Some(i) if g >= 0.85 && entry.senses.len() > 1 => {
+                                            format!("sense {}: {}", i + 1, entry.senses[i].gloss())
+                                        }
+                                        _ if g >= 0.85 => String::new(),
+                                        _ => entry.gloss(),
+                                    };
+                                    (g, shown)
                                 } else {
-                                    accepted_answers(&entry.word)
+                                    let accepted = accepted_answers(&entry.word);
+                                    (grade_answer(&ans, &accepted), accepted.join(", "))
                                 };
-                                let g = grade_answer(&ans, &accepted);
                                 let was_correct = g >= 0.85;
                                 let latency = crate::learner::now_ms().saturating_sub(prompt_start()) as u32;
                                 let headword = entry.word.clone();
-                                learner.emit_all(crate::learner::meaning_evidence(&lang(), dir_en, &headword, g, latency));
+                                learner.emit_all(crate::learner::meaning_evidence(&lang(), dir_en, &headword, Some(entry.rank), g, latency));
                                 attempts.with_mut(|a| *a += 1);
                                 if was_correct {
                                     correct.with_mut(|c| *c += 1);
                                 }
-                                last.set(Some((was_correct, ans.clone(), accepted.join(", "))));
+                                last.set(Some((was_correct, ans.clone(), shown)));
                                 if pool_len > 0 {
                                     let mut rng = rand::rng();
                                     let cur_i = idx();
@@ -242,7 +251,9 @@ pub fn MeaningTest(words: Vec<LexEntry>, lang: Signal<String>) -> Element {
                 }
 
                 if let Some((ok, your, acc)) = last() {
-                    if ok {
+                    if ok && !acc.is_empty() {
+                        div { class: "text-sm text-center text-green-400", "✓ correct — {acc}" }
+                    } else if ok {
                         div { class: "text-sm text-center text-green-400", "✓ correct" }
                     } else {
                         div { class: "text-sm text-center text-red-400",
@@ -7,7 +7,10 @@ pub mod dict_search;
 pub mod dictionary;
 pub mod grammar;
 pub mod grammar_quiz;
+pub mod key_calibration;
 pub mod keyboard;
+pub mod letter_drill;
+pub mod listening_test;
 pub mod meaning_test;
 pub mod settings;
 pub mod typing_test;
@@ -20,7 +23,10 @@ pub use dict_search::DictSearch;
 pub use dictionary::Dictionary;
 pub use grammar::Grammar;
 pub use grammar_quiz::GrammarQuiz;
+pub use key_calibration::KeyCalibration;
 pub use keyboard::Keyboard;
+pub use letter_drill::LetterDrill;
+pub use listening_test::ListeningTest;
 pub use meaning_test::MeaningTest;
 pub use settings::SettingsButton;
 pub use typing_test::TypingTest;
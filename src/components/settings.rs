@@ -1,9 +1,10 @@
 // src/components/settings.rs
 //
-// Settings modal: volume, boot/default language, and an OPT-IN Pronunciation
-// (TTS) section. TTS is off by default; the toggle is the opt-in. When on, it
-// detects espeak-ng and — if missing — shows install guidance branched per OS
-// with cfg!(windows) (NOT the windows_subsystem attribute, which is unrelated).
+// Settings modal: volume, boot/default language, keyboard layout, and an OPT-IN
+// Pronunciation (TTS) section. TTS is off by default; the toggle is the opt-in.
+// When on, it detects espeak-ng and — if missing — shows install guidance
+// branched per OS with cfg!(windows) (NOT the windows_subsystem attribute,
+// which is unrelated).
 
 use dioxus::prelude::*;
 use dioxus_primitives::slider::SliderValue;
@@ -11,28 +12,22 @@ use dioxus_primitives::slider::SliderValue;
 use crate::components::dialog::{DialogContent, DialogDescription, DialogRoot, DialogTitle};
 use crate::components::slider::{Slider, SliderRange, SliderThumb, SliderTrack};
 use crate::components::toggle::Toggle;
+use crate::langpack::{use_manifest, use_registry};
 use crate::settings::use_settings;
 
-const LANGS: [(&str, &str); 2] = [("georgian", "Georgian"), ("russian", "Russian")];
-
-fn nice(lang: &str) -> String {
-    let mut c = lang.chars();
-    match c.next() {
-        Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
-        None => "this language".to_string(),
-    }
-}
-
 #[component]
 pub fn SettingsButton() -> Element {
     let mut open = use_signal(|| false);
     let mut settings = use_settings();
     let mut active_lang = use_context::<Signal<String>>();
+    let registry = use_registry();
+    let manifest = use_manifest();
 
     let volume = settings.read().volume;
     let volume_pct = (volume * 100.0).round() as i32;
     let default_lang = settings.read().default_language.clone();
     let tts_enabled = settings.read().tts_enabled;
+    let layout_id = settings.read().keyboard_layout.clone();
 
     // ── TTS detection (re-runs on language change or manual re-check) ──
     let mut recheck = use_signal(|| 0u32);
@@ -48,7 +43,7 @@ pub fn SettingsButton() -> Element {
         }
     });
     let probe_now = probe.read().clone();
-    let lang_name = nice(&active_lang());
+    let lang_name = manifest().name;
 
     // Platform-specific "how to install the engine" guidance.
     let (engine_hint, engine_cmd): (&str, Option<&str>) = if cfg!(windows) {
@@ -111,7 +106,7 @@ pub fn SettingsButton() -> Element {
                 div { style: "margin-top:1.25rem;",
                     div { style: "font-size:0.85rem; margin-bottom:0.35rem;", "Default language" }
                     div { style: "display:flex; gap:0.5rem;",
-                        for (code, name) in LANGS {
+                        for (code, name) in registry().into_iter().map(|m| (m.code, m.name)) {
                             button {
                                 key: "{code}",
                                 style: format!(
@@ -121,8 +116,8 @@ pub fn SettingsButton() -> Element {
                                     if default_lang == code { "#ffffff" } else { "#d1d5db" },
                                 ),
                                 onclick: move |_| {
-                                    settings.with_mut(|s| s.default_language = code.to_string());
-                                    active_lang.set(code.to_string());
+                                    settings.with_mut(|s| s.default_language = code.clone());
+                                    active_lang.set(code.clone());
                                 },
                                 "{name}"
                             }
@@ -133,6 +128,29 @@ pub fn SettingsButton() -> Element {
                     }
                 }
 
+                // ── physical keyboard (drives the on-screen keyboard and key hints) ──
+                div { style: "margin-top:1.25rem;",
+                    div { style: "font-size:0.85rem; margin-bottom:0.35rem;", "Keyboard layout" }
+                    div { style: "display:flex; flex-wrap:wrap; gap:0.5rem;",
+                        for p in crate::layouts::profiles() {
+                            button {
+                                key: "{p.id}",
+                                style: format!(
+                                    "padding:0.3rem 0.8rem; border-radius:0.5rem; cursor:pointer; border:1px solid {}; background:{}; color:{};",
+                                    if layout_id == p.id { "#818cf8" } else { "#374151" },
+                                    if layout_id == p.id { "#4f46e5" } else { "transparent" },
+                                    if layout_id == p.id { "#ffffff" } else { "#d1d5db" },
+                                ),
+                                onclick: move |_| settings.with_mut(|s| s.keyboard_layout = p.id.clone()),
+                                "{p.name}"
+                            }
+                        }
+                    }
+                    div { style: "font-size:0.7rem; opacity:0.6; margin-top:0.4rem;",
+                        "The keyboard you type on. Different printed labels? Calibrate Keys on the typing page."
+                    }
+                }
+
                 // ── pronunciation / text-to-speech (opt-in) ──
                 div { style: "margin-top:1.25rem;",
                     div { style: "display:flex; justify-content:space-between; align-items:center; gap:0.75rem;",
@@ -1,6 +1,5 @@
-use crate::assets::freq_json_for;
 use crate::components::input::Input;
-use crate::components::keyboard::code_to_qwerty_label;
+use crate::components::listening_test::ListeningTest;
 use crate::components::meaning_test::MeaningTest;
 use crate::components::radio_group::{RadioGroup, RadioItem};
 use crate::components::slider::{Slider, SliderRange, SliderThumb, SliderTrack};
@@ -12,16 +11,13 @@ use crate::models::{letter::Letter, lexicon::LexEntry};
 use crate::settings::use_settings;
 /**
  * TODO:
- * implementing user data{
- * 	calibrations for each key (utilize right click menu)
- * }
  * make generalized functions where:
  *  read json file, manipulate it in memory, save it once done, repeat
  *
  *
  *
  */
-use dioxus::events::FormEvent;
+use dioxus::events::{FormEvent, KeyboardEvent};
 use dioxus::prelude::*;
 use dioxus_primitives::slider::SliderValue;
 use dioxus_primitives::{ContentAlign, ContentSide};
@@ -34,8 +30,13 @@ enum TestMode {
     Sequential,
     Random,
     Bounded,
+    /// Words full of the letters most often mistyped (`keystrokes::drill_words`).
+    Trouble,
 }
 
+/// Worst mix-ups the trouble-letter mode drills at once.
+const TROUBLE_PAIRS: usize = 3;
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum BoundedOrder {
     Sequential,
@@ -65,6 +66,7 @@ impl TestMode {
             TestMode::Sequential => "sequential",
             TestMode::Random => "random",
             TestMode::Bounded => "bounded",
+            TestMode::Trouble => "trouble",
         }
     }
 
@@ -72,6 +74,7 @@ impl TestMode {
         match s {
             "sequential" => TestMode::Sequential,
             "bounded" => TestMode::Bounded,
+            "trouble" => TestMode::Trouble,
             _ => TestMode::Random,
         }
     }
@@ -184,6 +187,11 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
     // Empty = treat as "all enabled"
     let mut active_pos = use_signal(|| Vec::<String>::new());
     let mut test_mode = use_signal(|| TestMode::Random);
+    // worst mix-ups, snapshotted when trouble-letter mode is picked so the
+    // word list doesn't reshuffle under you as new keystrokes come in
+    let mut trouble = use_signal(Vec::<(char, char, u32)>::new);
+    let keystrokes = crate::keystrokes::use_keystrokes();
+    let keymap = crate::calibration::use_keymap();
     let mut min_rank = use_signal(|| 1u32);
     let mut max_rank = use_signal(|| 250u32);
     let mut bounded_order = use_signal(|| BoundedOrder::Random);
@@ -193,23 +201,25 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
         let lang_name = lang.read().clone();
 
         async move {
-            let json = freq_json_for(&lang_name);
-
-            serde_json::from_str::<Vec<LexEntry>>(json).unwrap_or_else(|e| {
-                eprintln!("Failed to parse 1000.json for {lang_name}: {e}");
-                Vec::new()
-            })
+            crate::langpack::lexicon(&lang_name)
+                .map(|lex| lex.all().to_vec())
+                .inspect_err(|e| eprintln!("{e}"))
         }
     });
 
-    let words = words_res.read().clone().unwrap_or_default();
+    let loaded = words_res.read().clone();
+    let load_error = loaded.as_ref().and_then(|r| r.as_ref().err().cloned());
+    let words = loaded.and_then(Result::ok).unwrap_or_default();
     if words.is_empty() {
         return rsx! {
             section { class: "p-6 flex justify-center",
                 div { class: "text-gray-300 text-center space-y-2",
                     div { "No frequency list loaded (1000.json)." }
+                    if let Some(err) = load_error {
+                        div { class: "text-xs text-red-300 font-mono", "{err}" }
+                    }
                     div { class: "text-xs text-gray-500",
-                        "Check that your 1000.json exists and is valid."
+                        "Check that your 1000.json exists and is valid — `lang-sprint-validate <pack dir>` lists every problem."
                     }
                 }
             }
@@ -256,11 +266,22 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
     let lo = min_rank();
     let hi = max_rank().max(lo); // keep hi >= lo
 
+    // …or narrow to the letters the keystroke log says get mixed up
+    let trouble_pairs = trouble();
+    let trouble_note = trouble_pairs
+        .iter()
+        .map(|(e, t, n)| format!("{e}→{t} ×{n}"))
+        .collect::<Vec<_>>()
+        .join(" · ");
+
     let filtered_words: Vec<LexEntry> = match mode_now {
         TestMode::Bounded => words_pos_filtered
             .into_iter()
             .filter(|w| w.rank >= lo && w.rank <= hi)
             .collect(),
+        TestMode::Trouble if !trouble_pairs.is_empty() => {
+            crate::keystrokes::drill_words(&words_pos_filtered, &trouble_pairs)
+        }
         _ => words_pos_filtered,
     };
 
@@ -276,6 +297,8 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
     } else {
         (None, String::new())
     };
+    let target_rank = current_opt.as_ref().map(|c| c.rank);
+    let current_gloss = current_opt.as_ref().map(|c| c.gloss()).unwrap_or_default();
 
     let typed_now = typed();
 
@@ -290,19 +313,21 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
             .zip(target_chars.iter())
             .all(|(a, b)| a == b);
 
-    // Map Georgian char -> "J" or "⇧J" depending on whether it's shifted
+    // Map Georgian char -> "J" or "⇧J" depending on whether it's shifted,
+    // labelled as printed on the learner's keyboard
+    let keymap_now = keymap();
     let mut hint_map: HashMap<char, String> = HashMap::new();
 
-    for letter in &letters_vec {
+    for letter in &keymap_now.apply(letters_vec.clone()) {
         if let Some(ch) = letter.letter.chars().next() {
-            let base = code_to_qwerty_label(&letter.key_code);
+            let base = keymap_now.label(&letter.key_code);
             if !base.is_empty() {
                 let rendered = if letter.shifted {
                     // whatever style you like:
                     // format!("Shift+{}", base)
                     format!("⇧{}", base)
                 } else {
-                    base.to_string()
+                    base
                 };
 
                 // if multiple entries share same char, last one wins; that's fine here
@@ -332,6 +357,7 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
 
     // ── learning-engine hook (word drill) ────────────────────────────────
     let learner = crate::learner::use_learner();
+    let mut capture = crate::keystrokes::use_capture();
     let mut word_start = use_signal(|| crate::learner::now_ms());
     use_effect(move || {
         let _ = current_index(); // reset the per-word timer on change
@@ -378,6 +404,7 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
                 learner.emit_all(crate::learner::word_drill_evidence(
                     &lang_snapshot,
                     &target_snapshot,
+                    target_rank,
                     latency,
                 ));
 
@@ -403,7 +430,7 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
                         }
                     }
 
-                    TestMode::Random => {
+                    TestMode::Random | TestMode::Trouble => {
                         let mut rng = rand::rng();
                         let mut n = rng.random_range(0..len_snapshot);
 
@@ -435,6 +462,7 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
 
                 idx_sig.set(next);
                 typed_sig.set(String::new());
+                capture.end_run();
                 progress_sig.set(None);
             }
         });
@@ -564,6 +592,7 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
             // ── reset drill state ─────────────────────────────
             current_index.set(0);
             typed_sig.set(String::new());
+            capture.end_run();
             active_pos_sig.set(Vec::new()); // “all POS” again
             test_mode_sig.set(TestMode::Random); // or whatever default you want
             min_rank_sig.set(1);
@@ -595,6 +624,7 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
 
                         // reset drill state
                         typed.set(String::new());
+                        capture.end_run();
                         current_index.set(0);
 
                         // reset wpm state
@@ -621,6 +651,11 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
                                                          value: "meaning".to_string(),
                                                          "Meaning"
                                                      }
+                        TabTrigger {
+                            index: 3usize,
+                            value: "listening".to_string(),
+                            "Listening"
+                        }
                         // you can add more TabTrigger here for other tests
                     }
 
@@ -643,8 +678,14 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
                             input {
                                 r#type: "text",
                                 value: "{typed_now}",
-                                oninput: move |evt: FormEvent| {
-                                    typed.set(evt.value());
+                                onkeydown: move |evt: KeyboardEvent| capture.key_down(evt.code().to_string()),
+                                oninput: {
+                                    let target = target_word.clone();
+                                    move |evt: FormEvent| {
+                                        let v = evt.value();
+                                        capture.input(&lang.peek(), &target, &typed.peek(), &v);
+                                        typed.set(v);
+                                    }
                                 },
                                 onfocus: move |_| {
                                     input_focused.set(true);
@@ -719,9 +760,7 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
                                                                     onclick: move |_| {
                                                                         if let Some(file) = audio_file.as_deref() {
                                                                             let l = lang();
-                                                                            if let Some(bytes) = crate::assets::letter_audio_bytes(&l, file) {
-                                                                                crate::audio::play_audio_bytes(&format!("{l}/{file}"), bytes, 1.0);
-                                                                            }
+                                                                            crate::audio::play_letter(&l, file, 1.0);
                                                                         }
                                                                     },
                                                                     span { class: "{class} font-bold", "{ch}" }
@@ -826,7 +865,7 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
                                 div { class: "flex flex-col items-center gap-2",
                                     span { "Rank: #" b{"{current.rank}"} }
                                     span {
-                                        "Translation: {current.en} \u{00A0}"
+                                        "Translation: {current_gloss} \u{00A0}"
                                         if let Some(pos) = current.pos.clone() {
                                             span {
                                                 class: "px-2 py-0.5 rounded-full bg-indigo-900 text-indigo-200 \
@@ -861,6 +900,11 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
                                                 value: "meaning".to_string(),
                                                 MeaningTest { words: filtered_words.clone(), lang }
                                             }
+                   TabContent {
+                       index: 3usize,
+                       value: "listening".to_string(),
+                       ListeningTest { words: filtered_words.clone(), lang }
+                   }
                     //settings
                 if show_settings() {
                     div { class:"flex flex-col items-center gap-3 mb-3 border-b-1 rounded",
@@ -936,6 +980,7 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
 
                                                         idx_sig.set(0);
                                                         typed_sig.set(String::new());
+                                                        capture.end_run();
                                                     },
                                                     {label_text}
                                                 }
@@ -956,10 +1001,15 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
                                         value: test_mode().as_str().to_string(),
                                         horizontal: true,
                                         on_value_change: move |value: String| {
-                                            test_mode.set(TestMode::from_str(&value));
+                                            let mode = TestMode::from_str(&value);
+                                            if mode == TestMode::Trouble {
+                                                trouble.set(keystrokes.confusions(&lang.peek()).worst_pairs(TROUBLE_PAIRS));
+                                            }
+                                            test_mode.set(mode);
                                             // reset index & input when mode changes
                                             current_index.set(0);
                                             typed.set(String::new());
+                                            capture.end_run();
                                         },
 
                                             RadioItem {
@@ -977,6 +1027,20 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
                                                 index: 2usize,
                                                 "Bounded (by rank)"
                                             }
+                                            RadioItem {
+                                                value: "trouble".to_string(),
+                                                index: 3usize,
+                                                "Trouble letters"
+                                            }
+                                        }
+                                    }
+                                    if matches!(test_mode(), TestMode::Trouble) {
+                                        div { class: "text-xs text-gray-400 text-center mt-2",
+                                            if trouble_note.is_empty() {
+                                                "No mix-ups recorded yet — any word will do until the drills log some."
+                                            } else {
+                                                "Drilling: {trouble_note}"
+                                            }
                                         }
                                     }
                                     if matches!(test_mode(), TestMode::Bounded) {
@@ -1018,6 +1082,7 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
                                                                     min_rank_sig.set(v);
                                                                     idx_sig.set(0);
                                                                     typed_sig.set(String::new());
+                                                                    capture.end_run();
                                                                 }
                                                             }
                                                         }
@@ -1045,6 +1110,7 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
                                                                     max_rank_sig.set(v);
                                                                     idx_sig.set(0);
                                                                     typed_sig.set(String::new());
+                                                                    capture.end_run();
                                                                 }
                                                             }
                                                         }
@@ -1062,6 +1128,7 @@ pub fn TypingTest(lang: Signal<String>, letters_vec: Vec<Letter>) -> Element {
                                                                 bounded_order_sig.set(BoundedOrder::from_str(&value));
                                                                 idx_sig.set(0);
                                                                 typed_sig.set(String::new());
+                                                                capture.end_run();
                                                             },
 
                                                             RadioItem {
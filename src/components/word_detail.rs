@@ -1,14 +1,24 @@
 // src/components/word_detail.rs
 //
-// The focused word card (modal): head word, POS, gloss, example, and — when the
-// lexicon entry carries one — the Georgian declension table with a hover tooltip
-// on each case name. The "With noun" (attributive) column is dropped entirely
-// when no case has such a form, so nouns show a clean 3-column table and
-// adjectives get the 4th column.
+// The focused word card (modal): head word, POS, numbered senses (each with its
+// own POS, usage note and examples where the entry has them), example, and —
+// when the lexicon entry carries one — the declension table over the language's
+// cases, with a hover tooltip on each case name, flagged when some of it was
+// generated by rule. Only the columns some case fills are shown: a Georgian
+// noun gets singular / plural, a Georgian adjective adds "With noun", and a
+// Russian adjective reads masculine / feminine / neuter / plural.
+//
+// The card also edits the lexicon: "Edit" turns the head word, meanings, POS
+// and example into a form (meanings as one line, `;` between senses), "Delete"
+// removes the word (after a second click), and both go to the user's overlay
+// (`langpack::commit_user_lexicon`), never the pack. Opened with a rank-0 entry
+// (a blank one, or a word prefilled by the reading drill), the card starts as
+// the form and saves a new word.
 
 use dioxus::prelude::*;
 
-use crate::models::lexicon::{GeoCase, LexEntry};
+use crate::langpack::{self, use_manifest};
+use crate::models::lexicon::{Column, LexEntry, Sense, UserEntry};
 
 const CASE_TIP_CSS: &str = "\
 .lex-case{position:relative;cursor:help;border-bottom:1px dotted #9b8f76;}\
@@ -30,12 +40,39 @@ pub fn WordDetail(entry: LexEntry, on_close: EventHandler<()>) -> Element {
     let rule = "#cbbfa3";
     let l2 = "'Noto Serif Georgian','Noto Serif',Georgia,'Times New Roman',serif";
     let body = "Georgia,'Times New Roman','Noto Serif',serif";
+    // Only packs that declare declensions get the case table.
+    let manifest = use_manifest()();
+    let declines = manifest.features.declensions;
+    let lang = use_context::<Signal<String>>()();
+    // ranks start at 1, so rank 0 means a word that isn't in the lexicon yet
+    let adding = entry.rank == 0;
+    let mut editing = use_signal(|| adding);
+    let mut confirm_delete = use_signal(|| false);
+    let mut word_in = use_signal(|| entry.word.clone());
+    let mut en_in = use_signal(|| entry.gloss());
+    let mut pos_in = use_signal(|| entry.pos.clone().unwrap_or_default());
+    let mut example_in = use_signal(|| entry.example.clone().unwrap_or_default());
 
     let head = entry.head().to_string();
     let rank = entry.rank;
     let pos = entry.pos.clone();
-    let gloss = entry.en.clone();
+    let numbered = entry.senses.len() > 1;
+    let senses: Vec<(String, String, Sense)> = entry
+        .senses
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            let n = if numbered { format!("{}.", i + 1) } else { String::new() };
+            (n, s.gloss(), s.clone())
+        })
+        .collect();
     let example = entry.example.clone();
+    let generated = entry.declension_generated;
+    let rank_label = if entry.user_added {
+        "added by you".to_string()
+    } else {
+        format!("#{rank}")
+    };
 
     let dash = |s: &str| {
         if s.trim().is_empty() {
@@ -45,29 +82,29 @@ pub fn WordDetail(entry: LexEntry, on_close: EventHandler<()>) -> Element {
         }
     };
 
-    // Column-level decision: does ANY case carry an attributive "with noun" form?
-    let has_with_noun = entry.declension.as_ref().map_or(false, |d| {
-        GeoCase::ALL
-            .iter()
-            .any(|&c| !d.forms(c).with_noun.trim().is_empty())
-    });
+    // Column-level decision: only the columns some case actually fills.
+    let columns: Vec<Column> = entry
+        .declension
+        .as_ref()
+        .map(|d| d.columns())
+        .unwrap_or_default();
+    let column_labels: Vec<&'static str> = columns.iter().map(|c| c.label()).collect();
 
-    let decl_rows: Vec<(&'static str, &'static str, String, String, String)> = entry
+    let decl_rows: Vec<(String, String, Vec<String>)> = entry
         .declension
         .as_ref()
-        .filter(|d| d.any_filled())
+        .filter(|d| declines && d.any_filled())
         .map(|d| {
-            GeoCase::ALL
+            manifest
+                .inventory
+                .cases
                 .iter()
-                .map(|&c| {
-                    let f = d.forms(c);
-                    (
-                        c.label(),
-                        c.blurb(),
-                        dash(&f.singular),
-                        dash(&f.plural),
-                        dash(&f.with_noun),
-                    )
+                .map(|row| {
+                    let cells = columns
+                        .iter()
+                        .map(|c| dash(d.forms(&row.key).map_or("", |f| c.of(f))))
+                        .collect();
+                    (row.label.to_string(), row.blurb.to_string(), cells)
                 })
                 .collect::<Vec<_>>()
         })
@@ -82,6 +119,86 @@ pub fn WordDetail(entry: LexEntry, on_close: EventHandler<()>) -> Element {
         "padding:0.4rem 0.6rem; border:1px solid {rule}; font-family:{body}; \
          font-size:0.82rem; color:{ink};"
     );
+    let field = format!(
+        "display:block; width:100%; margin-top:0.25rem; padding:0.35rem 0.5rem; \
+         background:{paper_edge}; color:{ink}; border:1px solid {rule}; border-radius:3px; \
+         font-size:0.95rem;"
+    );
+    let field_label = format!(
+        "display:block; margin-top:0.8rem; font-size:0.64rem; letter-spacing:0.08em; \
+         text-transform:uppercase; color:{secondary};"
+    );
+    let link_button = format!(
+        "background:none; border:none; color:{accent}; cursor:pointer; \
+         font-size:0.85rem; font-family:{body}; margin-left:1.1rem;"
+    );
+    let editing_now = editing();
+    let can_save = !word_in.read().trim().is_empty() && !en_in.read().trim().is_empty();
+    let delete_label = if confirm_delete() {
+        "Really delete?"
+    } else {
+        "Delete"
+    };
+
+    let save = {
+        let entry = entry.clone();
+        let lang = lang.clone();
+        move |_| {
+            let optional = |s: String| {
+                let s = s.trim().to_string();
+                (!s.is_empty()).then_some(s)
+            };
+            // an untouched line keeps the senses' notes and examples
+            let meanings = en_in().trim().to_string();
+            let senses = if meanings == entry.gloss() {
+                entry.senses.clone()
+            } else {
+                Sense::parse(&meanings)
+            };
+            let edited = UserEntry {
+                edits: None,
+                word: word_in().trim().to_string(),
+                senses,
+                pos: optional(pos_in()),
+                example: optional(example_in()),
+            };
+            if edited.word.is_empty() || edited.senses.is_empty() {
+                return;
+            }
+            let mut user = langpack::user_lexicon(&lang);
+            if adding {
+                user.add(edited);
+            } else {
+                user.edit(&entry, edited);
+            }
+            langpack::commit_user_lexicon(&lang, &user);
+            on_close.call(());
+        }
+    };
+
+    let delete = {
+        let entry = entry.clone();
+        let lang = lang.clone();
+        move |_| {
+            if !confirm_delete() {
+                confirm_delete.set(true);
+                return;
+            }
+            let mut user = langpack::user_lexicon(&lang);
+            user.delete(&entry);
+            langpack::commit_user_lexicon(&lang, &user);
+            on_close.call(());
+        }
+    };
+
+    let cancel = move |_| {
+        if adding {
+            on_close.call(());
+        } else {
+            editing.set(false);
+        }
+    };
+
     let form_cell = format!(
         "padding:0.4rem 0.6rem; border:1px solid {rule}; font-family:{l2}; \
          font-size:0.96rem; color:{ink}; word-break:break-word;"
@@ -102,77 +219,159 @@ pub fn WordDetail(entry: LexEntry, on_close: EventHandler<()>) -> Element {
                         overflow:visible;",
                 onclick: move |e| e.stop_propagation(),
 
-                div {
-                    style: "display:flex; align-items:baseline; justify-content:space-between; \
-                            border-bottom:1.5px solid {accent}; padding-bottom:0.55rem;",
-                    span { style: "font-family:{l2}; font-size:1.9rem; font-weight:700; color:{ink}; line-height:1;", "{head}" }
-                    span { style: "font-size:0.68rem; color:{secondary};", "#{rank}" }
-                }
+                if editing_now {
+                    div {
+                        style: "border-bottom:1.5px solid {accent}; padding-bottom:0.55rem; \
+                                font-size:0.72rem; letter-spacing:0.12em; text-transform:uppercase; color:{accent};",
+                        if adding { "New word" } else { "Edit word" }
+                    }
+                    label { style: "{field_label}", "Head word"
+                        input {
+                            style: "{field} font-family:{l2};",
+                            value: "{word_in}",
+                            oninput: move |e| word_in.set(e.value()),
+                        }
+                    }
+                    label { style: "{field_label}", "Meanings"
+                        input {
+                            style: "{field} font-family:{body};",
+                            placeholder: "alternatives separated by /, senses by ;",
+                            value: "{en_in}",
+                            oninput: move |e| en_in.set(e.value()),
+                        }
+                    }
+                    label { style: "{field_label}", "Part of speech"
+                        input {
+                            style: "{field} font-family:{body};",
+                            placeholder: "noun, verb, adjective…",
+                            value: "{pos_in}",
+                            oninput: move |e| pos_in.set(e.value()),
+                        }
+                    }
+                    label { style: "{field_label}", "Example"
+                        input {
+                            style: "{field} font-family:{l2};",
+                            value: "{example_in}",
+                            oninput: move |e| example_in.set(e.value()),
+                        }
+                    }
+                } else {
+                    div {
+                        style: "display:flex; align-items:baseline; justify-content:space-between; \
+                                border-bottom:1.5px solid {accent}; padding-bottom:0.55rem;",
+                        span { style: "font-family:{l2}; font-size:1.9rem; font-weight:700; color:{ink}; line-height:1;", "{head}" }
+                        span { style: "font-size:0.68rem; color:{secondary};", "{rank_label}" }
+                    }
 
-                if let Some(p) = pos {
-                    div { style: "margin-top:0.7rem;",
-                        span {
-                            style: "display:inline-block; background:{paper_edge}; color:{accent}; \
-                                    font-size:0.7rem; letter-spacing:0.04em; padding:0.16rem 0.65rem; \
-                                    border-radius:999px;",
-                            "{p}"
+                    if let Some(p) = pos {
+                        div { style: "margin-top:0.7rem;",
+                            span {
+                                style: "display:inline-block; background:{paper_edge}; color:{accent}; \
+                                        font-size:0.7rem; letter-spacing:0.04em; padding:0.16rem 0.65rem; \
+                                        border-radius:999px;",
+                                "{p}"
+                            }
                         }
                     }
-                }
 
-                div { style: "margin-top:0.55rem; font-size:1.08rem; color:{ink};", "{gloss}" }
+                    for (n, gloss, sense) in senses {
+                        div { key: "{n}{gloss}", style: "margin-top:0.55rem; font-size:1.08rem; color:{ink};",
+                            if !n.is_empty() {
+                                span { style: "color:{accent}; font-weight:700; margin-right:0.4rem;", "{n}" }
+                            }
+                            "{gloss}"
+                            if let Some(p) = sense.pos.clone() {
+                                span { style: "font-size:0.7rem; color:{accent}; margin-left:0.45rem;", "{p}" }
+                            }
+                            if let Some(note) = sense.note.clone() {
+                                span { style: "font-size:0.85rem; font-style:italic; color:{secondary}; margin-left:0.45rem;", "({note})" }
+                            }
+                            for ex in sense.examples.clone() {
+                                div { key: "{ex}", style: "font-family:{l2}; font-style:italic; font-size:0.9rem; color:{secondary}; margin-left:1.2rem;", "{ex}" }
+                            }
+                        }
+                    }
 
-                if let Some(ex) = example {
-                    div { style: "font-family:{l2}; font-style:italic; font-size:0.95rem; color:{secondary}; margin-top:0.75rem;", "{ex}" }
-                }
+                    if let Some(ex) = example {
+                        div { style: "font-family:{l2}; font-style:italic; font-size:0.95rem; color:{secondary}; margin-top:0.75rem;", "{ex}" }
+                    }
 
-                if has_table {
-                    div { style: "margin-top:1.4rem;",
-                        div {
-                            style: "font-size:0.72rem; letter-spacing:0.12em; text-transform:uppercase; \
-                                    color:{accent}; margin-bottom:0.5rem;",
-                            "Declension"
-                        }
-                        table { style: "width:100%; border-collapse:collapse; table-layout:fixed;",
-                            thead {
-                                tr {
-                                    th { style: "{header_cell} width:8.5rem;", "Case" }
-                                    th { style: "{header_cell}", "Singular" }
-                                    th { style: "{header_cell}", "Plural" }
-                                    if has_with_noun {
-                                        th { style: "{header_cell}", "With noun" }
-                                    }
-                                }
+                    if has_table {
+                        div { style: "margin-top:1.4rem;",
+                            div {
+                                style: "font-size:0.72rem; letter-spacing:0.12em; text-transform:uppercase; \
+                                        color:{accent}; margin-bottom:0.5rem;",
+                                "Declension"
                             }
-                            tbody {
-                                for (label, blurb, sg, pl, wn) in decl_rows.iter().cloned() {
+                            table { style: "width:100%; border-collapse:collapse; table-layout:fixed;",
+                                thead {
                                     tr {
-                                        key: "{label}",
-                                        td { style: "{case_cell}",
-                                            span { class: "lex-case",
-                                                "{label}"
-                                                span { class: "lex-tip", "{blurb}" }
-                                            }
+                                        th { style: "{header_cell} width:8.5rem;", "Case" }
+                                        for label in column_labels.iter() {
+                                            th { key: "{label}", style: "{header_cell}", "{label}" }
                                         }
-                                        td { style: "{form_cell}", "{sg}" }
-                                        td { style: "{form_cell}", "{pl}" }
-                                        if has_with_noun {
-                                            td { style: "{form_cell}", "{wn}" }
+                                    }
+                                }
+                                tbody {
+                                    for (label, blurb, cells) in decl_rows.iter().cloned() {
+                                        tr {
+                                            key: "{label}",
+                                            td { style: "{case_cell}",
+                                                span { class: "lex-case",
+                                                    "{label}"
+                                                    span { class: "lex-tip", "{blurb}" }
+                                                }
+                                            }
+                                            for (i, form) in cells.into_iter().enumerate() {
+                                                td { key: "{i}", style: "{form_cell}", "{form}" }
+                                            }
                                         }
                                     }
                                 }
                             }
+                            if generated {
+                                div { style: "font-size:0.72rem; font-style:italic; color:{secondary}; margin-top:0.4rem;",
+                                    "Some forms generated by rule — check irregular words against a grammar."
+                                }
+                            }
                         }
                     }
                 }
 
                 div { style: "text-align:right; margin-top:1.3rem;",
-                    button {
-                        class: "focus:outline-none focus-visible:ring-2 focus-visible:ring-amber-700",
-                        style: "background:none; border:none; color:{accent}; cursor:pointer; \
-                                font-size:0.85rem; font-family:{body};",
-                        onclick: move |_| on_close.call(()),
-                        "Close"
+                    if editing_now {
+                        button {
+                            class: "focus:outline-none focus-visible:ring-2 focus-visible:ring-amber-700",
+                            style: "{link_button}",
+                            onclick: cancel,
+                            "Cancel"
+                        }
+                        button {
+                            class: "focus:outline-none focus-visible:ring-2 focus-visible:ring-amber-700",
+                            style: "{link_button} font-weight:700;",
+                            disabled: !can_save,
+                            onclick: save,
+                            "Save"
+                        }
+                    } else {
+                        button {
+                            class: "focus:outline-none focus-visible:ring-2 focus-visible:ring-amber-700",
+                            style: "{link_button}",
+                            onclick: move |_| editing.set(true),
+                            "Edit"
+                        }
+                        button {
+                            class: "focus:outline-none focus-visible:ring-2 focus-visible:ring-amber-700",
+                            style: "{link_button}",
+                            onclick: delete,
+                            "{delete_label}"
+                        }
+                        button {
+                            class: "focus:outline-none focus-visible:ring-2 focus-visible:ring-amber-700",
+                            style: "{link_button}",
+                            onclick: move |_| on_close.call(()),
+                            "Close"
+                        }
                     }
                 }
             }
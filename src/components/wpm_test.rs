@@ -22,7 +22,6 @@ use rand::Rng;
 use std::collections::HashMap;
 use std::time::Duration;
 
-use crate::components::keyboard::code_to_qwerty_label;
 use crate::models::{letter::Letter, lexicon::LexEntry};
 
 /// Breathing caret animation. Injected once via a <style> element.
@@ -338,11 +337,35 @@ pub fn WpmTest(words: Vec<LexEntry>, lang: Signal<String>, letters_vec: Vec<Lett
     let mut samples = use_signal(Vec::<Sample>::new);
 
     let mut run_id = use_signal(|| 0u32);
+    let mut capture = crate::keystrokes::use_capture();
+
+    // a finished run is per-word Script & Sound evidence, each word at its
+    // frequency rank's difficulty
+    let learner = crate::learner::use_learner();
+    let log_run = {
+        // keyed the way the passage shows them (`clean_word`)
+        let ranks: HashMap<String, u32> = words
+            .iter()
+            .filter_map(|w| Some((clean_word(&w.word)?, w.rank)))
+            .collect();
+        use_callback(move |(text, secs): (String, f64)| {
+            capture.end_run();
+            learner.emit_all(crate::learner::wpm_evidence(
+                &lang.peek(),
+                &target.peek(),
+                &text,
+                secs,
+                crate::learner::now_ms(),
+                |w| ranks.get(w).copied(),
+            ));
+        })
+    };
 
     // Restart the SAME passage (Tab / Esc / Restart button).
     let restart = use_callback(move |_: ()| {
         run_id.set(run_id() + 1); // cancel any running timer
         typed.set(String::new());
+        capture.end_run();
         state.set(WpmState::Idle);
         remaining.set(duration());
         result.set(None);
@@ -357,6 +380,7 @@ pub fn WpmTest(words: Vec<LexEntry>, lang: Signal<String>, letters_vec: Vec<Lett
             run_id.set(run_id() + 1);
             target.set(build_wpm_text(&words, min_chars_for(duration())));
             typed.set(String::new());
+            capture.end_run();
             state.set(WpmState::Idle);
             remaining.set(duration());
             result.set(None);
@@ -364,15 +388,17 @@ pub fn WpmTest(words: Vec<LexEntry>, lang: Signal<String>, letters_vec: Vec<Lett
         })
     };
 
+    // key hints as printed on the learner's keyboard
+    let keymap = crate::calibration::use_keymap()();
     let mut hint_map: HashMap<char, String> = HashMap::new();
-    for letter in &letters_vec {
+    for letter in &keymap.apply(letters_vec.clone()) {
         if let Some(ch) = letter.letter.chars().next() {
-            let base = code_to_qwerty_label(&letter.key_code);
+            let base = keymap.label(&letter.key_code);
             if !base.is_empty() {
                 let rendered = if letter.shifted {
                     format!("⇧{base}")
                 } else {
-                    base.to_string()
+                    base
                 };
                 hint_map.insert(ch, rendered);
             }
@@ -388,6 +414,7 @@ pub fn WpmTest(words: Vec<LexEntry>, lang: Signal<String>, letters_vec: Vec<Lett
 
             target.set(build_wpm_text(&words, min_chars_for(dur)));
             typed.set(String::new());
+            capture.end_run();
             state.set(WpmState::Idle);
             remaining.set(dur);
             result.set(None);
@@ -442,6 +469,7 @@ pub fn WpmTest(words: Vec<LexEntry>, lang: Signal<String>, letters_vec: Vec<Lett
             res.consistency = consistency_pct(&samples.peek());
             result.set(Some(res));
             state.set(WpmState::Finished);
+            log_run.call((typed.peek().clone(), total as f64));
         }
     });
 
@@ -720,6 +748,7 @@ pub fn WpmTest(words: Vec<LexEntry>, lang: Signal<String>, letters_vec: Vec<Lett
                     spellcheck: "false",
                     autofocus: "true",
                     onkeydown: move |evt: KeyboardEvent| {
+                        capture.key_down(evt.code().to_string());
                         // Tab or Esc restarts the test (Tab would otherwise move focus)
                         match evt.key() {
                             Key::Tab | Key::Escape => {
@@ -739,6 +768,7 @@ pub fn WpmTest(words: Vec<LexEntry>, lang: Signal<String>, letters_vec: Vec<Lett
                         if v.chars().count() > tlen {
                             v = v.chars().take(tlen).collect();
                         }
+                        capture.input(&lang.peek(), &target.peek(), &typed.peek(), &v);
 
                         if matches!(*state.peek(), WpmState::Idle) {
                             if v.is_empty() {
@@ -767,6 +797,7 @@ pub fn WpmTest(words: Vec<LexEntry>, lang: Signal<String>, letters_vec: Vec<Lett
                             s.consistency = consistency_pct(&samples.peek());
                             result.set(Some(s));
                             state.set(WpmState::Finished);
+                            log_run.call((v.clone(), el as f64));
                         }
                     },
                 }
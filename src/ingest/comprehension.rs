@@ -0,0 +1,232 @@
+// src/ingest/comprehension.rs
+//
+// How much of a sentence the learner can already read: the share of its words
+// whose head word (`models::lemma`) has a `{lang}:rec:` memory the scheduler
+// still rates as likely recalled. Words the lexicon can't place count as
+// unknown; tokens without letters (numbers) don't count at all.
+//
+// The Reading page serves corpus sentences in the i+1 band — mostly known,
+// a few words to learn from context — falling back to the nearest sentence
+// when nothing unread is in the band yet.
+
+use crate::learning::{LearnerModel, Millis};
+use crate::models::lemma::Lemmatizer;
+use crate::models::text::tokenize;
+
+use super::corpus::Corpus;
+
+/// A word counts as known at this predicted recall or above.
+pub const KNOWN_RECALL: f32 = 0.8;
+/// Share of known words that makes a sentence i+1.
+pub const BAND: (f32, f32) = (0.85, 0.95);
+/// How many unread sentences (in corpus order) `pick` looks at, so a long
+/// book stays cheap to scan and reading keeps roughly to the book's order.
+const WINDOW: usize = 500;
+/// Parses below this don't identify a word well enough to credit it.
+const MIN_CONFIDENCE: f32 = 0.3;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Comprehension {
+    pub known: usize,
+    pub words: usize,
+}
+
+/// Where a sentence sits relative to `BAND`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Band {
+    Below,
+    Within,
+    Above,
+}
+
+impl Comprehension {
+    /// Known share, 0–1; 0 for a sentence with no words.
+    pub fn fraction(&self) -> f32 {
+        if self.words == 0 {
+            0.0
+        } else {
+            self.known as f32 / self.words as f32
+        }
+    }
+
+    pub fn band(&self) -> Band {
+        let f = self.fraction();
+        if f < BAND.0 {
+            Band::Below
+        } else if f > BAND.1 {
+            Band::Above
+        } else {
+            Band::Within
+        }
+    }
+
+    /// How far outside the band; 0 inside it.
+    fn distance(&self) -> f32 {
+        let f = self.fraction();
+        (BAND.0 - f).max(f - BAND.1).max(0.0)
+    }
+}
+
+/// Score `text` against what the learner currently recalls.
+pub fn score(
+    lang: &str,
+    text: &str,
+    lemmatizer: &Lemmatizer,
+    model: &LearnerModel,
+    now: Millis,
+) -> Comprehension {
+    let mut c = Comprehension::default();
+    for t in tokenize(text) {
+        if !t.chars().any(char::is_alphabetic) {
+            continue;
+        }
+        c.words += 1;
+        let known = lemmatizer
+            .best(&t)
+            .filter(|a| a.confidence >= MIN_CONFIDENCE)
+            .is_some_and(|a| {
+                let item = format!("{lang}:rec:{}", a.lemma());
+                model.retrievability(lang, &item, now) >= KNOWN_RECALL
+            });
+        if known {
+            c.known += 1;
+        }
+    }
+    c
+}
+
+/// The first `n` unread sentences, in corpus order, each with its score: the
+/// Reading page's list of what's coming up.
+pub fn scored_unread(
+    lang: &str,
+    corpus: &Corpus,
+    lemmatizer: &Lemmatizer,
+    model: &LearnerModel,
+    now: Millis,
+    n: usize,
+) -> Vec<(usize, Comprehension)> {
+    corpus
+        .unread()
+        .take(n)
+        .map(|(i, s)| (i, score(lang, &s.text, lemmatizer, model, now)))
+        .collect()
+}
+
+/// The unread sentence to serve next: the first in the band, else the one
+/// nearest to it (earlier wins ties). `None` once everything's read.
+pub fn pick(
+    lang: &str,
+    corpus: &Corpus,
+    lemmatizer: &Lemmatizer,
+    model: &LearnerModel,
+    now: Millis,
+) -> Option<(usize, Comprehension)> {
+    let mut best: Option<(usize, Comprehension)> = None;
+    for (i, s) in corpus.unread().take(WINDOW) {
+        let c = score(lang, &s.text, lemmatizer, model, now);
+        if c.band() == Band::Within {
+            return Some((i, c));
+        }
+        if best.is_none_or(|(_, b)| c.distance() < b.distance()) {
+            best = Some((i, c));
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::learning::{Evidence, Skill, Source};
+    use crate::models::lexicon::{LexEntry, Lexicon};
+
+    const WORDS: [&str; 10] = [
+        "один",
+        "два",
+        "три",
+        "четыре",
+        "пять",
+        "шесть",
+        "семь",
+        "восемь",
+        "девять",
+        "десять",
+    ];
+
+    fn setup(known: usize) -> (Lemmatizer, LearnerModel) {
+        let entries = WORDS
+            .iter()
+            .enumerate()
+            .map(|(i, w)| LexEntry {
+                rank: i as u32 + 1,
+                word: w.to_string(),
+                ..Default::default()
+            })
+            .collect();
+        let lemmatizer = Lemmatizer::new("russian", &Lexicon::from_entries(entries));
+        let mut model = LearnerModel::from_log(&[]);
+        for w in &WORDS[..known] {
+            model.apply(&Evidence::new(
+                1_000,
+                "russian",
+                format!("russian:rec:{w}"),
+                Skill::VocabRecognition,
+                1.0,
+                0.0,
+                800,
+                Source::Reading,
+            ));
+        }
+        (lemmatizer, model)
+    }
+
+    #[test]
+    fn counts_known_head_words_and_skips_numbers() {
+        let (l, m) = setup(3);
+        let c = score("russian", "Один, два — 42 шесть!", &l, &m, 1_000);
+        assert_eq!(c, Comprehension { known: 2, words: 3 });
+        assert_eq!(c.band(), Band::Below);
+    }
+
+    #[test]
+    fn picks_the_first_sentence_in_band_else_the_nearest() {
+        let (l, m) = setup(9);
+        let sentence = |n: usize, from: usize| WORDS[from..from + n].join(" ");
+        let mut corpus = Corpus::default();
+        corpus.add(
+            "book.txt",
+            [
+                sentence(2, 8),  // 1 of 2 known
+                sentence(10, 0), // 9 of 10: in band
+                sentence(9, 0),  // all known
+            ],
+        );
+        assert_eq!(pick("russian", &corpus, &l, &m, 1_000).unwrap().0, 1);
+        corpus.mark_read(1);
+        // nothing in band: all-known (5% over) beats half-known (35% under)
+        assert_eq!(pick("russian", &corpus, &l, &m, 1_000).unwrap().0, 2);
+        corpus.mark_read(0);
+        corpus.mark_read(2);
+        assert!(pick("russian", &corpus, &l, &m, 1_000).is_none());
+    }
+
+    #[test]
+    fn lists_unread_sentences_with_their_scores() {
+        let (l, m) = setup(2);
+        let mut corpus = Corpus::default();
+        corpus.add(
+            "book.txt",
+            ["один два".into(), "три".into(), "один пять".into()],
+        );
+        corpus.mark_read(1);
+        let listed = scored_unread("russian", &corpus, &l, &m, 1_000, 5);
+        assert_eq!(
+            listed,
+            [
+                (0, Comprehension { known: 2, words: 2 }),
+                (2, Comprehension { known: 1, words: 2 }),
+            ]
+        );
+        assert_eq!(scored_unread("russian", &corpus, &l, &m, 1_000, 1).len(), 1);
+    }
+}
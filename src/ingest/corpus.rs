@@ -0,0 +1,167 @@
+// src/ingest/corpus.rs
+//
+// The per-language reading corpus: every sentence imported so far, in import
+// order, each flagged once it's been read. Sentences are served out of order
+// (`ingest::comprehension` picks by how much of each the learner can read), so
+// progress is per sentence rather than a cursor. Lives at
+// `<data root>/corpus/<lang>.json`. Re-importing a file only appends
+// sentences the corpus doesn't already have. Files from before the per-sentence
+// flags kept a `read` cursor instead; loading one marks everything before the
+// cursor read.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Sentence {
+    pub text: String,
+    /// File name it was imported from.
+    #[serde(default)]
+    pub source: String,
+    #[serde(default)]
+    pub read: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Corpus {
+    #[serde(default)]
+    pub sentences: Vec<Sentence>,
+    /// The old format's cursor: sentences before it had been read. Folded into
+    /// the flags on load and never written back.
+    #[serde(default, rename = "read", skip_serializing)]
+    legacy_cursor: usize,
+}
+
+impl Corpus {
+    /// Append the sentences not already in the corpus; returns how many.
+    pub fn add(&mut self, source: &str, sentences: impl IntoIterator<Item = String>) -> usize {
+        let mut seen: HashSet<String> = self.sentences.iter().map(|s| s.text.clone()).collect();
+        let before = self.sentences.len();
+        for text in sentences {
+            if seen.insert(text.clone()) {
+                self.sentences.push(Sentence {
+                    text,
+                    source: source.to_string(),
+                    read: false,
+                });
+            }
+        }
+        self.sentences.len() - before
+    }
+
+    /// Unread sentences with their indices, in corpus order.
+    pub fn unread(&self) -> impl Iterator<Item = (usize, &Sentence)> {
+        self.sentences.iter().enumerate().filter(|(_, s)| !s.read)
+    }
+
+    pub fn unread_count(&self) -> usize {
+        self.unread().count()
+    }
+
+    pub fn mark_read(&mut self, i: usize) {
+        if let Some(s) = self.sentences.get_mut(i) {
+            s.read = true;
+        }
+    }
+
+    /// Read `path`; a missing file is an empty corpus, an unreadable one is
+    /// logged and treated the same.
+    pub fn load_from(path: &Path) -> Self {
+        let Ok(json) = fs::read_to_string(path) else {
+            return Self::default();
+        };
+        let mut corpus: Self = serde_json::from_str(&json).unwrap_or_else(|e| {
+            eprintln!("corpus {} unreadable: {e}", path.display());
+            Self::default()
+        });
+        for i in 0..std::mem::take(&mut corpus.legacy_cursor) {
+            corpus.mark_read(i);
+        }
+        corpus
+    }
+
+    pub fn save_to(&self, path: &Path) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        crate::paths::write_atomic(path, &json)
+    }
+}
+
+/// Where `lang`'s corpus lives.
+pub fn path(lang: &str) -> PathBuf {
+    let name = format!("{lang}.json");
+    crate::paths::data_root()
+        .map(|d| d.join("corpus").join(&name))
+        .unwrap_or_else(|| PathBuf::from("corpus").join(name))
+}
+
+pub fn load(lang: &str) -> Corpus {
+    Corpus::load_from(&path(lang))
+}
+
+/// Best-effort save: failure is logged, not fatal.
+pub fn save(lang: &str, corpus: &Corpus) {
+    if let Err(e) = corpus.save_to(&path(lang)) {
+        eprintln!("corpus save failed: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reimport_appends_only_new_sentences_and_keeps_progress() {
+        let mut c = Corpus::default();
+        assert_eq!(c.add("a.txt", ["Один.".to_string(), "Два.".to_string()]), 2);
+        c.mark_read(0);
+        assert_eq!(c.unread().next().unwrap().1.text, "Два.");
+        assert_eq!(c.add("b.txt", ["Два.".to_string(), "Три.".to_string()]), 1);
+        assert_eq!(c.unread_count(), 2);
+        c.mark_read(2);
+        c.mark_read(7);
+        let left: Vec<usize> = c.unread().map(|(i, _)| i).collect();
+        assert_eq!(left, [1]);
+    }
+
+    #[test]
+    fn round_trips_through_disk() {
+        let dir = crate::paths::TestDir::new("corpus");
+        let path = dir.join("corpus").join("russian.json");
+        let mut c = Corpus::default();
+        c.add("a.txt", ["Один.".to_string()]);
+        c.save_to(&path).unwrap();
+        assert_eq!(Corpus::load_from(&path), c);
+        assert_eq!(
+            Corpus::load_from(&dir.join("missing.json")),
+            Corpus::default()
+        );
+    }
+
+    #[test]
+    fn a_cursor_file_loads_as_read_flags() {
+        let dir = crate::paths::TestDir::new("corpus-cursor");
+        let path = dir.join("georgian.json");
+        let old = r#"{
+  "sentences": [
+    { "text": "ერთი.", "source": "a.txt" },
+    { "text": "ორი.", "source": "a.txt" },
+    { "text": "სამი.", "source": "b.txt" }
+  ],
+  "read": 2
+}"#;
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, old).unwrap();
+        let c = Corpus::load_from(&path);
+        let left: Vec<usize> = c.unread().map(|(i, _)| i).collect();
+        assert_eq!(left, [2]);
+        // saved back in the new format, the cursor is gone and the flags stay
+        c.save_to(&path).unwrap();
+        assert!(!std::fs::read_to_string(&path)
+            .unwrap()
+            .contains("\"read\": 2"));
+        assert_eq!(Corpus::load_from(&path), c);
+    }
+}
@@ -0,0 +1,281 @@
+// src/ingest/epub.rs
+//
+// Text of a local EPUB: it's a zip whose `META-INF/container.xml` points at
+// the OPF package file, whose spine lists the XHTML chapters in reading order.
+// The markup is simple and machine-written, so it's scanned by hand instead of
+// pulling in an XML parser: tags are dropped, block-level tags become
+// paragraph breaks (which `sentences::split` treats as hard boundaries), and
+// the common entities are decoded.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use zip::ZipArchive;
+
+/// Closing one of these ends a paragraph.
+const BLOCK_TAGS: [&str; 14] = [
+    "p",
+    "div",
+    "br",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "li",
+    "tr",
+    "blockquote",
+    "section",
+    "title",
+];
+/// Their content isn't text.
+const SKIPPED_TAGS: [&str; 3] = ["head", "script", "style"];
+
+fn read_entry(zip: &mut ZipArchive<File>, name: &str) -> Result<String, String> {
+    let mut entry = zip
+        .by_name(name)
+        .map_err(|e| format!("EPUB has no {name}: {e}"))?;
+    let mut out = String::new();
+    entry
+        .read_to_string(&mut out)
+        .map_err(|e| format!("couldn't read {name} from EPUB: {e}"))?;
+    Ok(out)
+}
+
+/// The insides of every `<name …>` start tag, namespace prefix or not
+/// (`<item …>` and `<opf:item …>` both count).
+fn start_tags<'a>(xml: &'a str, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    xml.split('<').skip(1).filter_map(move |chunk| {
+        let tag = &chunk[..chunk.find('>')?];
+        let tag_name = tag.split(|c: char| c.is_whitespace() || c == '/').next()?;
+        let local = tag_name.rsplit(':').next()?;
+        (local == name).then_some(tag)
+    })
+}
+
+/// Value of `name="…"` (or `'…'`) inside a start tag.
+fn attr<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = tag;
+    while let Some(at) = rest.find(name) {
+        let before_ok = rest[..at].ends_with(char::is_whitespace);
+        let after = rest[at + name.len()..].trim_start();
+        rest = &rest[at + name.len()..];
+        let Some(after) = after.strip_prefix('=').map(str::trim_start) else {
+            continue;
+        };
+        let Some(quote) = after.chars().next().filter(|q| *q == '"' || *q == '\'') else {
+            continue;
+        };
+        let value = &after[1..];
+        if before_ok {
+            return value.find(quote).map(|end| &value[..end]);
+        }
+    }
+    None
+}
+
+/// `%20` and friends in manifest hrefs.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = (bytes[i] == b'%')
+            .then(|| s.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match hex {
+            Some(b) => {
+                out.push(b);
+                i += 3;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// `base/href`, with `..` resolved (zip names are always `/`-separated).
+fn join(base: &str, href: &str) -> String {
+    let href = href.split('#').next().unwrap_or(href);
+    let mut parts: Vec<&str> = base.split('/').filter(|p| !p.is_empty()).collect();
+    for part in href.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            p => parts.push(p),
+        }
+    }
+    parts.join("/")
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        "laquo" => Some('«'),
+        "raquo" => Some('»'),
+        "mdash" => Some('—'),
+        "ndash" => Some('–'),
+        "hellip" => Some('…'),
+        _ => {
+            let num = entity.strip_prefix('#')?;
+            let code = match num.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => num.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// Readable text of one XHTML document, one paragraph per blank-line block.
+pub fn html_to_text(html: &str) -> String {
+    let mut out = String::new();
+    let mut skipping: Option<String> = None;
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('<') {
+            let end = after.find('>').unwrap_or(after.len());
+            let tag = &after[..end];
+            rest = after.get(end + 1..).unwrap_or("");
+            let closing = tag.starts_with('/');
+            let name = tag
+                .trim_start_matches('/')
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()
+                .unwrap_or("")
+                .to_ascii_lowercase();
+            let name = name.rsplit(':').next().unwrap_or("").to_string();
+            match &skipping {
+                Some(skip) if closing && *skip == name => skipping = None,
+                Some(_) => {}
+                None if !closing
+                    && !tag.ends_with('/')
+                    && SKIPPED_TAGS.contains(&name.as_str()) =>
+                {
+                    skipping = Some(name)
+                }
+                None if BLOCK_TAGS.contains(&name.as_str()) => out.push_str("\n\n"),
+                None => {}
+            }
+            continue;
+        }
+        let end = rest.find('<').unwrap_or(rest.len());
+        let (text, tail) = rest.split_at(end);
+        rest = tail;
+        if skipping.is_some() {
+            continue;
+        }
+        let mut chunks = text.split('&');
+        out.push_str(chunks.next().unwrap_or(""));
+        for chunk in chunks {
+            let decoded = chunk
+                .find(';')
+                .filter(|&semi| semi <= 10)
+                .and_then(|semi| Some((decode_entity(&chunk[..semi])?, &chunk[semi + 1..])));
+            match decoded {
+                Some((c, tail)) => {
+                    out.push(c);
+                    out.push_str(tail);
+                }
+                None => {
+                    out.push('&');
+                    out.push_str(chunk);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// All the text of the EPUB at `path`, chapters in spine order.
+pub fn extract(path: &Path) -> Result<String, String> {
+    let file = File::open(path).map_err(|e| format!("couldn't open {}: {e}", path.display()))?;
+    let mut zip =
+        ZipArchive::new(file).map_err(|e| format!("{} isn't an EPUB: {e}", path.display()))?;
+
+    let container = read_entry(&mut zip, "META-INF/container.xml")?;
+    let opf_path = start_tags(&container, "rootfile")
+        .find_map(|t| attr(t, "full-path"))
+        .ok_or("EPUB container names no package file")?
+        .to_string();
+    let opf = read_entry(&mut zip, &opf_path)?;
+    let base = opf_path.rsplit_once('/').map_or("", |(dir, _)| dir);
+
+    let manifest: HashMap<&str, &str> = start_tags(&opf, "item")
+        .filter_map(|t| Some((attr(t, "id")?, attr(t, "href")?)))
+        .collect();
+    let mut text = String::new();
+    for idref in start_tags(&opf, "itemref").filter_map(|t| attr(t, "idref")) {
+        let Some(href) = manifest.get(idref) else {
+            continue;
+        };
+        let name = join(base, &percent_decode(href));
+        match read_entry(&mut zip, &name) {
+            Ok(doc) => {
+                text.push_str(&html_to_text(&doc));
+                text.push_str("\n\n");
+            }
+            Err(e) => eprintln!("ingest: {e}"),
+        }
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markup_is_stripped_and_blocks_become_paragraphs() {
+        let html = r#"<?xml version="1.0"?><html><head><title>Ch 1</title>
+            <style>p{}</style></head><body><h1>Глава&nbsp;1</h1>
+            <p>Он сказал: &laquo;Да&raquo; &amp; <i>ушёл</i>.</p><p>A&#x10D0;&#4304; &bogus; x</p></body></html>"#;
+        let text = html_to_text(html);
+        let paras: Vec<&str> = text
+            .split("\n\n")
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .collect();
+        assert_eq!(
+            paras,
+            ["Глава 1", "Он сказал: «Да» & ушёл.", "Aაა &bogus; x"]
+        );
+    }
+
+    #[test]
+    fn package_paths_and_attributes() {
+        let opf =
+            r#"<opf:item id="c1" href="Text/ch%201.xhtml" media-type="x"/><itemref idref='c1'/>"#;
+        assert_eq!(
+            start_tags(opf, "item")
+                .filter_map(|t| attr(t, "href"))
+                .next(),
+            Some("Text/ch%201.xhtml")
+        );
+        assert_eq!(
+            start_tags(opf, "itemref")
+                .filter_map(|t| attr(t, "idref"))
+                .next(),
+            Some("c1")
+        );
+        assert_eq!(
+            join("OEBPS", &percent_decode("Text/ch%201.xhtml#s2")),
+            "OEBPS/Text/ch 1.xhtml"
+        );
+        assert_eq!(join("OEBPS/Text", "../Images/a.png"), "OEBPS/Images/a.png");
+    }
+}
@@ -0,0 +1,49 @@
+// src/ingest/mod.rs
+//
+// Feeds the Reading drill: a local PDF, EPUB or plain-text file becomes text
+// (`pdf`, `epub`), the text becomes sentences by the language's punctuation
+// rules (`sentences`), and the sentences are appended to that language's
+// reading corpus under the data root (`corpus`). `ReadingPage` works through
+// it one unread sentence at a time, picked by how much of it the learner can
+// already read (`comprehension`).
+
+pub mod comprehension;
+pub mod corpus;
+pub mod epub;
+pub mod pdf;
+pub mod sentences;
+
+use std::path::Path;
+
+/// Plain text of a local file, by extension: `.pdf`, `.epub`, anything else
+/// read as UTF-8 text.
+pub fn extract_text(path: &Path) -> Result<String, String> {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+    match ext.as_deref() {
+        Some("pdf") => pdf::extract(path),
+        Some("epub") => epub::extract(path),
+        _ => std::fs::read_to_string(path)
+            .map_err(|e| format!("couldn't read {}: {e}", path.display())),
+    }
+}
+
+/// Import `path` into `lang`'s corpus. Returns how many new sentences were
+/// added (re-imports add nothing).
+pub fn ingest_file(lang: &str, path: &Path) -> Result<usize, String> {
+    let text = extract_text(path)?;
+    let found = sentences::split(lang, &text);
+    if found.is_empty() {
+        return Err(format!("no sentences found in {}", path.display()));
+    }
+    let source = path.file_name().map_or_else(
+        || path.display().to_string(),
+        |n| n.to_string_lossy().into_owned(),
+    );
+    let mut c = corpus::load(lang);
+    let added = c.add(&source, found);
+    corpus::save(lang, &c);
+    Ok(added)
+}
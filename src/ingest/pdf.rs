@@ -0,0 +1,18 @@
+// src/ingest/pdf.rs
+//
+// Text layer of a local PDF via `pdf-extract`. Scanned PDFs have no text layer
+// and come back (near) empty; there's no OCR here.
+
+use std::path::Path;
+
+/// All the text of the PDF at `path`, pages in order.
+pub fn extract(path: &Path) -> Result<String, String> {
+    // pdf-extract panics on some malformed or exotic files rather than
+    // returning an error; don't let one bad import take the app down
+    let result = std::panic::catch_unwind(|| pdf_extract::extract_text(path));
+    match result {
+        Ok(Ok(text)) => Ok(text),
+        Ok(Err(e)) => Err(format!("couldn't read PDF {}: {e}", path.display())),
+        Err(_) => Err(format!("unsupported PDF {}", path.display())),
+    }
+}
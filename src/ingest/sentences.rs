@@ -0,0 +1,233 @@
+// src/ingest/sentences.rs
+//
+// Sentence splitting with per-language punctuation rules. Text from PDFs and
+// EPUBs arrives hard-wrapped, so it's normalised first: words hyphenated across
+// a line break are rejoined, single newlines become spaces, and a blank line
+// (a paragraph break) always ends a sentence — that keeps headings from being
+// glued onto the first sentence under them.
+//
+// Inside a paragraph a sentence ends at `.` `!` `?` `…` (plus any closing
+// quotes / brackets after it) followed by whitespace, unless:
+// - the word before a `.` is a known abbreviation (`т.е.`, `ул.`, `მაგ.`) or a
+//   single capital letter (an initial: `А. С. Пушкин`);
+// - the language capitalises sentences (Russian) and the next word doesn't
+//   start with a capital, digit or opening quote. A dash doesn't count: in
+//   `— Правда? — спросил он.` it carries on the same sentence.
+// Georgian has no capitals, so it splits on punctuation alone.
+
+/// Longer than this is almost certainly a table, an index or a splitting
+/// accident, not something to read in one go.
+const MAX_CHARS: usize = 400;
+
+struct Rules {
+    terminators: &'static [char],
+    /// May follow a terminator and still belong to the sentence it ends.
+    closers: &'static [char],
+    /// Lowercase, without the final dot.
+    abbreviations: &'static [&'static str],
+    /// The next sentence must start with a capital (or an opening quote, or a digit).
+    capital_next: bool,
+}
+
+const TERMINATORS: [char; 4] = ['.', '!', '?', '…'];
+/// May precede the first letter of a sentence.
+const OPENERS: [char; 5] = ['«', '„', '“', '"', '('];
+
+const GEORGIAN: Rules = Rules {
+    terminators: &TERMINATORS,
+    closers: &['"', '»', '“', '”', ')', '\''],
+    abbreviations: &[
+        "ე.ი",
+        "მაგ",
+        "ა.შ",
+        "ძვ",
+        "წ",
+        "სს",
+        "ქ",
+        "გვ",
+        "იხ",
+        "ბ-ნ",
+        "ქ-ნ",
+    ],
+    capital_next: false,
+};
+
+const RUSSIAN: Rules = Rules {
+    terminators: &TERMINATORS,
+    closers: &['"', '»', '“', '”', ')'],
+    abbreviations: &[
+        "т.е", "т.к", "т.д", "т.п", "т.н", "и.о", "др", "пр", "см", "ср", "г", "гг", "в", "вв",
+        "ул", "д", "кв", "им", "стр", "с", "тыс", "млн", "млрд", "руб", "коп", "проф", "акад",
+        "св", "напр", "рис", "табл",
+    ],
+    capital_next: true,
+};
+
+const DEFAULT: Rules = Rules {
+    terminators: &TERMINATORS,
+    closers: &['"', '\'', '”', '’', ')'],
+    abbreviations: &[
+        "mr", "mrs", "ms", "dr", "st", "e.g", "i.e", "etc", "vs", "cf", "p", "pp",
+    ],
+    capital_next: true,
+};
+
+fn rules(lang: &str) -> &'static Rules {
+    match lang {
+        "georgian" => &GEORGIAN,
+        "russian" => &RUSSIAN,
+        _ => &DEFAULT,
+    }
+}
+
+/// Paragraphs of `text` with hard wraps undone and whitespace collapsed.
+fn paragraphs(text: &str) -> Vec<String> {
+    let text = text.replace("\r\n", "\n").replace('\u{AD}', "");
+    let mut out = Vec::new();
+    for block in text.split("\n\n") {
+        let mut para = String::new();
+        for line in block.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            // `сло-\nво` → `слово`, but keep real hyphens (`кто-то`) that
+            // happen to sit at a line end before a capital
+            let hyphenated = para.ends_with('-')
+                && para[..para.len() - 1].ends_with(char::is_alphabetic)
+                && line.starts_with(|c: char| c.is_lowercase());
+            if hyphenated {
+                para.pop();
+            } else if !para.is_empty() {
+                para.push(' ');
+            }
+            para.push_str(line);
+        }
+        let para = para.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !para.is_empty() {
+            out.push(para);
+        }
+    }
+    out
+}
+
+/// The word a `.` at the end of `before` closes is an abbreviation or initial.
+fn is_abbreviation(before: &[char], rules: &Rules) -> bool {
+    let start = before
+        .iter()
+        .rposition(|c| c.is_whitespace())
+        .map_or(0, |p| p + 1);
+    let word: String = before[start..]
+        .iter()
+        .skip_while(|c| OPENERS.contains(c))
+        .collect();
+    let mut letters = word.chars();
+    if let (Some(c), None) = (letters.next(), letters.next()) {
+        if c.is_uppercase() {
+            return true;
+        }
+    }
+    let lower = word.to_lowercase();
+    rules.abbreviations.contains(&lower.as_str())
+}
+
+fn split_paragraph(para: &str, rules: &Rules, out: &mut Vec<String>) {
+    let chars: Vec<char> = para.chars().collect();
+    let mut start = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        if !rules.terminators.contains(&chars[i]) {
+            i += 1;
+            continue;
+        }
+        let mut end = i + 1;
+        while end < chars.len()
+            && (rules.terminators.contains(&chars[end]) || rules.closers.contains(&chars[end]))
+        {
+            end += 1;
+        }
+        // `3.5`, `...»,` — not followed by a space, so not a boundary
+        let spaced = chars.get(end).is_some_and(|c| c.is_whitespace());
+        let next = chars[end..].iter().find(|c| !c.is_whitespace());
+        let boundary = match next {
+            None => true,
+            Some(_) if !spaced => false,
+            Some(_)
+                if chars[i] == '.' && end == i + 1 && is_abbreviation(&chars[start..i], rules) =>
+            {
+                false
+            }
+            Some(&c) => {
+                !rules.capital_next
+                    || c.is_uppercase()
+                    || c.is_ascii_digit()
+                    || OPENERS.contains(&c)
+            }
+        };
+        if boundary {
+            out.push(
+                chars[start..end]
+                    .iter()
+                    .collect::<String>()
+                    .trim()
+                    .to_string(),
+            );
+            start = end;
+        }
+        i = end;
+    }
+    let rest: String = chars[start..].iter().collect();
+    if !rest.trim().is_empty() {
+        out.push(rest.trim().to_string());
+    }
+}
+
+/// Split `text` into sentences by `lang`'s rules, dropping fragments with no
+/// letters (page numbers, rules) and anything over `MAX_CHARS`.
+pub fn split(lang: &str, text: &str) -> Vec<String> {
+    let rules = rules(lang);
+    let mut out = Vec::new();
+    for para in paragraphs(text) {
+        split_paragraph(&para, rules, &mut out);
+    }
+    out.retain(|s| s.chars().any(char::is_alphabetic) && s.chars().count() <= MAX_CHARS);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn russian_respects_abbreviations_initials_and_capitals() {
+        let text = "Мы жили на ул. Ленина, т.е. в центре. А. С. Пушкин писал стихи!\n\n\
+                    — Правда? — спросил он. Число 3.5 не конец.";
+        assert_eq!(
+            split("russian", text),
+            [
+                "Мы жили на ул. Ленина, т.е. в центре.",
+                "А. С. Пушкин писал стихи!",
+                "— Правда? — спросил он.",
+                "Число 3.5 не конец.",
+            ]
+        );
+    }
+
+    #[test]
+    fn georgian_splits_without_capitals_and_keeps_closing_quotes() {
+        let text = "კაცი ქუჩაში დადიოდა. მან თქვა: „სად ხარ?“ ბავშვი მაგ. სახლშია…";
+        assert_eq!(
+            split("georgian", text),
+            [
+                "კაცი ქუჩაში დადიოდა.",
+                "მან თქვა: „სად ხარ?“",
+                "ბავშვი მაგ. სახლშია…",
+            ]
+        );
+    }
+
+    #[test]
+    fn wraps_are_undone_and_paragraphs_break() {
+        let text = "Глава 1\n\nОн про-\nчитал кни-\nгу\nза вечер.\n\n12\n";
+        assert_eq!(
+            split("russian", text),
+            ["Глава 1", "Он прочитал книгу за вечер."]
+        );
+    }
+}
@@ -0,0 +1,589 @@
+//! Per-keystroke capture from the copy-typing drills (word drill, WPM): which
+//! glyph was expected, which one landed, the physical key pressed, and how
+//! long after the previous character. Appended to `keystrokes.jsonl` under the
+//! data root and folded into `Confusions` — per-letter miss rates for the
+//! keyboard heatmap, and the worst expected→typed pairs, which pick the words
+//! for a targeted drill.
+//!
+//! Only the newest keystrokes are kept raw. Once the log passes `RAW_CAP`, the
+//! older ones are compacted into per-glyph `Counts` (`keystroke_counts.json`),
+//! which is all `Confusions` needs from them.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::learning::Millis;
+use crate::models::lexicon::LexEntry;
+
+pub const KEYSTROKE_FILE: &str = "keystrokes.jsonl";
+pub const COUNTS_FILE: &str = "keystroke_counts.json";
+
+/// Raw keystrokes kept before the oldest are compacted into the counts, and
+/// how many of the newest a compaction leaves raw.
+const RAW_CAP: usize = 20_000;
+const RAW_KEEP: usize = 5_000;
+
+/// Keystrokes buffered before the log is flushed; `Capture::end_run` flushes
+/// too.
+const FLUSH_EVERY: usize = 64;
+
+/// Presses of a letter before its miss rate is worth showing.
+const MIN_PRESSES: u32 = 5;
+
+/// One character typed into a copy-typing drill.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Keystroke {
+    pub at: Millis,
+    pub lang: String,
+    pub expected: char,
+    pub typed: char,
+    /// Physical key of the keydown behind it (`"KeyA"`); empty if none was seen.
+    #[serde(default)]
+    pub key_code: String,
+    /// Since the previous character of the same run; 0 for the first.
+    pub latency_ms: u32,
+}
+
+/// First line of a compacted log.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LogHeader {
+    /// Keystrokes compacted away before this log's first one.
+    base: usize,
+}
+
+/// The raw keystrokes, and where the first of them falls among every
+/// keystroke ever recorded.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RawLog {
+    /// Keystrokes before this log (its header; 0 if none).
+    pub base: usize,
+    pub keystrokes: Vec<Keystroke>,
+}
+
+/// Every keystroke line; malformed ones are skipped, a missing file is empty.
+pub fn load(path: &Path) -> RawLog {
+    let mut out = RawLog::default();
+    let Ok(s) = fs::read_to_string(path) else {
+        return out;
+    };
+    let mut bad = 0;
+    for (i, l) in s.lines().enumerate() {
+        if l.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<Keystroke>(l) {
+            Ok(k) => out.keystrokes.push(k),
+            Err(_) => match serde_json::from_str::<LogHeader>(l) {
+                Ok(h) if i == 0 => out.base = h.base,
+                _ => bad += 1,
+            },
+        }
+    }
+    if bad > 0 {
+        eprintln!("{}: skipped {bad} corrupt line(s)", path.display());
+    }
+    out
+}
+
+/// The log file, opened once and appended to through a buffer.
+#[derive(Default)]
+struct Sink {
+    out: Option<BufWriter<fs::File>>,
+    unflushed: usize,
+}
+
+impl Sink {
+    /// Queue one keystroke as a JSON line, opening the file (and creating
+    /// parent dirs) on first use; flushes every `FLUSH_EVERY` lines.
+    fn write(&mut self, path: &Path, k: &Keystroke) -> std::io::Result<()> {
+        if self.out.is_none() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let f = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)?;
+            self.out = Some(BufWriter::new(f));
+        }
+        let line = serde_json::to_string(k).map_err(std::io::Error::other)?;
+        if let Some(out) = self.out.as_mut() {
+            writeln!(out, "{line}")?;
+        }
+        self.unflushed += 1;
+        if self.unflushed >= FLUSH_EVERY {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if let Some(out) = self.out.as_mut() {
+            out.flush()?;
+        }
+        self.unflushed = 0;
+        Ok(())
+    }
+
+    /// Flush and let go of the file, so it can be rewritten.
+    fn close(&mut self) -> std::io::Result<()> {
+        self.flush()?;
+        self.out = None;
+        Ok(())
+    }
+}
+
+/// How often `expected` came out as `typed` (itself, for a hit) in one
+/// language — a keystroke with its time and key folded away.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Tally {
+    pub lang: String,
+    pub expected: char,
+    pub typed: char,
+    pub count: u32,
+}
+
+/// Keystrokes compacted out of the raw log.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Counts {
+    /// How many keystrokes, oldest first, are folded in. A raw log starting
+    /// before that (`RawLog::base`) repeats them: a compaction that saved the
+    /// counts but never got to shorten the log leaves them behind.
+    pub folded: usize,
+    pub tallies: Vec<Tally>,
+}
+
+impl Counts {
+    /// A missing file is empty; an unreadable one is reported and ignored.
+    pub fn load(path: &Path) -> Self {
+        let Ok(s) = fs::read_to_string(path) else {
+            return Counts::default();
+        };
+        serde_json::from_str(&s).unwrap_or_else(|e| {
+            eprintln!("ignoring unreadable {}: {e}", path.display());
+            Counts::default()
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let json = serde_json::to_string(self).map_err(std::io::Error::other)?;
+        crate::paths::write_atomic(path, &json)
+    }
+
+    /// Fold the next keystrokes in, the ones right after the `folded` so far.
+    pub fn add<'a>(&mut self, log: impl IntoIterator<Item = &'a Keystroke>) {
+        for k in log {
+            self.folded += 1;
+            match self
+                .tallies
+                .iter_mut()
+                .find(|t| t.lang == k.lang && t.expected == k.expected && t.typed == k.typed)
+            {
+                Some(t) => t.count += 1,
+                None => self.tallies.push(Tally {
+                    lang: k.lang.clone(),
+                    expected: k.expected,
+                    typed: k.typed,
+                    count: 1,
+                }),
+            }
+        }
+    }
+
+    /// The keystrokes of `log` not folded in yet.
+    fn unfolded<'a>(&self, log: &'a RawLog) -> &'a [Keystroke] {
+        let covered = self.folded.saturating_sub(log.base);
+        &log.keystrokes[covered.min(log.keystrokes.len())..]
+    }
+}
+
+/// Fold all but the newest `keep` keystrokes of `log` into `counts`, then
+/// rewrite the log file with just those, under a header with the new base.
+/// The counts are saved first, so a crash in between leaves keystrokes they
+/// cover (`Counts::folded`) rather than losing any. Returns the log left.
+pub fn compact(
+    log_path: &Path,
+    counts_path: &Path,
+    counts: &mut Counts,
+    log: RawLog,
+    keep: usize,
+) -> std::io::Result<RawLog> {
+    let fresh = counts.unfolded(&log);
+    let cut = fresh.len().saturating_sub(keep);
+    let mut folded = counts.clone();
+    folded.folded = folded.folded.max(log.base);
+    folded.add(&fresh[..cut]);
+    folded.save(counts_path)?;
+    *counts = folded;
+    let tail = RawLog {
+        base: counts.folded,
+        keystrokes: fresh[cut..].to_vec(),
+    };
+    let header = LogHeader { base: tail.base };
+    let mut text = serde_json::to_string(&header).map_err(std::io::Error::other)? + "\n";
+    for k in &tail.keystrokes {
+        text += &serde_json::to_string(k).map_err(std::io::Error::other)?;
+        text.push('\n');
+    }
+    crate::paths::write_atomic(log_path, &text)?;
+    Ok(tail)
+}
+
+/// Who gets typed for whom, for one language.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Confusions {
+    /// expected letter -> (presses, misses)
+    letters: HashMap<char, (u32, u32)>,
+    /// (expected, typed) -> misses
+    pairs: HashMap<(char, char), u32>,
+}
+
+impl Confusions {
+    pub fn from_log<'a>(lang: &str, log: impl IntoIterator<Item = &'a Keystroke>) -> Self {
+        let mut c = Confusions::default();
+        for k in log.into_iter().filter(|k| k.lang == lang) {
+            c.note(k.expected, k.typed, 1);
+        }
+        c
+    }
+
+    /// The compacted counts plus the raw keystrokes they don't cover yet.
+    pub fn from_counts(lang: &str, counts: &Counts, log: &RawLog) -> Self {
+        let mut c = Confusions::from_log(lang, counts.unfolded(log));
+        for t in counts.tallies.iter().filter(|t| t.lang == lang) {
+            c.note(t.expected, t.typed, t.count);
+        }
+        c
+    }
+
+    fn note(&mut self, expected: char, typed: char, n: u32) {
+        let slot = self.letters.entry(expected).or_default();
+        slot.0 += n;
+        if typed != expected {
+            slot.1 += n;
+            *self.pairs.entry((expected, typed)).or_default() += n;
+        }
+    }
+
+    /// (presses, misses) for an expected letter.
+    pub fn counts(&self, expected: char) -> (u32, u32) {
+        self.letters.get(&expected).copied().unwrap_or_default()
+    }
+
+    /// Share of presses of any of `expected` (a key's plain and shifted
+    /// letters, say) that came out wrong; `None` until there are enough of
+    /// them to mean anything.
+    pub fn miss_rate(&self, expected: &[char]) -> Option<f32> {
+        let (n, miss) = expected
+            .iter()
+            .map(|&c| self.counts(c))
+            .fold((0, 0), |(n, m), (a, b)| (n + a, m + b));
+        (n >= MIN_PRESSES).then(|| miss as f32 / n as f32)
+    }
+
+    /// What got typed instead of `expected`, most often first.
+    pub fn typed_for(&self, expected: char) -> Vec<(char, u32)> {
+        let mut out: Vec<(char, u32)> = self
+            .pairs
+            .iter()
+            .filter(|((e, _), _)| *e == expected)
+            .map(|(&(_, t), &n)| (t, n))
+            .collect();
+        out.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        out
+    }
+
+    /// The `n` most frequent letter-for-letter mix-ups as (expected, typed,
+    /// count). Slips involving spaces are word-boundary noise, not confusion.
+    pub fn worst_pairs(&self, n: usize) -> Vec<(char, char, u32)> {
+        let mut out: Vec<(char, char, u32)> = self
+            .pairs
+            .iter()
+            .filter(|((e, t), _)| !e.is_whitespace() && !t.is_whitespace())
+            .map(|(&(e, t), &c)| (e, t, c))
+            .collect();
+        out.sort_by(|a, b| b.2.cmp(&a.2).then((a.0, a.1).cmp(&(b.0, b.1))));
+        out.truncate(n);
+        out
+    }
+}
+
+/// Words that exercise the given mix-ups: those holding either side of a
+/// pair, the most (and most-missed) trouble letters first, then by rank.
+pub fn drill_words(words: &[LexEntry], pairs: &[(char, char, u32)]) -> Vec<LexEntry> {
+    let mut weight: HashMap<char, u32> = HashMap::new();
+    for &(e, t, n) in pairs {
+        *weight.entry(e).or_default() += n;
+        *weight.entry(t).or_default() += n;
+    }
+    let mut scored: Vec<(u32, &LexEntry)> = words
+        .iter()
+        .map(|w| (w.word.chars().filter_map(|c| weight.get(&c)).sum(), w))
+        .filter(|(s, _)| *s > 0)
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.rank.cmp(&b.1.rank)));
+    scored.into_iter().map(|(_, w)| w.clone()).collect()
+}
+
+// ─── Dioxus glue ───────────────────────────────────────────────────────────
+
+fn log_path() -> Option<PathBuf> {
+    crate::paths::data_root().map(|d| d.join(KEYSTROKE_FILE))
+}
+
+fn counts_path() -> Option<PathBuf> {
+    crate::paths::data_root().map(|d| d.join(COUNTS_FILE))
+}
+
+/// Cheap, copyable handle to the keystroke log.
+#[derive(Clone, Copy)]
+pub struct Keystrokes {
+    log: Signal<RawLog>,
+    counts: Signal<Counts>,
+    sink: Signal<Sink>,
+}
+
+impl Keystrokes {
+    /// Persist one keystroke and add it to the in-memory log, compacting once
+    /// the log passes `RAW_CAP`. Persistence failure is logged, not fatal.
+    pub fn record(&self, k: Keystroke) {
+        if let Some(path) = log_path() {
+            let mut sink = self.sink;
+            let written = sink.write().write(&path, &k);
+            if let Err(e) = written {
+                eprintln!("keystroke persist failed: {e}");
+            }
+        }
+        let mut log = self.log;
+        log.write().keystrokes.push(k);
+        if log.peek().keystrokes.len() > RAW_CAP {
+            self.compact();
+        }
+    }
+
+    /// Write out any buffered keystrokes.
+    pub fn flush(&self) {
+        let mut sink = self.sink;
+        let written = sink.write().flush();
+        if let Err(e) = written {
+            eprintln!("keystroke persist failed: {e}");
+        }
+    }
+
+    /// Fold all but the newest `RAW_KEEP` keystrokes into the counts.
+    fn compact(&self) {
+        let (Some(log_path), Some(counts_path)) = (log_path(), counts_path()) else {
+            return;
+        };
+        let mut sink = self.sink;
+        let written = sink.write().close();
+        if let Err(e) = written {
+            eprintln!("keystroke persist failed: {e}");
+        }
+        let mut counts = self.counts.peek().clone();
+        let raw = self.log.peek().clone();
+        match compact(&log_path, &counts_path, &mut counts, raw, RAW_KEEP) {
+            Ok(tail) => {
+                let (mut c, mut log) = (self.counts, self.log);
+                c.set(counts);
+                log.set(tail);
+            }
+            Err(e) => eprintln!("keystroke compaction failed: {e}"),
+        }
+    }
+
+    /// The confusion tables for `lang` (subscribes the caller to new keystrokes).
+    pub fn confusions(&self, lang: &str) -> Confusions {
+        Confusions::from_counts(lang, &self.counts.read(), &self.log.read())
+    }
+}
+
+/// Call once at the App root: loads the persisted counts and log, compacting
+/// a log that's already past the cap, and provides the handle.
+pub fn provide_keystrokes() {
+    let counts = use_signal(|| counts_path().map(|p| Counts::load(&p)).unwrap_or_default());
+    let log = use_signal(|| log_path().map(|p| load(&p)).unwrap_or_default());
+    let sink = use_signal(Sink::default);
+    let handle = use_context_provider(|| Keystrokes { log, counts, sink });
+    use_hook(|| {
+        if log.peek().keystrokes.len() > RAW_CAP {
+            handle.compact();
+        }
+    });
+}
+
+pub fn use_keystrokes() -> Keystrokes {
+    use_context::<Keystrokes>()
+}
+
+/// Capture state for one typing field: the physical key of the latest keydown
+/// and when the previous character landed.
+#[derive(Clone, Copy)]
+pub struct Capture {
+    keystrokes: Keystrokes,
+    code: Signal<String>,
+    last: Signal<Option<Millis>>,
+}
+
+impl Capture {
+    /// Call from the field's `onkeydown` with `evt.code()`.
+    pub fn key_down(&mut self, code: String) {
+        self.code.set(code);
+    }
+
+    /// Call wherever the drill clears the field or finishes a run: the next
+    /// character starts a fresh run (no latency across the gap), and the one
+    /// just typed is flushed to disk.
+    pub fn end_run(&self) {
+        let mut last = self.last;
+        last.set(None);
+        self.keystrokes.flush();
+    }
+
+    /// Call from `oninput` with the field's value before and after the edit.
+    /// A single appended character is recorded against `target`; the learner
+    /// clearing the field ends the run.
+    pub fn input(&mut self, lang: &str, target: &str, before: &str, after: &str) {
+        if after.is_empty() {
+            self.end_run();
+            return;
+        }
+        let Some((expected, typed)) =
+            crate::components::drills::typing_engine::keystroke(target, before, after)
+        else {
+            return;
+        };
+        let now = crate::learner::now_ms();
+        let latency_ms = self
+            .last
+            .peek()
+            .map_or(0, |t| now.saturating_sub(t).min(u32::MAX as u64) as u32);
+        self.last.set(Some(now));
+        self.keystrokes.record(Keystroke {
+            at: now,
+            lang: lang.to_string(),
+            expected,
+            typed,
+            key_code: self.code.peek().clone(),
+            latency_ms,
+        });
+    }
+}
+
+pub fn use_capture() -> Capture {
+    Capture {
+        keystrokes: use_keystrokes(),
+        code: use_signal(String::new),
+        last: use_signal(|| None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn k(lang: &str, expected: char, typed: char) -> Keystroke {
+        Keystroke {
+            at: 1_000,
+            lang: lang.into(),
+            expected,
+            typed,
+            key_code: String::new(),
+            latency_ms: 200,
+        }
+    }
+
+    #[test]
+    fn confusions_count_misses_per_letter_and_pair() {
+        let mut log = vec![k("georgian", 'ფ', 'პ'); 3];
+        log.extend(vec![k("georgian", 'ფ', 'ფ'); 3]);
+        log.push(k("georgian", 'თ', 'ტ'));
+        log.push(k("georgian", 'ა', ' '));
+        log.push(k("russian", 'ш', 'щ'));
+
+        let c = Confusions::from_log("georgian", &log);
+        assert_eq!(c.counts('ფ'), (6, 3));
+        assert_eq!(c.miss_rate(&['ფ']), Some(0.5));
+        assert_eq!(c.miss_rate(&['თ']), None, "one press is too few");
+        assert_eq!(c.miss_rate(&['თ', 'ფ']), Some(4.0 / 7.0));
+        assert_eq!(c.typed_for('ფ'), [('პ', 3)]);
+        assert_eq!(c.worst_pairs(5), [('ფ', 'პ', 3), ('თ', 'ტ', 1)]);
+    }
+
+    #[test]
+    fn drills_favour_words_full_of_trouble_letters() {
+        let entry = |rank, word: &str| LexEntry {
+            rank,
+            word: word.into(),
+            ..LexEntry::default()
+        };
+        let words = [
+            entry(1, "და"),
+            entry(2, "პური"),
+            entry(3, "ფაფა"),
+            entry(4, "კაცი"),
+        ];
+        let picked: Vec<String> = drill_words(&words, &[('ფ', 'პ', 3)])
+            .into_iter()
+            .map(|w| w.word)
+            .collect();
+        assert_eq!(picked, ["ფაფა", "პური"]);
+    }
+
+    #[test]
+    fn keystrokes_round_trip_through_the_log() {
+        let dir = crate::paths::TestDir::new("keys");
+        let path = dir.join(KEYSTROKE_FILE);
+        let mut sink = Sink::default();
+        sink.write(&path, &k("georgian", 'ქ', 'კ')).unwrap();
+        sink.flush().unwrap();
+        fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"not json\n")
+            .unwrap();
+        sink.write(&path, &k("georgian", 'ა', 'ა')).unwrap();
+        sink.close().unwrap();
+        assert_eq!(
+            load(&path).keystrokes,
+            [k("georgian", 'ქ', 'კ'), k("georgian", 'ა', 'ა')]
+        );
+    }
+
+    #[test]
+    fn compaction_keeps_a_raw_tail_and_the_same_confusions() {
+        let dir = crate::paths::TestDir::new("keys-compact");
+        let (log_path, counts_path) = (dir.join(KEYSTROKE_FILE), dir.join(COUNTS_FILE));
+        // all in the same millisecond: position, not time, says what's counted
+        let log: Vec<Keystroke> = (0..10)
+            .map(|i| k("georgian", 'ფ', if i % 2 == 0 { 'პ' } else { 'ფ' }))
+            .collect();
+        let raw = RawLog {
+            base: 0,
+            keystrokes: log.clone(),
+        };
+        let before = Confusions::from_log("georgian", &log);
+
+        let mut counts = Counts::default();
+        let kept = compact(&log_path, &counts_path, &mut counts, raw.clone(), 3).unwrap();
+        assert_eq!((kept.base, &kept.keystrokes[..]), (7, &log[7..]));
+        assert_eq!(load(&log_path), kept);
+        assert_eq!(Counts::load(&counts_path), counts);
+        assert_eq!(Confusions::from_counts("georgian", &counts, &kept), before);
+
+        // the counts were saved but the log never got shortened: nothing is
+        // counted twice, and the next compaction drops what's covered
+        assert_eq!(Confusions::from_counts("georgian", &counts, &raw), before);
+        let again = compact(&log_path, &counts_path, &mut counts, raw, 3).unwrap();
+        assert_eq!(again, kept);
+        assert_eq!(load(&log_path), kept);
+        assert_eq!(Confusions::from_counts("georgian", &counts, &kept), before);
+    }
+}
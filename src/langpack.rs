@@ -0,0 +1,486 @@
+//! Language packs, resolved at runtime. A pack is a `langs/<code>/` folder:
+//!
+//!   langs/<code>/manifest.json
+//!   langs/<code>/alphabet.json
+//!   langs/<code>/1000.json
+//!   langs/<code>/pronunciation/alphabet/*.wav
+//!
+//! Each file is looked up in priority order — the user's data root
+//! (`paths::data_root()/langs`), then a `langs/` folder next to the executable,
+//! then the packs embedded at compile time (`assets.rs`). Files resolve one at a
+//! time, so a folder can override a single file of a built-in pack, and a brand
+//! new language ships as a folder drop with no rebuild.
+//!
+//! The user's own additions and edits to a lexicon live apart from the packs,
+//! in `paths::data_root()/lexicon/<code>.json` (`UserLexicon`), and are laid
+//! over the seeded list on every load — so they survive pack upgrades.
+//!
+//! `manifest.json` says how to present the language (name, flag, TTS voice,
+//! script direction, collation), what its tables are made of (cases, tenses,
+//! pronouns) and which optional content it has. The
+//! registry built from those manifests is the one list of languages the
+//! switcher, settings and TTS all read.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::letter::Letter;
+use crate::models::lexicon::{Inventory, Lexicon, UserLexicon};
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+pub const ALPHABET_FILE: &str = "alphabet.json";
+pub const LEXICON_FILE: &str = "1000.json";
+/// Per-letter recordings, relative to the pack folder.
+pub const LETTER_AUDIO_DIR: &str = "pronunciation/alphabet";
+/// Word and phrase recordings (`LexEntry.audio`), relative to the pack folder.
+pub const WORD_AUDIO_DIR: &str = "pronunciation/words";
+/// User lexicon overlays, relative to the data root.
+pub const USER_LEXICON_DIR: &str = "lexicon";
+
+/// Directories that may hold `<code>/` pack folders, highest priority first.
+pub fn search_roots() -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+    if let Some(d) = crate::paths::data_root() {
+        roots.push(d.join("langs"));
+    }
+    if let Ok(exe) = std::env::current_exe() {
+        if let Some(dir) = exe.parent() {
+            roots.push(dir.join("langs"));
+        }
+    }
+    roots
+}
+
+/// A pack code / file name is a single plain path segment — no separators, no
+/// `..` — so a language string can never walk out of the `langs/` folder.
+fn is_plain_segment(s: &str) -> bool {
+    !s.is_empty()
+        && s != "."
+        && s != ".."
+        && !s.contains(['/', '\\'])
+        && !s.chars().any(char::is_control)
+}
+
+/// First on-disk copy of `<code>/<rel>` across the search roots.
+pub fn resolve(lang: &str, rel: &str) -> Option<PathBuf> {
+    if !is_plain_segment(lang) {
+        return None;
+    }
+    search_roots()
+        .into_iter()
+        .map(|root| root.join(lang).join(rel))
+        .find(|p| p.is_file())
+}
+
+/// Every language with a usable pack: on-disk folders that carry an
+/// `alphabet.json`, plus the embedded ones. Sorted, no duplicates.
+pub fn available() -> Vec<String> {
+    let mut codes: BTreeSet<String> = crate::assets::EMBEDDED_LANGS
+        .iter()
+        .map(|c| c.to_string())
+        .collect();
+    for root in search_roots() {
+        let Ok(dir) = fs::read_dir(&root) else {
+            continue;
+        };
+        for entry in dir.flatten() {
+            let path = entry.path();
+            if !path.join(ALPHABET_FILE).is_file() {
+                continue;
+            }
+            if let Some(code) = path.file_name().and_then(|n| n.to_str()) {
+                if is_plain_segment(code) {
+                    codes.insert(code.to_string());
+                }
+            }
+        }
+    }
+    codes.into_iter().collect()
+}
+
+// ─── manifest ──────────────────────────────────────────────────────────────
+
+/// Which way the language's script runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+impl Direction {
+    /// Value for an HTML `dir` attribute.
+    pub fn as_attr(self) -> &'static str {
+        match self {
+            Direction::Ltr => "ltr",
+            Direction::Rtl => "rtl",
+        }
+    }
+}
+
+/// Optional content a pack ships. Views hide what a language doesn't have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Features {
+    /// A grammar doc exists (`grammar::doc_for`).
+    pub grammar: bool,
+    /// Lexicon entries carry declension tables.
+    pub declensions: bool,
+    /// Verbs carry conjugation tables, in the rows of `Manifest::inventory`.
+    pub conjugations: bool,
+    /// Per-letter recordings exist for the alphabet.
+    pub audio: bool,
+}
+
+/// A pack's `manifest.json`. Every field is optional on disk.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Manifest {
+    /// Pack folder name — the id stored in settings and item ids.
+    pub code: String,
+    /// English display name ("Georgian").
+    pub name: String,
+    /// Emoji flag for the language switcher.
+    pub flag: String,
+    /// espeak-ng voice for whole-word TTS ("ka"). `None` = no TTS.
+    pub espeak_voice: Option<String>,
+    pub direction: Direction,
+    /// Dictionary section order as leading glyphs. Empty = `alphabet.json` order.
+    pub collation: Vec<String>,
+    /// Letters that are easy to mix up by ear (Georgian ფ/პ), drilled against
+    /// each other by the alphabet page's confusion mode.
+    pub confusables: Vec<Vec<String>>,
+    /// Case and tense rows and conjugation pronouns, as top-level `cases`,
+    /// `tenses` and `pronouns`. Whatever is left out comes from the built-in
+    /// tables (`Inventory::or_builtin`).
+    #[serde(flatten)]
+    pub inventory: Inventory,
+    pub features: Features,
+}
+
+impl Manifest {
+    /// Stand-in for a pack with no readable manifest: a name derived from the
+    /// folder, the built-in tables and no optional features.
+    pub fn fallback(code: &str) -> Self {
+        let mut c = code.chars();
+        let name = match c.next() {
+            Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
+            None => String::new(),
+        };
+        Manifest {
+            code: code.to_string(),
+            name,
+            flag: "🏳️".to_string(),
+            inventory: Inventory::builtin(code),
+            ..Manifest::default()
+        }
+    }
+
+    /// Switcher label, e.g. "🇬🇪 Georgian".
+    pub fn label(&self) -> String {
+        format!("{} {}", self.flag, self.name).trim().to_string()
+    }
+
+    /// Problems a pack author should hear about, for the pack in folder `code`.
+    /// Empty means the manifest is fine.
+    pub fn validate(&self, code: &str) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.code != code {
+            problems.push(format!("code \"{}\" doesn't match folder \"{code}\"", self.code));
+        }
+        if self.name.trim().is_empty() {
+            problems.push("name is empty".to_string());
+        }
+        if let Some(v) = &self.espeak_voice {
+            let ok = !v.is_empty()
+                && v.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '_'));
+            if !ok {
+                problems.push(format!("espeak_voice \"{v}\" isn't a voice name"));
+            }
+        }
+        let mut seen = BTreeSet::new();
+        for g in &self.collation {
+            if g.trim().is_empty() {
+                problems.push("collation has an empty entry".to_string());
+            } else if !seen.insert(g.as_str()) {
+                problems.push(format!("collation lists \"{g}\" twice"));
+            }
+        }
+        for set in &self.confusables {
+            if set.len() < 2 {
+                problems.push(format!("confusable set {set:?} needs at least two letters"));
+            }
+        }
+        problems.extend(self.inventory.validate());
+        problems
+    }
+}
+
+/// The pack's manifest, or `Manifest::fallback` if it has none / it won't parse.
+pub fn manifest(lang: &str) -> Manifest {
+    let Some(json) = read_text(lang, MANIFEST_FILE, crate::assets::manifest_json_for(lang)) else {
+        return Manifest::fallback(lang);
+    };
+    match serde_json::from_str::<Manifest>(&json) {
+        Ok(mut m) => {
+            if m.code.is_empty() {
+                m.code = lang.to_string();
+            }
+            if m.name.trim().is_empty() {
+                m.name = Manifest::fallback(lang).name;
+            }
+            m.inventory = m.inventory.or_builtin(lang);
+            m
+        }
+        Err(e) => {
+            eprintln!("[langpack] couldn't parse {MANIFEST_FILE} for {lang}: {e}");
+            Manifest::fallback(lang)
+        }
+    }
+}
+
+/// Every available pack's manifest, sorted by code. Validation problems are
+/// logged, and the folder name wins over a mismatched `code`.
+pub fn registry() -> Vec<Manifest> {
+    available()
+        .into_iter()
+        .map(|code| {
+            let mut m = manifest(&code);
+            for p in m.validate(&code) {
+                eprintln!("[langpack] {code}/{MANIFEST_FILE}: {p}");
+            }
+            m.code = code;
+            m
+        })
+        .collect()
+}
+
+// ─── data files ────────────────────────────────────────────────────────────
+
+/// Text of one pack file: the on-disk copy if any, else the embedded one.
+fn read_text(lang: &str, rel: &str, embedded: Option<&'static str>) -> Option<String> {
+    if let Some(path) = resolve(lang, rel) {
+        match fs::read_to_string(&path) {
+            Ok(s) => return Some(s),
+            Err(e) => eprintln!("[langpack] couldn't read {}: {e}", path.display()),
+        }
+    }
+    embedded.map(str::to_string)
+}
+
+/// The language's letters, in `alphabet.json` order.
+pub fn alphabet(lang: &str) -> Result<Vec<Letter>, String> {
+    let json = read_text(lang, ALPHABET_FILE, crate::assets::alphabet_json_for(lang))
+        .ok_or_else(|| format!("No {ALPHABET_FILE} found for {lang}"))?;
+    serde_json::from_str(&json).map_err(|e| format!("Failed to parse alphabet for {lang}: {e}"))
+}
+
+/// The language's lexicon (seeded by its `1000.json`, with the user's overlay
+/// applied), with declension tables completed by the rule-based generator
+/// where the pack has one.
+pub fn lexicon(lang: &str) -> Result<Lexicon, String> {
+    let json = read_text(lang, LEXICON_FILE, crate::assets::freq_json_for(lang))
+        .ok_or_else(|| format!("No {LEXICON_FILE} found for {lang}"))?;
+    let mut lexicon = Lexicon::from_json(&json)
+        .map_err(|e| format!("Failed to parse lexicon for {lang}: {e}"))?;
+    lexicon.apply_user(&user_lexicon(lang));
+    lexicon.fill_declensions(lang);
+    Ok(lexicon)
+}
+
+fn user_lexicon_path(lang: &str) -> Option<PathBuf> {
+    if !is_plain_segment(lang) {
+        return None;
+    }
+    crate::paths::data_root().map(|d| d.join(USER_LEXICON_DIR).join(format!("{lang}.json")))
+}
+
+/// The user's overlay for `lang`; empty if there's none or it won't parse.
+pub fn user_lexicon(lang: &str) -> UserLexicon {
+    let Some(json) = user_lexicon_path(lang).and_then(|p| fs::read_to_string(p).ok()) else {
+        return UserLexicon::default();
+    };
+    serde_json::from_str(&json).unwrap_or_else(|e| {
+        eprintln!("[langpack] couldn't parse the user lexicon for {lang}: {e}");
+        UserLexicon::default()
+    })
+}
+
+pub fn save_user_lexicon(lang: &str, user: &UserLexicon) -> Result<(), String> {
+    let path = user_lexicon_path(lang).ok_or("no data directory to save to")?;
+    let json = serde_json::to_string_pretty(user).map_err(|e| e.to_string())?;
+    crate::paths::write_atomic(&path, &json)
+        .map_err(|e| format!("couldn't save {}: {e}", path.display()))
+}
+
+/// On-disk recording for a letter's `audio` filename, if a pack folder has one.
+pub fn letter_audio_path(lang: &str, file: &str) -> Option<PathBuf> {
+    if !is_plain_segment(file) {
+        return None;
+    }
+    resolve(lang, &format!("{LETTER_AUDIO_DIR}/{file}"))
+}
+
+/// On-disk recording for a lexicon entry's `audio` filename.
+pub fn word_audio_path(lang: &str, file: &str) -> Option<PathBuf> {
+    if !is_plain_segment(file) {
+        return None;
+    }
+    resolve(lang, &format!("{WORD_AUDIO_DIR}/{file}"))
+}
+
+// ─── Dioxus glue ───────────────────────────────────────────────────────────
+
+use dioxus::prelude::*;
+
+/// Call ONCE at the App root. Scans the packs and provides the registry.
+pub fn provide_registry() -> Signal<Vec<Manifest>> {
+    let reg = use_signal(registry);
+    use_context_provider(|| reg);
+    reg
+}
+
+/// Bumped whenever a user lexicon is saved, so `use_lexicon` readers reload.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LexiconRevision(u32);
+
+/// Call ONCE at the App root, next to `provide_registry`.
+pub fn provide_lexicon_revision() {
+    let rev = use_signal(LexiconRevision::default);
+    use_context_provider(|| rev);
+}
+
+/// `lang`'s lexicon; reloads on a language switch and after any user edit.
+pub fn use_lexicon(lang: Signal<String>) -> Resource<Lexicon> {
+    let rev = use_context::<Signal<LexiconRevision>>();
+    use_resource(move || {
+        let l = lang.read().clone();
+        let _ = rev.read();
+        async move {
+            lexicon(&l).unwrap_or_else(|e| {
+                eprintln!("[langpack] {e}");
+                Lexicon::default()
+            })
+        }
+    })
+}
+
+/// Save `user` as `lang`'s overlay and reload every lexicon reader. Failure
+/// is logged and leaves the lexicons as they were.
+pub fn commit_user_lexicon(lang: &str, user: &UserLexicon) {
+    match save_user_lexicon(lang, user) {
+        Ok(()) => {
+            let mut rev = consume_context::<Signal<LexiconRevision>>();
+            rev.write().0 += 1;
+        }
+        Err(e) => eprintln!("[langpack] {e}"),
+    }
+}
+
+/// The language registry, anywhere below the provider.
+pub fn use_registry() -> Signal<Vec<Manifest>> {
+    use_context::<Signal<Vec<Manifest>>>()
+}
+
+/// Manifest of the active language; follows the language switcher.
+pub fn use_manifest() -> Memo<Manifest> {
+    let reg = use_registry();
+    let lang = use_context::<Signal<String>>();
+    use_memo(move || {
+        let code = lang.read().clone();
+        reg.read()
+            .iter()
+            .find(|m| m.code == code)
+            .cloned()
+            .unwrap_or_else(|| Manifest::fallback(&code))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_segments_cannot_escape_langs() {
+        assert!(is_plain_segment("georgian"));
+        assert!(is_plain_segment("ch'.wav"));
+        assert!(!is_plain_segment(".."));
+        assert!(!is_plain_segment("../etc"));
+        assert!(!is_plain_segment("a\\b"));
+        assert!(!is_plain_segment(""));
+        assert!(resolve("../georgian", ALPHABET_FILE).is_none());
+    }
+
+    #[test]
+    fn builtins_load_and_unknown_languages_do_not_fall_back() {
+        assert!(!alphabet("georgian").unwrap().is_empty());
+        assert!(!lexicon("russian").unwrap().is_empty());
+        assert!(alphabet("no-such-language").is_err());
+        assert!(available().contains(&"georgian".to_string()));
+    }
+
+    #[test]
+    fn packs_offering_conjugations_ship_tables_for_their_common_verbs() {
+        for lang in crate::assets::EMBEDDED_LANGS {
+            if !manifest(lang).features.conjugations {
+                continue;
+            }
+            let lexicon = lexicon(lang).unwrap();
+            let verbs = lexicon.by_rank();
+            let top: Vec<_> = verbs
+                .iter()
+                .filter(|e| e.pos.as_deref() == Some("verb"))
+                .take(30)
+                .collect();
+            let bare = top.iter().filter(|e| e.conjugation.is_none()).count();
+            assert!(bare <= 3, "{lang}: {bare} of the top {} verbs have no table", top.len());
+        }
+    }
+
+    #[test]
+    fn every_builtin_letter_recording_is_embedded() {
+        for lang in crate::assets::EMBEDDED_LANGS {
+            for l in alphabet(lang).unwrap() {
+                if let Some(file) = l.audio.as_deref() {
+                    assert!(
+                        crate::assets::letter_audio_bytes(lang, file).is_some(),
+                        "{lang}/{file} not embedded"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn manifests_describe_builtins_and_catch_mistakes() {
+        let ka = manifest("georgian");
+        assert_eq!(ka.espeak_voice.as_deref(), Some("ka"));
+        assert!(ka.features.declensions);
+        assert!(ka.validate("georgian").is_empty());
+        // the shipped packs declare exactly the tables their generators fill
+        for lang in ["georgian", "russian"] {
+            let declared: Manifest =
+                serde_json::from_str(crate::assets::manifest_json_for(lang).unwrap()).unwrap();
+            assert_eq!(declared.inventory, Inventory::builtin(lang), "{lang}");
+        }
+
+        let bad = Manifest {
+            code: "klingon".into(),
+            espeak_voice: Some("tlh; rm -rf".into()),
+            collation: vec!["a".into(), "a".into()],
+            confusables: vec![vec!["q".into()]],
+            ..Manifest::default()
+        };
+        assert_eq!(bad.validate("tlh").len(), 5);
+        assert!(ka.confusables.iter().any(|set| set.contains(&"ფ".to_string())));
+
+        let missing = manifest("no-such-language");
+        assert_eq!(missing.name, "No-such-language");
+        assert!(missing.inventory.cases.is_empty());
+        assert_eq!(missing.features, Features::default());
+    }
+}
@@ -0,0 +1,128 @@
+//! Physical keyboard layouts as data: the shapes of the key rows, widths
+//! included, and what's printed on each key's base layer. They ship in
+//! `layouts/layouts.json` as ANSI, ISO, Dvorak, Colemak and JIS profiles.
+//! Key codes name physical positions (`KeyboardEvent.code`), so a pack's
+//! `Letter.key_code` lands on the same key whichever profile is drawn. What
+//! changes from one profile to the next is the printed label, and which keys
+//! exist at all (ISO's extra key by Left Shift, JIS's ¥ and ろ). Fingers belong
+//! to positions too, so every profile shares one finger table.
+//!
+//! The learner picks a profile in Settings (`Settings.keyboard_layout`).
+
+use std::collections::BTreeMap;
+
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+/// The profile used when none (or an unknown one) is chosen.
+pub const DEFAULT_LAYOUT: &str = "ansi";
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct PhysicalKey {
+    /// `KeyboardEvent.code`, e.g. `"KeyQ"`.
+    pub code: String,
+    /// Base-layer legend; empty for the lower half of an L-shaped Enter.
+    #[serde(default)]
+    pub label: String,
+    /// In key units; a letter key is 1.
+    #[serde(default = "one")]
+    pub width: f32,
+}
+
+fn one() -> f32 {
+    1.0
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct LayoutProfile {
+    pub id: String,
+    pub name: String,
+    /// Top to bottom, number row first; the space bar isn't listed.
+    pub rows: Vec<Vec<PhysicalKey>>,
+}
+
+impl LayoutProfile {
+    pub fn key(&self, code: &str) -> Option<&PhysicalKey> {
+        self.rows.iter().flatten().find(|k| k.code == code)
+    }
+
+    /// The printed label for `code`, if this keyboard has that key.
+    pub fn label(&self, code: &str) -> Option<&str> {
+        self.key(code)
+            .map(|k| k.label.as_str())
+            .filter(|l| !l.is_empty())
+    }
+}
+
+#[derive(Deserialize)]
+struct LayoutData {
+    /// KeyCode → finger class (`"index_left"`, …), shared by every profile.
+    fingers: BTreeMap<String, String>,
+    profiles: Vec<LayoutProfile>,
+}
+
+static LAYOUTS: Lazy<LayoutData> = Lazy::new(|| {
+    serde_json::from_str(include_str!("../layouts/layouts.json")).unwrap_or_else(|e| {
+        eprintln!("[layouts] shipped layouts.json doesn't parse: {e}");
+        LayoutData {
+            fingers: BTreeMap::new(),
+            profiles: Vec::new(),
+        }
+    })
+});
+
+/// Every shipped profile, in menu order.
+pub fn profiles() -> &'static [LayoutProfile] {
+    &LAYOUTS.profiles
+}
+
+/// The profile `id`, else the default one.
+pub fn profile(id: &str) -> Option<&'static LayoutProfile> {
+    let all = profiles();
+    all.iter()
+        .find(|p| p.id == id)
+        .or_else(|| all.iter().find(|p| p.id == DEFAULT_LAYOUT))
+}
+
+/// The finger that strikes the key at `code` in touch typing.
+pub fn finger(code: &str) -> Option<&'static str> {
+    LAYOUTS.fingers.get(code).map(String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_profile_is_complete() {
+        let ids: Vec<&str> = profiles().iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, ["ansi", "iso", "dvorak", "colemak", "jis"]);
+        for p in profiles() {
+            // number row, then Q / A / Z rows, all present and labelled
+            let codes = (0..=9)
+                .map(|d| format!("Digit{d}"))
+                .chain(('A'..='Z').map(|c| format!("Key{c}")));
+            for code in codes {
+                assert!(p.label(&code).is_some(), "{}: no {code}", p.id);
+                assert!(finger(&code).is_some(), "no finger for {code}");
+            }
+            // every row is as wide as the ANSI board
+            for row in &p.rows {
+                let width: f32 = row.iter().map(|k| k.width).sum();
+                assert!((width - 15.0).abs() < 1e-3, "{}: row is {width}", p.id);
+            }
+        }
+    }
+
+    #[test]
+    fn profiles_differ_in_labels_and_keys_not_codes() {
+        let label = |id: &str, code: &str| profile(id).and_then(|p| p.label(code));
+        assert_eq!(label("ansi", "KeyS"), Some("S"));
+        assert_eq!(label("dvorak", "KeyS"), Some("O"));
+        assert_eq!(label("colemak", "KeyS"), Some("R"));
+        assert_eq!(label("iso", "IntlBackslash"), Some("\\"));
+        assert_eq!(label("ansi", "IntlBackslash"), None);
+        assert_eq!(label("jis", "IntlYen"), Some("¥"));
+        assert_eq!(label("no-such-board", "KeyQ"), Some("Q"));
+    }
+}
@@ -1,16 +1,24 @@
 // src/learner.rs
 //
 // The Dioxus-facing bridge to the pure `learning` brain. It owns the in-memory
-// evidence log (a Signal), persists each new event to disk, and exposes a cheap
-// `emit` for drills plus a freshly-folded `LearnerModel` for readers like the
-// dashboard. The brain in `learning/` never imports any of this.
+// evidence log and a live `LearnerModel` (both Signals), persists each new
+// event to disk, and exposes a cheap `emit` for drills — which folds the event
+// straight into the model — plus the model itself for readers like the
+// dashboard. A snapshot of the model is written every `SNAPSHOT_EVERY` events
+// so startup only replays the tail, and `compact` archives what a snapshot
+// already covers. The brain in `learning/` never imports any of this.
 
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use dioxus::prelude::*;
 
-use crate::learning::{store, Evidence, LearnerModel, Millis};
+use crate::learning::fit::{self, Fit};
+use crate::learning::store::{self, Snapshot};
+use crate::learning::{Evidence, LearnerModel, Millis};
+
+/// Events between model snapshots.
+const SNAPSHOT_EVERY: usize = 250;
 
 /// Current time in unix-epoch ms (desktop). On wasm this needs a JS shim.
 pub fn now_ms() -> Millis {
@@ -21,30 +29,53 @@ pub fn now_ms() -> Millis {
         .unwrap_or(0)
 }
 
-/// Where the append-only evidence log lives. Linux/XDG for now; swap in the
-/// `directories` crate when you want macOS/Windows correctness.
-fn log_path() -> PathBuf {
+/// A path under the data root (the evidence log, fitted config, snapshots…).
+/// Linux/XDG for now; swap in the `directories` crate when you want
+/// macOS/Windows correctness.
+fn data_file(name: &str) -> PathBuf {
     crate::paths::data_root()
-        .map(|d| d.join("evidence.jsonl"))
-        .unwrap_or_else(|| PathBuf::from("evidence.jsonl"))
+        .map(|d| d.join(name))
+        .unwrap_or_else(|| PathBuf::from(name))
+}
+
+/// Where everything the learner persists lives.
+struct Files {
+    log: PathBuf,
+    /// The user's fitted tunables (written by `refit` or `lang-sprint-fit`).
+    config: PathBuf,
+    snapshots: PathBuf,
+    /// Compacted-away events (`store::compact`).
+    archive: PathBuf,
 }
 
 /// Cheap, cloneable handle that drills and views read from context.
 #[derive(Clone)]
 pub struct Learner {
     log: Signal<Vec<Evidence>>,
-    path: Arc<PathBuf>,
+    /// Records archived before the live log (`store::LogLoad::base`).
+    base: Signal<usize>,
+    model: Signal<LearnerModel>,
+    files: Arc<Files>,
+    /// Line numbers in the log file that didn't parse when it was last read.
+    corrupt: Signal<Vec<usize>>,
 }
 
 impl Learner {
-    /// Append one observation: persist it, then push to the in-memory log so any
-    /// `model()` reader recomputes. Persistence failure is logged, not fatal.
+    /// Append one observation: persist it, push it to the in-memory log, and
+    /// fold it into the live model so any reader recomputes. Persistence
+    /// failure is logged, not fatal.
     pub fn emit(&self, e: Evidence) {
-        if let Err(err) = store::append(&self.path, &e) {
+        if let Err(err) = store::append(&self.files.log, &e) {
             eprintln!("evidence persist failed: {err}");
         }
+        let mut model = self.model;
+        model.write().apply(&e);
         let mut log = self.log;
         log.write().push(e);
+
+        if self.log.peek().len().is_multiple_of(SNAPSHOT_EVERY) {
+            self.snapshot();
+        }
     }
 
     pub fn emit_all(&self, evidence: impl IntoIterator<Item = Evidence>) {
@@ -53,26 +84,128 @@ impl Learner {
         }
     }
 
-    /// Fold the whole log into a model (cheap; the log is small).
-    pub fn model(&self) -> LearnerModel {
-        LearnerModel::from_log(&self.log.read())
+    /// The live model (kept current by `emit`); `.read()` it to query.
+    pub fn model(&self) -> Signal<LearnerModel> {
+        self.model
+    }
+
+    /// Fit the tunables to the whole history, archive included
+    /// (`learning::fit`), persist them, and refold the model under them.
+    /// `None` if there isn't enough evidence yet. The fit replays the history
+    /// many times over, so it runs on a blocking thread, on the history as it
+    /// stood when called; anything emitted meanwhile is folded in on top.
+    pub async fn refit(&self) -> Option<Fit> {
+        let files = self.files.clone();
+        let cfg = self.model.peek().config();
+        // records of the whole history, so a compaction meanwhile can't move it
+        let through = *self.base.peek() + self.log.peek().len();
+        let (f, refolded) = tokio::task::spawn_blocking(move || {
+            let mut history = store::load_history(&files.log, &files.archive);
+            history.truncate(through);
+            let f = fit::fit(cfg, &history)?;
+            if let Err(err) = store::save_config(&files.config, &f.cfg) {
+                eprintln!("config persist failed: {err}");
+            }
+            let refolded = LearnerModel::from_log_with(f.cfg, &history);
+            Some((f, refolded))
+        })
+        .await
+        .unwrap_or_else(|err| {
+            eprintln!("refit failed: {err}");
+            None
+        })?;
+        let mut model = self.model;
+        model.set(refolded);
+        let base = *self.base.peek();
+        if through < base {
+            for e in store::load_archive(&self.files.archive, base).iter().skip(through) {
+                model.write().apply(e);
+            }
+        }
+        for e in self.log.peek().iter().skip(through.saturating_sub(base)) {
+            model.write().apply(e);
+        }
+        self.snapshot();
+        Some(f)
+    }
+
+    /// Snapshot the model, then archive every logged event out of the live
+    /// log. Returns how many were archived.
+    pub fn compact(&self) -> std::io::Result<usize> {
+        self.snapshot();
+        let f = &self.files;
+        let n = store::compact(&f.log, &f.snapshots, &f.archive)?;
+        if n > 0 {
+            // the file lost its head: re-read it so counts and line numbers match
+            let loaded = store::read_log(&f.log);
+            let (mut log, mut base, mut corrupt) = (self.log, self.base, self.corrupt);
+            log.set(loaded.evidence);
+            base.set(loaded.base);
+            corrupt.set(loaded.corrupt);
+        }
+        Ok(n)
     }
 
+    fn snapshot(&self) {
+        let snap = Snapshot::new(
+            now_ms(),
+            *self.base.peek(),
+            &self.log.peek(),
+            self.model.peek().clone(),
+        );
+        if let Err(err) = store::save_snapshot(&self.files.snapshots, &snap) {
+            eprintln!("model snapshot failed: {err}");
+        }
+    }
+
+    /// Log lines skipped as corrupt when the log was last read (1-based).
+    pub fn corrupt_lines(&self) -> Vec<usize> {
+        self.corrupt.read().clone()
+    }
+
+    /// Records in the live log (archived ones excluded).
     pub fn log_len(&self) -> usize {
         self.log.read().len()
     }
 }
 
-/// Call once at the App root: loads any persisted log and provides the handle.
+/// Call once at the App root: loads any persisted log, resumes the model from
+/// the latest snapshot (replaying only newer events), and provides the handle.
 pub fn provide_learner() {
-    let path = log_path();
-    let log = use_signal({
-        let p = path.clone();
-        move || store::load_log(&p)
+    let files = Arc::new(Files {
+        log: data_file(store::LOG_FILE),
+        config: data_file(store::CONFIG_FILE),
+        snapshots: data_file(store::SNAPSHOT_DIR),
+        archive: data_file(store::ARCHIVE_DIR),
+    });
+    let (log, base, corrupt) = use_hook({
+        let f = files.clone();
+        move || {
+            let loaded = store::read_log(&f.log);
+            (
+                Signal::new(loaded.evidence),
+                Signal::new(loaded.base),
+                Signal::new(loaded.corrupt),
+            )
+        }
+    });
+    let model = use_signal({
+        let f = files.clone();
+        move || {
+            let cfg = store::load_config(&f.config).unwrap_or_default();
+            let snapshot = store::load_latest_snapshot(&f.snapshots);
+            let base = *base.peek();
+            Snapshot::resume(snapshot, cfg, base, &log.peek(), || {
+                store::load_archive(&f.archive, base)
+            })
+        }
     });
     use_context_provider(|| Learner {
         log,
-        path: Arc::new(path),
+        base,
+        model,
+        files,
+        corrupt,
     });
 }
 
@@ -84,33 +217,42 @@ pub fn use_learner() -> Learner {
 /// Build per-word evidence from a finished WPM run. The current WPM test is
 /// copy-typing displayed L2 words, so it bears on Script & Sound (decode/encode
 /// the script under time pressure) — not yet Vocab-Production, which needs an
-/// L1→L2 prompt. Adjust skill/difficulty as drills specialize.
+/// L1→L2 prompt. `rank_of` looks a word's frequency rank up for its prior
+/// difficulty. Only the words the learner got to are scored; a word cut off
+/// by the timer isn't.
 pub fn wpm_evidence(
     lang: &str,
     target: &str,
     typed: &str,
     elapsed_secs: f64,
     now: Millis,
+    rank_of: impl Fn(&str) -> Option<u32>,
 ) -> Vec<Evidence> {
+    use crate::learning::item::word_prior;
     use crate::learning::{word_accuracy, Skill, Source};
 
     let tw: Vec<&str> = target.split_whitespace().collect();
-    let yw: Vec<&str> = typed.split_whitespace().collect();
-    if tw.is_empty() {
+    let mut yw: Vec<&str> = typed.split_whitespace().collect();
+    let finished = typed.chars().count() >= target.chars().count();
+    if !finished && !typed.ends_with(char::is_whitespace) {
+        yw.pop();
+    }
+    let reached = yw.len().min(tw.len());
+    if reached == 0 {
         return Vec::new();
     }
-    let per_word_ms = ((elapsed_secs * 1000.0) / tw.len() as f64).max(0.0) as u32;
-    tw.iter()
-        .enumerate()
-        .map(|(i, t)| {
-            let y = yw.get(i).copied().unwrap_or("");
+    let per_word_ms = ((elapsed_secs * 1000.0) / reached as f64).max(0.0) as u32;
+    tw[..reached]
+        .iter()
+        .zip(&yw)
+        .map(|(t, y)| {
             Evidence::new(
                 now,
                 lang,
                 format!("{lang}:word:{t}"),
                 Skill::ScriptSound,
                 word_accuracy(t, y),
-                0.0, // TODO: scale difficulty by length / frequency rank
+                word_prior(rank_of(t), t),
                 per_word_ms,
                 Source::Wpm,
             )
@@ -119,10 +261,11 @@ pub fn wpm_evidence(
 }
 
 /// Evidence from the word drill (copy-typing a displayed L2 word). The answer is
-/// on screen, so this is mostly a Script & Sound / hand-eye signal, logged at a
-/// low difficulty so a clean fast type nudges the axis only a little — it can't
-/// stand in for recall. `difficulty` is the weighting knob; lower = counts less.
-pub fn word_drill_evidence(lang: &str, word: &str, latency_ms: u32) -> Vec<Evidence> {
+/// on screen, so this is mostly a Script & Sound / hand-eye signal, logged a
+/// step below the word's prior difficulty so a clean fast type nudges the axis
+/// only a little — it can't stand in for recall.
+pub fn word_drill_evidence(lang: &str, word: &str, rank: Option<u32>, latency_ms: u32) -> Vec<Evidence> {
+    use crate::learning::item::{word_prior, COPY_OFFSET};
     use crate::learning::{Skill, Source};
     if word.trim().is_empty() {
         return Vec::new();
@@ -132,8 +275,8 @@ pub fn word_drill_evidence(lang: &str, word: &str, latency_ms: u32) -> Vec<Evide
         lang,
         format!("{lang}:word:{word}"),
         Skill::ScriptSound,
-        1.0,  // the drill only advances on a correct match
-        -1.0, // low difficulty: success here is weak positive evidence
+        1.0, // the drill only advances on a correct match
+        word_prior(rank, word) + COPY_OFFSET,
         latency_ms,
         Source::WordDrill,
     )]
@@ -141,24 +284,27 @@ pub fn word_drill_evidence(lang: &str, word: &str, latency_ms: u32) -> Vec<Evide
 
 /// Evidence from the timed meaning-recall drill ("WPM, but type the meaning").
 /// Answering in English tests recognition (L2 word -> meaning); answering in the
-/// target language tests production (meaning -> L2 word). Full difficulty (0.0):
-/// this is real recall, not copy-typing. `grade` is the continuous score from the
-/// grading core (1.0 exact; lower for typos / wrong answers).
+/// target language tests production (meaning -> L2 word), which starts a step
+/// harder. `grade` is the continuous score from the grading core (1.0 exact;
+/// lower for typos / wrong answers).
 pub fn meaning_evidence(
     lang: &str,
     to_english: bool,
     headword: &str,
+    rank: Option<u32>,
     grade: f32,
     latency_ms: u32,
 ) -> Vec<Evidence> {
+    use crate::learning::item::{word_prior, PRODUCTION_OFFSET};
     use crate::learning::{Skill, Source};
     if headword.trim().is_empty() {
         return Vec::new();
     }
-    let (skill, item) = if to_english {
-        (Skill::VocabRecognition, format!("{lang}:rec:{headword}"))
+    let prior = word_prior(rank, headword);
+    let (skill, item, difficulty) = if to_english {
+        (Skill::VocabRecognition, format!("{lang}:rec:{headword}"), prior)
     } else {
-        (Skill::VocabProduction, format!("{lang}:prod:{headword}"))
+        (Skill::VocabProduction, format!("{lang}:prod:{headword}"), prior + PRODUCTION_OFFSET)
     };
     vec![Evidence::new(
         now_ms(),
@@ -166,8 +312,194 @@ pub fn meaning_evidence(
         item,
         skill,
         grade,
-        0.0,
+        difficulty,
         latency_ms,
         Source::Recall,
     )]
 }
+
+/// Evidence from the alphabet page's letter-sound drill, one letter per
+/// answer. Hearing a letter and finding its glyph and seeing a glyph and
+/// naming its sound are separate items; `confusable` marks a round drilled
+/// against its look-alike sounds, which starts harder.
+pub fn letter_sound_evidence(
+    lang: &str,
+    letter: &str,
+    from_sound: bool,
+    confusable: bool,
+    grade: f32,
+    latency_ms: u32,
+) -> Vec<Evidence> {
+    use crate::learning::item::letter_prior;
+    use crate::learning::{Skill, Source};
+    if letter.trim().is_empty() {
+        return Vec::new();
+    }
+    let dir = if from_sound { "hear" } else { "read" };
+    vec![Evidence::new(
+        now_ms(),
+        lang,
+        format!("{lang}:letter:{dir}:{letter}"),
+        Skill::ScriptSound,
+        grade,
+        letter_prior(letter, confusable),
+        latency_ms,
+        Source::Alphabet,
+    )]
+}
+
+/// Evidence from the listening drill: the learner heard `text` (a headword,
+/// or an example phrase when `rank` is `None`) and typed it back, or typed its
+/// meaning when `to_english`. Dictation and "what does it mean" are separate
+/// items, since hearing a word well enough to spell it isn't understanding it.
+/// `latency_ms` runs from the end of playback.
+pub fn listening_evidence(
+    lang: &str,
+    text: &str,
+    rank: Option<u32>,
+    to_english: bool,
+    grade: f32,
+    latency_ms: u32,
+) -> Vec<Evidence> {
+    use crate::learning::item::{sentence_prior, word_prior};
+    use crate::learning::{Skill, Source};
+    let text = text.trim();
+    if text.is_empty() {
+        return Vec::new();
+    }
+    let difficulty = if rank.is_some() || !text.contains(char::is_whitespace) {
+        word_prior(rank, text)
+    } else {
+        sentence_prior(text)
+    };
+    let item = if to_english {
+        format!("{lang}:listen:en:{text}")
+    } else {
+        format!("{lang}:listen:{text}")
+    };
+    vec![Evidence::new(
+        now_ms(),
+        lang,
+        item,
+        Skill::Listening,
+        grade,
+        difficulty,
+        latency_ms,
+        Source::Listening,
+    )]
+}
+
+/// Evidence from finishing a sentence on the reading page or in a session: a
+/// Reading signal for the sentence as a whole. `outcome` is the average over
+/// its quizzed words, or a flat ½ when it was only read ("encountered", not
+/// tested).
+pub fn reading_evidence(lang: &str, sentence: &str, outcome: f32) -> Vec<Evidence> {
+    use crate::learning::item::sentence_prior;
+    use crate::learning::{Skill, Source};
+    use std::hash::{Hash, Hasher};
+    let text = sentence.trim();
+    if text.is_empty() {
+        return Vec::new();
+    }
+    let mut h = std::collections::hash_map::DefaultHasher::new();
+    text.hash(&mut h);
+    vec![Evidence::new(
+        now_ms(),
+        lang,
+        format!("{lang}:sentence:{:x}", h.finish()),
+        Skill::Reading,
+        outcome,
+        sentence_prior(text),
+        0,
+        Source::Reading,
+    )]
+}
+
+/// The same evidence re-tagged as a planner probe (`Source::Probe`): a
+/// question asked to resolve uncertainty about an axis, not routine practice.
+pub fn as_probe(evidence: Vec<Evidence>) -> Vec<Evidence> {
+    use crate::learning::Source;
+    evidence
+        .into_iter()
+        .map(|e| Evidence {
+            source: Source::Probe,
+            ..e
+        })
+        .collect()
+}
+
+/// Evidence from one conjugation-drill cell: a Grammar signal per (verb, tense,
+/// person), so the scheduler can bring back exactly the cells that slip.
+#[allow(clippy::too_many_arguments)]
+pub fn conjugation_evidence(
+    lang: &str,
+    verb: &str,
+    rank: Option<u32>,
+    tense: &str,
+    person: &str,
+    form: &str,
+    grade: f32,
+    latency_ms: u32,
+) -> Vec<Evidence> {
+    use crate::learning::item::word_prior;
+    use crate::learning::{Skill, Source};
+    if verb.trim().is_empty() {
+        return Vec::new();
+    }
+    vec![Evidence::new(
+        now_ms(),
+        lang,
+        format!("{lang}:conj:{verb}:{tense}:{person}"),
+        Skill::Grammar,
+        grade,
+        word_prior(rank, form),
+        latency_ms,
+        Source::Conjugation,
+    )]
+}
+
+/// Evidence from one grammar-bank question: a Grammar signal per prompt, at a
+/// prior from the expected answer and whether it was typed or picked.
+pub fn grammar_evidence(
+    lang: &str,
+    prompt: &str,
+    answer: &str,
+    typed: bool,
+    grade: f32,
+    latency_ms: u32,
+) -> Vec<Evidence> {
+    use crate::learning::item::grammar_prior;
+    use crate::learning::{Skill, Source};
+    use std::hash::{Hash, Hasher};
+    let mut h = std::collections::hash_map::DefaultHasher::new();
+    prompt.hash(&mut h);
+    vec![Evidence::new(
+        now_ms(),
+        lang,
+        format!("{lang}:grammar:{:x}", h.finish()),
+        Skill::Grammar,
+        grade,
+        grammar_prior(answer, typed),
+        latency_ms,
+        Source::Grammar,
+    )]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::learning::item::word_prior;
+
+    #[test]
+    fn wpm_evidence_scores_reached_words_at_their_ranked_difficulty() {
+        let rank_of = |w: &str| (w == "და").then_some(1);
+        let ev = wpm_evidence("georgian", "და არის ფანჯარა", "და არს ფან", 6.0, 0, rank_of);
+        let items: Vec<&str> = ev.iter().map(|e| e.item.as_str()).collect();
+        assert_eq!(items, ["georgian:word:და", "georgian:word:არის"], "ფანჯარა was cut off");
+        assert_eq!(ev[0].difficulty, word_prior(Some(1), "და"));
+        assert_eq!(ev[1].difficulty, word_prior(None, "არის"));
+        assert!(ev[0].difficulty < ev[1].difficulty);
+        assert!(ev[0].outcome > ev[1].outcome);
+        assert_eq!(ev[0].latency_ms, 3_000);
+    }
+}
@@ -4,6 +4,8 @@
 //! input, not feed-forward". The scheduler's half-life decides *when* to
 //! re-measure; the answer is the only thing that moves the estimate.
 
+use serde::{Deserialize, Serialize};
+
 use super::evidence::{Evidence, Millis};
 
 /// Logistic squash, shared with the model/dashboard for logit ↔ unit maps.
@@ -13,7 +15,7 @@ pub fn logistic(x: f32) -> f32 {
 
 /// A correct answer counts for more when fast (automaticity); a wrong answer
 /// stays wrong regardless of speed.
-fn speed_adjust(outcome: f32, latency_ms: u32) -> f32 {
+pub(super) fn speed_adjust(outcome: f32, latency_ms: u32) -> f32 {
     if outcome <= 0.0 {
         return 0.0;
     }
@@ -22,7 +24,7 @@ fn speed_adjust(outcome: f32, latency_ms: u32) -> f32 {
     ((0.7 + 0.3 * fast) * outcome).clamp(0.0, 1.0)
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Belief {
     pub mean: f32,
     pub var: f32,
@@ -57,9 +59,11 @@ impl Belief {
     }
 
     /// Fold one observation in (Kalman update on the projected prior).
-    pub fn observe(&mut self, e: &Evidence, q_per_day: f32) {
+    /// `difficulty` is the item's current estimate (`item.rs`), which starts
+    /// at `e.difficulty` and is refined as the log grows.
+    pub fn observe(&mut self, e: &Evidence, difficulty: f32, q_per_day: f32) {
         let prior = self.at(e.at, q_per_day);
-        let expected = logistic(prior.mean - e.difficulty);
+        let expected = logistic(prior.mean - difficulty);
         let y = speed_adjust(e.outcome, e.latency_ms);
         // measurement variance ~ inverse Fisher information of a Bernoulli trial
         let meas_var = 1.0 / (expected * (1.0 - expected)).max(1e-3);
@@ -30,6 +30,8 @@ pub enum Source {
     Recall,
     /// Authored grammar drill (the grammar “Practice” mode).
     Grammar,
+    /// Letter-sound drill on the alphabet page.
+    Alphabet,
 }
 
 /// One graded observation.
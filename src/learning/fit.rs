@@ -0,0 +1,141 @@
+//! Offline parameter fitting: tune `Config` to one user's own evidence log.
+//!
+//! Replay the log in order and, *before* folding each event in, predict it
+//! from the current belief and item estimate: `p = logistic(mean − difficulty)`. The mean
+//! log-loss of those predictions against the graded outcomes scores a config;
+//! a grid search over `q_per_day`, `prior_var` and `floor_margin` picks the
+//! best. `conservative_k` never moves the mean, so it is fitted afterwards
+//! against the radar's own estimate, `logistic(mean − k·sd − difficulty)`.
+
+use super::belief::logistic;
+use super::evidence::Evidence;
+use super::model::{Config, LearnerModel};
+
+/// Below this many events the fit is noise; keep the defaults.
+pub const MIN_EVENTS: usize = 30;
+
+const Q_GRID: [f32; 6] = [0.005, 0.01, 0.02, 0.05, 0.1, 0.2];
+const PRIOR_VAR_GRID: [f32; 5] = [0.25, 0.5, 1.0, 2.0, 4.0];
+const FLOOR_MARGIN_GRID: [f32; 5] = [0.0, 0.1, 0.25, 0.5, 1.0];
+const K_GRID: [f32; 9] = [0.0, 0.25, 0.5, 0.75, 1.0, 1.25, 1.5, 2.0, 3.0];
+
+/// Result of `fit`: the chosen config and how much it helped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fit {
+    pub cfg: Config,
+    /// Mean log-loss of the starting config.
+    pub baseline_loss: f32,
+    /// Mean log-loss of `cfg` (≤ `baseline_loss`).
+    pub loss: f32,
+    pub events: usize,
+}
+
+/// Binary cross-entropy with a soft label `y` in 0..=1.
+fn log_loss(p: f32, y: f32) -> f32 {
+    let p = p.clamp(1e-4, 1.0 - 1e-4);
+    -(y * p.ln() + (1.0 - y) * (1.0 - p).ln())
+}
+
+/// One pre-event prediction: (mean, sd, difficulty, outcome).
+type Prediction = (f32, f32, f32, f32);
+
+/// Replay `log` under `cfg`, recording each event's belief just before it.
+fn predictions(cfg: Config, log: &[Evidence]) -> Vec<Prediction> {
+    let mut m = LearnerModel::new(cfg);
+    let mut out = Vec::with_capacity(log.len());
+    for e in log {
+        let b = m.belief(e.lang_key(), e.skill).at(e.at, cfg.q_per_day);
+        out.push((b.mean, b.var.sqrt(), m.difficulty(e), e.outcome));
+        m.apply(e);
+    }
+    out
+}
+
+fn mean_loss(preds: &[Prediction], k: f32) -> f32 {
+    if preds.is_empty() {
+        return 0.0;
+    }
+    let total: f32 = preds
+        .iter()
+        .map(|&(mean, sd, d, y)| log_loss(logistic(mean - k * sd - d), y))
+        .sum();
+    total / preds.len() as f32
+}
+
+/// Mean log-loss of `logistic(mean − difficulty)` over a replay of `log`.
+pub fn score(cfg: Config, log: &[Evidence]) -> f32 {
+    mean_loss(&predictions(cfg, log), 0.0)
+}
+
+/// Grid-search the tunables, keeping `start`'s other fields (prior mean,
+/// scheduler). `None` if the log is shorter than `MIN_EVENTS`.
+pub fn fit(start: Config, log: &[Evidence]) -> Option<Fit> {
+    if log.len() < MIN_EVENTS {
+        return None;
+    }
+    let baseline_loss = score(start, log);
+
+    let mut best = (start, baseline_loss);
+    for q in Q_GRID {
+        for prior_var in PRIOR_VAR_GRID {
+            for floor_margin in FLOOR_MARGIN_GRID {
+                let cfg = Config {
+                    q_per_day: q,
+                    prior_var,
+                    floor_margin,
+                    ..start
+                };
+                let loss = score(cfg, log);
+                if loss < best.1 {
+                    best = (cfg, loss);
+                }
+            }
+        }
+    }
+
+    let preds = predictions(best.0, log);
+    let mut k_best = (best.0.conservative_k, mean_loss(&preds, best.0.conservative_k));
+    for k in K_GRID {
+        let loss = mean_loss(&preds, k);
+        if loss < k_best.1 {
+            k_best = (k, loss);
+        }
+    }
+
+    Some(Fit {
+        cfg: Config {
+            conservative_k: k_best.0,
+            ..best.0
+        },
+        baseline_loss,
+        loss: best.1,
+        events: log.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::learning::evidence::Source;
+    use crate::learning::skill::Skill;
+
+    fn ev(i: u64, outcome: f32) -> Evidence {
+        Evidence::new(1_000 + i * 60_000, "ka", "x", Skill::ScriptSound, outcome, 0.0, 900, Source::Wpm)
+    }
+
+    #[test]
+    fn first_event_is_scored_from_the_prior() {
+        // prior mean 0, difficulty 0 → p = 0.5 → loss ln 2
+        let loss = score(Config::default(), &[ev(0, 1.0)]);
+        assert!((loss - std::f32::consts::LN_2).abs() < 1e-4);
+    }
+
+    #[test]
+    fn fit_never_does_worse_than_the_start() {
+        let log: Vec<Evidence> = (0..60).map(|i| ev(i, if i % 5 == 0 { 0.0 } else { 1.0 })).collect();
+        let f = fit(Config::default(), &log).unwrap();
+        assert!(f.loss <= f.baseline_loss);
+        assert_eq!(f.events, 60);
+        assert!(fit(Config::default(), &log[..10]).is_none());
+    }
+}
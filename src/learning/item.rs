@@ -0,0 +1,135 @@
+//! Item difficulty on the ability scale — a Rasch / 1-PL IRT view of each
+//! thing we test. Emitters stamp `Evidence.difficulty` with a *prior* from
+//! what they know up front (frequency rank, length, script); the model then
+//! keeps a Gaussian per item and refines it from the outcomes in the log, the
+//! mirror image of the ability update: a surprise failure makes the item
+//! harder, a surprise success makes it easier. `Belief::observe` reads the
+//! refined value, so clearing a hard item moves ability more than a trivial
+//! one.
+
+use serde::{Deserialize, Serialize};
+
+use super::belief::logistic;
+
+/// Prior variance of a fresh item estimate (the emitter's guess is rough).
+const PRIOR_VAR: f32 = 0.5;
+/// Rank 1 sits here; rank 1000 at `RANK_EASY + RANK_SPAN`.
+const RANK_EASY: f32 = -1.0;
+const RANK_SPAN: f32 = 1.5;
+/// No known rank: treat as a fairly rare word.
+const UNRANKED: f32 = 0.75;
+/// Per character beyond (or short of) a 5-letter word.
+const PER_CHAR: f32 = 0.08;
+/// A non-Latin script is harder to decode for an English-speaking learner.
+const NON_LATIN: f32 = 0.25;
+/// Producing a word is harder than recognising it.
+pub const PRODUCTION_OFFSET: f32 = 0.5;
+/// Copying a displayed word is far easier than recalling it: a clean type is
+/// weak positive evidence.
+pub const COPY_OFFSET: f32 = -1.0;
+/// A lone letter sits at the easy end of the scale…
+const LETTER: f32 = -1.2;
+/// …unless it's heard against the one it's easily confused with (ფ vs პ).
+const CONFUSABLE: f32 = 0.8;
+
+/// Prior difficulty of a word from its frequency rank (1 = commonest),
+/// length, and script. Roughly −1.3 (short, common) .. +2 (long, rare).
+pub fn word_prior(rank: Option<u32>, word: &str) -> f32 {
+    let by_rank = match rank {
+        Some(r) if r > 0 => (RANK_EASY + RANK_SPAN * (r as f32).ln() / 1000f32.ln()).min(1.5),
+        _ => UNRANKED,
+    };
+    let chars = word.chars().filter(|c| c.is_alphabetic()).count() as f32;
+    let by_length = (PER_CHAR * (chars - 5.0)).clamp(-0.3, 0.6);
+    by_rank + by_length + script_prior(word)
+}
+
+/// Prior difficulty of a whole sentence: longer is harder.
+pub fn sentence_prior(sentence: &str) -> f32 {
+    let words = sentence.split_whitespace().count() as f32;
+    (0.1 * (words - 6.0)).clamp(-0.4, 1.0) + script_prior(sentence)
+}
+
+/// Prior difficulty of telling one letter from its sound (or back), harder
+/// when the choice is between `confusable` letters.
+pub fn letter_prior(letter: &str, confusable: bool) -> f32 {
+    LETTER + script_prior(letter) + if confusable { CONFUSABLE } else { 0.0 }
+}
+
+/// Prior difficulty of an authored grammar question from its answer: typing
+/// the form is production, picking it from a list is recognition.
+pub fn grammar_prior(answer: &str, typed: bool) -> f32 {
+    let chars = answer.chars().filter(|c| c.is_alphabetic()).count() as f32;
+    let by_length = (PER_CHAR * (chars - 5.0)).clamp(-0.3, 0.6);
+    let recall = if typed { PRODUCTION_OFFSET } else { 0.0 };
+    by_length + script_prior(answer) + recall
+}
+
+fn script_prior(text: &str) -> f32 {
+    if text.chars().any(|c| c.is_alphabetic() && !c.is_ascii()) {
+        NON_LATIN
+    } else {
+        0.0
+    }
+}
+
+/// The model's running estimate of one item's difficulty.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ItemDifficulty {
+    pub mean: f32,
+    pub var: f32,
+    /// Observations folded in.
+    pub n: u32,
+}
+
+impl ItemDifficulty {
+    pub fn prior(mean: f32) -> Self {
+        ItemDifficulty {
+            mean,
+            var: PRIOR_VAR,
+            n: 0,
+        }
+    }
+
+    /// Fold one graded outcome `y`, given the learner's ability *before* it.
+    pub fn observe(&mut self, ability: f32, y: f32) {
+        let expected = logistic(ability - self.mean);
+        let meas_var = 1.0 / (expected * (1.0 - expected)).max(1e-3);
+        let k = self.var / (self.var + meas_var);
+        self.mean -= k * (y - expected);
+        self.var = (self.var * (1.0 - k)).max(0.05);
+        self.n = self.n.saturating_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rare_long_and_foreign_words_start_harder() {
+        assert!(word_prior(Some(1), "cat") < word_prior(Some(900), "cat"));
+        assert!(word_prior(Some(50), "cat") < word_prior(Some(50), "catastrophe"));
+        assert!(word_prior(Some(50), "kaci") < word_prior(Some(50), "კაცი"));
+        assert_eq!(word_prior(None, "cat"), word_prior(Some(0), "cat"));
+        assert!(letter_prior("ფ", false) < letter_prior("ფ", true));
+        assert!(letter_prior("ფ", true) < word_prior(Some(50), "კაცი"));
+        assert!(grammar_prior("სახლში", false) < grammar_prior("სახლში", true));
+    }
+
+    #[test]
+    fn surprises_move_the_item_not_just_the_learner() {
+        let mut d = ItemDifficulty::prior(0.0);
+        d.observe(1.0, 0.0); // a capable learner fails it…
+        let once = d.mean;
+        for _ in 0..4 {
+            d.observe(1.0, 0.0); // …and keeps failing it
+        }
+        assert!(0.0 < once && once < d.mean, "{once} {d:?}");
+        assert_eq!(d.n, 5);
+
+        let mut easy = ItemDifficulty::prior(0.0);
+        easy.observe(-1.0, 1.0);
+        assert!(easy.mean < 0.0);
+    }
+}
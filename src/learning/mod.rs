@@ -6,7 +6,10 @@
 
 pub mod belief;
 pub mod evidence;
+pub mod fit;
+pub mod item;
 pub mod model;
+pub mod planner;
 pub mod scheduler;
 pub mod skill;
 pub mod store;
@@ -14,5 +17,5 @@ pub mod store;
 pub use belief::Belief;
 pub use evidence::{word_accuracy, Evidence, ItemId, Millis, Source};
 pub use model::{Config, LearnerModel};
-pub use scheduler::Memory;
+pub use scheduler::{Memory, Scheduler, SchedulerKind};
 pub use skill::Skill;
@@ -7,15 +7,19 @@
 
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 
-use super::belief::{logistic, Belief};
+use super::belief::{logistic, speed_adjust, Belief};
 use super::evidence::{Evidence, ItemId, Millis};
-use super::scheduler::{due_items, Memory};
+use super::item::ItemDifficulty;
+use super::scheduler::{due_items, Memory, SchedulerKind};
 use super::skill::Skill;
 
-/// Tunables. Defaults are a starting point — expect to sweep these against logs.
-#[derive(Clone, Copy, Debug)]
+/// Tunables. Defaults are a starting point — `fit.rs` sweeps them against the
+/// user's own log. Missing fields in a saved config fall back to the defaults.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Config {
     /// Variance added per idle day (uncertainty growth, not assumed forgetting).
     pub q_per_day: f32,
@@ -25,6 +29,8 @@ pub struct Config {
     pub conservative_k: f32,
     /// A demonstrated skill floors each prerequisite to `downstream_mean − margin`.
     pub floor_margin: f32,
+    /// Item scheduler (HLR or FSRS). Affects `due` only, never mastery.
+    pub scheduler: SchedulerKind,
 }
 
 impl Default for Config {
@@ -35,14 +41,19 @@ impl Default for Config {
             prior_var: 1.0,
             conservative_k: 1.0,
             floor_margin: 0.25,
+            scheduler: SchedulerKind::Hlr,
         }
     }
 }
 
 /// All beliefs and memories for ONE language.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 struct LangState {
     skills: HashMap<Skill, Belief>,
     items: HashMap<ItemId, Memory>,
+    /// Per-skill item difficulty estimates: copying a word and recalling its
+    /// meaning are different tasks, so they calibrate separately.
+    difficulty: HashMap<Skill, HashMap<ItemId, ItemDifficulty>>,
     events: u64,
 }
 
@@ -54,18 +65,45 @@ impl LangState {
         LangState {
             skills,
             items: HashMap::new(),
+            difficulty: HashMap::new(),
             events: 0,
         }
     }
 
+    /// A skill's belief, or the prior if this state predates the skill (an old
+    /// snapshot).
+    fn skill(&self, s: Skill, cfg: &Config) -> Belief {
+        self.skills
+            .get(&s)
+            .copied()
+            .unwrap_or_else(|| Belief::prior(cfg.prior_mean, cfg.prior_var))
+    }
+
+    /// The item's difficulty estimate, or the emitter's prior if it's new.
+    fn difficulty(&self, e: &Evidence) -> f32 {
+        self.difficulty
+            .get(&e.skill)
+            .and_then(|items| items.get(&e.item))
+            .map_or(e.difficulty, |d| d.mean)
+    }
+
     fn observe(&mut self, e: &Evidence, cfg: &Config) {
-        if let Some(b) = self.skills.get_mut(&e.skill) {
-            b.observe(e, cfg.q_per_day);
-        }
-        self.items
+        let belief = self
+            .skills
+            .entry(e.skill)
+            .or_insert_with(|| Belief::prior(cfg.prior_mean, cfg.prior_var));
+        let ability = belief.mean;
+        let item = self
+            .difficulty
+            .entry(e.skill)
+            .or_default()
             .entry(e.item.clone())
-            .or_insert_with(Memory::seed)
-            .review(e.at, e.outcome >= 0.5);
+            .or_insert_with(|| ItemDifficulty::prior(e.difficulty));
+        belief.observe(e, item.mean, cfg.q_per_day);
+        item.observe(ability, speed_adjust(e.outcome, e.latency_ms));
+
+        let mem = self.items.entry(e.item.clone()).or_insert_with(Memory::seed);
+        cfg.scheduler.get().review(mem, e.at, e.outcome, e.latency_ms);
         self.events += 1;
         self.propagate_floor(e.skill, e.at, cfg);
     }
@@ -103,7 +141,7 @@ impl LangState {
     fn mastery(&self, now: Millis, cfg: &Config) -> HashMap<Skill, Option<f32>> {
         Skill::iter()
             .map(|s| {
-                let b = &self.skills[&s];
+                let b = self.skill(s, cfg);
                 let v = b
                     .observed()
                     .then(|| logistic(b.conservative(now, cfg.q_per_day, cfg.conservative_k)));
@@ -115,7 +153,7 @@ impl LangState {
     fn mean_mastery(&self, now: Millis, cfg: &Config) -> Option<f32> {
         let vals: Vec<f32> = Skill::iter()
             .filter_map(|s| {
-                let b = &self.skills[&s];
+                let b = self.skill(s, cfg);
                 b.observed()
                     .then(|| logistic(b.conservative(now, cfg.q_per_day, cfg.conservative_k)))
             })
@@ -128,6 +166,9 @@ impl LangState {
     }
 }
 
+/// Serializable so a folded model can be snapshotted (`store::Snapshot`) and
+/// resumed by applying only the log tail.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LearnerModel {
     cfg: Config,
     langs: HashMap<String, LangState>,
@@ -154,6 +195,10 @@ impl LearnerModel {
         m
     }
 
+    pub fn config(&self) -> Config {
+        self.cfg
+    }
+
     /// Fold a single observation into its language bucket.
     pub fn apply(&mut self, e: &Evidence) {
         let key = e.lang_key().to_string();
@@ -173,20 +218,41 @@ impl LearnerModel {
         }
     }
 
+    /// Current difficulty of the item `e` tests (for `fit`'s predictions).
+    pub fn difficulty(&self, e: &Evidence) -> f32 {
+        self.langs
+            .get(e.lang_key())
+            .map_or(e.difficulty, |l| l.difficulty(e))
+    }
+
     pub fn belief(&self, lang: &str, s: Skill) -> Belief {
         match self.langs.get(lang) {
-            Some(l) => l.skills[&s],
+            Some(l) => l.skill(s, &self.cfg),
             None => Belief::prior(self.cfg.prior_mean, self.cfg.prior_var),
         }
     }
 
     pub fn due(&self, lang: &str, now: Millis, target: f32) -> Vec<ItemId> {
         match self.langs.get(lang) {
-            Some(l) => due_items(&l.items, now, target),
+            Some(l) => due_items(&l.items, self.cfg.scheduler.get(), now, target),
             None => Vec::new(),
         }
     }
 
+    /// Whether `item` has any evidence yet.
+    pub fn seen(&self, lang: &str, item: &str) -> bool {
+        self.langs.get(lang).is_some_and(|l| l.items.contains_key(item))
+    }
+
+    /// Predicted recall of `item` at `now` by the configured scheduler; 0 for
+    /// an item with no evidence.
+    pub fn retrievability(&self, lang: &str, item: &str, now: Millis) -> f32 {
+        self.langs
+            .get(lang)
+            .and_then(|l| l.items.get(item))
+            .map_or(0.0, |m| self.cfg.scheduler.get().retrievability(m, now))
+    }
+
     pub fn item_count(&self, lang: &str) -> usize {
         self.langs.get(lang).map(|l| l.items.len()).unwrap_or(0)
     }
@@ -262,6 +328,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn clearing_a_hard_item_counts_for_more() {
+        let gain = |difficulty: f32| {
+            let mut m = LearnerModel::new(Config::default());
+            m.apply(&Evidence::new(1_000, "ka", "x", Skill::VocabRecognition, 1.0, difficulty, 800, Source::Recall));
+            m.belief("ka", Skill::VocabRecognition).mean
+        };
+        assert!(gain(1.5) > gain(-1.0));
+    }
+
+    #[test]
+    fn retrievability_is_zero_until_reviewed_then_decays() {
+        let mut m = LearnerModel::new(Config::default());
+        assert_eq!(m.retrievability("ka", "x", 1_000), 0.0);
+        m.apply(&ev(1_000, Skill::VocabRecognition, 1.0));
+        let fresh = m.retrievability("ka", "x", 1_000);
+        assert!(fresh > 0.99);
+        assert!(m.retrievability("ka", "x", 1_000 + 30 * 86_400_000) < fresh);
+        assert_eq!(m.retrievability("ru", "x", 1_000), 0.0);
+    }
+
     #[test]
     fn untested_axis_reports_none() {
         let m = LearnerModel::new(Config::default());
@@ -308,4 +395,18 @@ mod tests {
         assert_eq!(m.event_count("georgian"), 10);
         assert_eq!(m.event_count("russian"), 1);
     }
+
+    #[test]
+    fn snapshot_plus_tail_equals_full_fold() {
+        let log: Vec<Evidence> = (0..40)
+            .map(|i| ev(1_000 + i * 3_600_000, Skill::Reading, if i % 3 == 0 { 0.0 } else { 1.0 }))
+            .collect();
+        let mut half = LearnerModel::from_log(&log[..25]);
+        let json = serde_json::to_string(&half).unwrap();
+        half = serde_json::from_str(&json).unwrap();
+        for e in &log[25..] {
+            half.apply(e);
+        }
+        assert_eq!(half, LearnerModel::from_log(&log));
+    }
 }
@@ -0,0 +1,329 @@
+//! "Today's session": turn the model into a short, timed plan. Three sources,
+//! in priority order:
+//!
+//! - probes for skill axes the model is unsure of (high `Belief.var`), aimed
+//!   at words whose difficulty sits at the current ability estimate, where
+//!   one answer says the most;
+//! - items the scheduler says are due;
+//! - new words, commonest first.
+//!
+//! Each step names the drill that runs it. Pure — `views/session.rs` runs it.
+
+use std::collections::HashSet;
+
+use strum::IntoEnumIterator;
+
+use super::evidence::Millis;
+use super::item::{word_prior, COPY_OFFSET, PRODUCTION_OFFSET};
+use super::model::LearnerModel;
+use super::skill::Skill;
+
+/// A skill axis at or above this variance gets probed.
+pub const UNCERTAIN_VAR: f32 = 0.5;
+/// Recall target passed to `LearnerModel::due`.
+const DUE_TARGET: f32 = 0.9;
+/// Shares of the budget probes and due reviews may take; new words get the rest.
+const PROBE_SHARE: f32 = 0.3;
+const DUE_SHARE: f32 = 0.5;
+const PROBES_PER_AXIS: usize = 2;
+const NEW_MAX: usize = 10;
+
+/// Where a step runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Drill {
+    /// Meaning test, L2 → English.
+    Recognition,
+    /// Meaning test, English → L2.
+    Production,
+    /// Copy-type the word.
+    Typing,
+    /// Letter-sound drill.
+    Letters,
+    /// Dictation.
+    Listening,
+    /// Conjugation cells.
+    Conjugation,
+    GrammarQuiz,
+    Reading,
+}
+
+impl Drill {
+    /// The drill that probes `skill`.
+    pub fn for_skill(skill: Skill) -> Drill {
+        match skill {
+            Skill::VocabRecognition => Drill::Recognition,
+            Skill::VocabProduction => Drill::Production,
+            Skill::ScriptSound => Drill::Typing,
+            Skill::Grammar => Drill::GrammarQuiz,
+            Skill::Reading => Drill::Reading,
+            Skill::Listening => Drill::Listening,
+        }
+    }
+
+    /// One question about one word, vs. a block of the whole drill.
+    pub fn per_word(self) -> bool {
+        matches!(self, Drill::Recognition | Drill::Production | Drill::Typing)
+    }
+
+    /// Rough time a step takes.
+    pub fn secs(self) -> u32 {
+        match self {
+            Drill::Recognition | Drill::Production => 20,
+            Drill::Typing => 10,
+            Drill::Letters | Drill::Reading => 60,
+            Drill::Listening | Drill::Conjugation => 90,
+            Drill::GrammarQuiz => 120,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Drill::Recognition => "Meaning",
+            Drill::Production => "Say it",
+            Drill::Typing => "Type it",
+            Drill::Letters => "Letter sounds",
+            Drill::Listening => "Listening",
+            Drill::Conjugation => "Conjugation",
+            Drill::GrammarQuiz => "Grammar quiz",
+            Drill::Reading => "Reading",
+        }
+    }
+
+    /// Prior difficulty of asking `word` (rank `rank`) in this drill — the
+    /// same priors the emitters log.
+    fn prior(self, rank: u32, word: &str) -> f32 {
+        let d = word_prior(Some(rank), word);
+        match self {
+            Drill::Production => d + PRODUCTION_OFFSET,
+            Drill::Typing => d + COPY_OFFSET,
+            _ => d,
+        }
+    }
+
+    /// The drill and headword behind an item id (`{lang}:rec:{word}`, …).
+    pub fn for_item(lang: &str, item: &str) -> Option<(Drill, Option<String>)> {
+        let rest = item.strip_prefix(lang)?.strip_prefix(':')?;
+        let (kind, tail) = rest.split_once(':')?;
+        let word = Some(tail.to_string());
+        match kind {
+            "rec" => Some((Drill::Recognition, word)),
+            "prod" => Some((Drill::Production, word)),
+            "word" => Some((Drill::Typing, word)),
+            "letter" => Some((Drill::Letters, None)),
+            "listen" => Some((Drill::Listening, None)),
+            "conj" => Some((Drill::Conjugation, None)),
+            "grammar" => Some((Drill::GrammarQuiz, None)),
+            "sentence" => Some((Drill::Reading, None)),
+            _ => None,
+        }
+    }
+}
+
+/// Why a step is in the plan.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Why {
+    /// Resolves uncertainty about a skill axis — logged as `Source::Probe`.
+    Uncertain,
+    Due,
+    New,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step {
+    pub drill: Drill,
+    pub why: Why,
+    /// Headword for per-word drills; `None` for a drill block.
+    pub word: Option<String>,
+}
+
+/// Accumulates steps under the total budget and a per-source cap. A source
+/// always gets its first step if the total allows, so a short budget still
+/// touches everything.
+struct Builder {
+    steps: Vec<Step>,
+    taken: HashSet<(Drill, Option<String>)>,
+    used: u32,
+    budget: u32,
+}
+
+impl Builder {
+    fn push(&mut self, step: Step, spent: &mut u32, cap: u32) -> bool {
+        let secs = step.drill.secs();
+        let fits = self.used + secs <= self.budget && (*spent == 0 || *spent + secs <= cap);
+        if !fits || !self.taken.insert((step.drill, step.word.clone())) {
+            return false;
+        }
+        self.used += secs;
+        *spent += secs;
+        self.steps.push(step);
+        true
+    }
+}
+
+/// Plan about `budget_secs` of practice in `lang`. `words` is the lexicon as
+/// `(rank, headword)`, commonest first.
+pub fn plan(
+    model: &LearnerModel,
+    lang: &str,
+    now: Millis,
+    words: &[(u32, String)],
+    budget_secs: u32,
+) -> Vec<Step> {
+    let mut b = Builder {
+        steps: Vec::new(),
+        taken: HashSet::new(),
+        used: 0,
+        budget: budget_secs,
+    };
+    let q = model.config().q_per_day;
+
+    // 1. Probes, least certain axis first.
+    let cap = (budget_secs as f32 * PROBE_SHARE) as u32;
+    let mut spent = 0;
+    let mut axes: Vec<(f32, Skill)> = Skill::iter()
+        .map(|s| (model.belief(lang, s).at(now, q).var, s))
+        .filter(|&(var, _)| var >= UNCERTAIN_VAR)
+        .collect();
+    axes.sort_by(|a, b| b.0.total_cmp(&a.0));
+    for (_, skill) in axes {
+        let drill = Drill::for_skill(skill);
+        if !drill.per_word() {
+            b.push(Step { drill, why: Why::Uncertain, word: None }, &mut spent, cap);
+            continue;
+        }
+        // Words nearest the ability estimate: p(correct) ≈ ½, the most informative.
+        let mean = model.belief(lang, skill).mean;
+        let mut pool: Vec<(f32, &String)> = words
+            .iter()
+            .map(|(rank, w)| ((drill.prior(*rank, w) - mean).abs(), w))
+            .collect();
+        pool.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut n = 0;
+        for (_, w) in pool {
+            if n == PROBES_PER_AXIS {
+                break;
+            }
+            let step = Step { drill, why: Why::Uncertain, word: Some(w.clone()) };
+            if b.push(step, &mut spent, cap) {
+                n += 1;
+            } else if b.used + drill.secs() > b.budget {
+                break;
+            }
+        }
+    }
+
+    // 2. Due items, most overdue first. Letters, dictations, conjugation cells
+    //    and hashed ids (grammar, sentences) fold into one block of their drill.
+    let cap = (budget_secs as f32 * DUE_SHARE) as u32;
+    let mut spent = 0;
+    for id in model.due(lang, now, DUE_TARGET) {
+        if let Some((drill, word)) = Drill::for_item(lang, &id) {
+            let word = word.filter(|_| drill.per_word());
+            b.push(Step { drill, why: Why::Due, word }, &mut spent, cap);
+        }
+    }
+
+    // 3. New words for whatever time is left.
+    let mut spent = 0;
+    let mut added = 0;
+    for (_, w) in words {
+        if added == NEW_MAX || b.used + Drill::Recognition.secs() > b.budget {
+            break;
+        }
+        if model.seen(lang, &format!("{lang}:rec:{w}")) {
+            continue;
+        }
+        let step = Step { drill: Drill::Recognition, why: Why::New, word: Some(w.clone()) };
+        if b.push(step, &mut spent, budget_secs) {
+            added += 1;
+        }
+    }
+
+    b.steps
+}
+
+/// Total planned time.
+pub fn total_secs(steps: &[Step]) -> u32 {
+    steps.iter().map(|s| s.drill.secs()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::learning::evidence::{Evidence, Source};
+    use crate::learning::model::Config;
+
+    const DAY: Millis = 86_400_000;
+
+    fn lexicon() -> Vec<(u32, String)> {
+        ["და", "არ", "ის", "რომ", "ეს", "კაცი", "წიგნი", "სახლი", "ქალაქი", "მეგობარი", "მასწავლებელი", "ლამაზი"]
+            .iter()
+            .enumerate()
+            .map(|(i, w)| (i as u32 + 1, w.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn a_fresh_learner_gets_probes_then_new_words_within_budget() {
+        let m = LearnerModel::new(Config::default());
+        let steps = plan(&m, "ka", DAY, &lexicon(), 600);
+        assert!(total_secs(&steps) <= 600);
+        assert!(steps.iter().any(|s| s.why == Why::Uncertain && s.drill == Drill::Production));
+        let new: Vec<&str> = steps
+            .iter()
+            .filter(|s| s.why == Why::New)
+            .filter_map(|s| s.word.as_deref())
+            .collect();
+        assert_eq!(new.first(), Some(&"და"), "new words go commonest first");
+        assert!(!steps.iter().any(|s| s.why == Why::Due));
+    }
+
+    #[test]
+    fn due_items_come_back_in_their_drill_and_are_not_new() {
+        let mut m = LearnerModel::new(Config::default());
+        for (i, item) in ["ka:rec:და", "ka:grammar:1f", "ka:grammar:2e"].iter().enumerate() {
+            m.apply(&Evidence::new(1_000 + i as Millis, "ka", *item, Skill::VocabRecognition, 1.0, 0.0, 900, Source::Recall));
+        }
+        let steps = plan(&m, "ka", 30 * DAY, &lexicon(), 1_200);
+        let due: Vec<&Step> = steps.iter().filter(|s| s.why == Why::Due).collect();
+        assert!(due.contains(&&Step { drill: Drill::Recognition, why: Why::Due, word: Some("და".into()) }));
+        // two due grammar items, one grammar block (shared with the probe, if any)
+        assert_eq!(steps.iter().filter(|s| s.drill == Drill::GrammarQuiz).count(), 1);
+        assert!(!steps.iter().any(|s| s.why == Why::New && s.word.as_deref() == Some("და")));
+        assert_eq!(Drill::for_item("ka", "ru:rec:да"), None);
+    }
+
+    #[test]
+    fn every_skill_and_item_kind_has_its_own_drill() {
+        let drills: HashSet<Drill> = Skill::iter().map(Drill::for_skill).collect();
+        assert_eq!(drills.len(), Skill::iter().count());
+        let kinds = [
+            ("ka:letter:hear:ფ", Drill::Letters),
+            ("ka:listen:კაცი", Drill::Listening),
+            ("ka:listen:en:კაცი", Drill::Listening),
+            ("ka:conj:წერა:present:1sg", Drill::Conjugation),
+            ("ka:sentence:9a", Drill::Reading),
+        ];
+        for (item, drill) in kinds {
+            assert_eq!(Drill::for_item("ka", item), Some((drill, None)), "{item}");
+        }
+    }
+
+    #[test]
+    fn due_letters_dictations_and_cells_are_scheduled() {
+        let mut m = LearnerModel::new(Config::default());
+        let items = [
+            ("ka:letter:hear:ფ", Skill::ScriptSound),
+            ("ka:listen:კაცი", Skill::Listening),
+            ("ka:conj:წერა:present:1sg", Skill::Grammar),
+        ];
+        for (i, (item, skill)) in items.iter().enumerate() {
+            m.apply(&Evidence::new(1_000 + i as Millis, "ka", *item, *skill, 1.0, 0.0, 900, Source::Recall));
+        }
+        let steps = plan(&m, "ka", 30 * DAY, &lexicon(), 1_200);
+        // the listening block may be the probe's; letters and cells only come due
+        for drill in [Drill::Letters, Drill::Listening, Drill::Conjugation] {
+            assert!(steps.iter().any(|s| s.drill == drill), "{drill:?} not scheduled");
+        }
+    }
+}
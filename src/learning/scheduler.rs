@@ -1,54 +1,261 @@
 //! Item-level memory, for *scheduling only*. It predicts when to re-ask an
-//! item; it never feeds the mastery estimate (that's evidence-only). FSRS/SM-2
-//! can slot in later — the contract is just `retrievability(now)`.
+//! item; it never feeds the mastery estimate (that's evidence-only). The
+//! contract is the `Scheduler` trait — `retrievability(now)` plus a `review`
+//! update — with two implementations picked by `Config::scheduler`:
+//!
+//! - `Hlr`: half-life regression flavour, one number per item.
+//! - `Fsrs`: FSRS-4.5-style stability + difficulty, default weights.
+//!
+//! Both read the graded `outcome` (0..=1) continuously, not as pass/fail.
 
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
+
 use super::evidence::{ItemId, Millis};
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+const MS_PER_DAY: f32 = 86_400_000.0;
+
+/// Per-item scheduling state. Field meaning is scheduler-specific: HLR reads
+/// `stability_days` as a half-life (recall 50%), FSRS as the interval at which
+/// recall falls to 90%.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Memory {
-    pub half_life_days: f32,
+    pub stability_days: f32,
+    /// FSRS item difficulty, 1..=10. HLR ignores it.
+    pub difficulty: f32,
     pub last_seen: Millis,
     pub reps: u32,
+    /// Reviews graded as failures (outcome < 0.5).
+    pub lapses: u32,
 }
 
 impl Memory {
+    /// Never-reviewed state; the scheduler initialises it on the first review.
     pub fn seed() -> Self {
         Memory {
-            half_life_days: 1.0,
+            stability_days: 1.0,
+            difficulty: 5.0,
             last_seen: 0,
             reps: 0,
+            lapses: 0,
+        }
+    }
+
+    fn elapsed_days(&self, now: Millis) -> f32 {
+        now.saturating_sub(self.last_seen) as f32 / MS_PER_DAY
+    }
+}
+
+/// A spaced-repetition model: how recall decays, and how a review updates it.
+pub trait Scheduler {
+    /// Predicted recall probability at `now` (decides what's due). A never-seen
+    /// item is 0.
+    fn retrievability(&self, m: &Memory, now: Millis) -> f32;
+
+    /// Fold one graded review (`outcome` 0..=1; `latency_ms` 0 = unknown) into `m`.
+    fn review(&self, m: &mut Memory, now: Millis, outcome: f32, latency_ms: u32);
+}
+
+/// Which `Scheduler` the model uses.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SchedulerKind {
+    #[default]
+    Hlr,
+    Fsrs,
+}
+
+impl SchedulerKind {
+    pub fn get(self) -> &'static dyn Scheduler {
+        match self {
+            SchedulerKind::Hlr => &Hlr,
+            SchedulerKind::Fsrs => &FSRS_DEFAULT,
+        }
+    }
+}
+
+// ─── HLR ───────────────────────────────────────────────────────────────────
+
+/// Half-life regression, simplified: each review scales the half-life by a
+/// factor interpolated (geometrically) from ×0.5 at outcome 0 to ×1.8 at 1.
+pub struct Hlr;
+
+impl Hlr {
+    const FAIL: f32 = 0.5;
+    const PASS: f32 = 1.8;
+}
+
+impl Scheduler for Hlr {
+    fn retrievability(&self, m: &Memory, now: Millis) -> f32 {
+        if m.last_seen == 0 {
+            return 0.0;
+        }
+        0.5f32.powf(m.elapsed_days(now) / m.stability_days.max(1e-3))
+    }
+
+    fn review(&self, m: &mut Memory, now: Millis, outcome: f32, _latency_ms: u32) {
+        let o = outcome.clamp(0.0, 1.0);
+        let factor = Hlr::FAIL * (Hlr::PASS / Hlr::FAIL).powf(o);
+        m.reps = m.reps.saturating_add(1);
+        if o < 0.5 {
+            m.lapses = m.lapses.saturating_add(1);
+        }
+        m.stability_days = (m.stability_days * factor).clamp(0.02, 365.0);
+        m.last_seen = now;
+    }
+}
+
+// ─── FSRS ──────────────────────────────────────────────────────────────────
+
+/// FSRS-style model. `w` are the 17 FSRS-4.5 weights; grades live on FSRS's
+/// 1 (again) ..= 4 (easy) scale, here continuous.
+pub struct Fsrs {
+    pub w: [f32; 17],
+}
+
+/// Published FSRS-4.5 defaults — a sane start before any per-user fitting.
+pub const FSRS_DEFAULT: Fsrs = Fsrs {
+    w: [
+        0.4872, 1.4003, 3.7145, 13.8206, 5.1618, 1.2298, 0.8975, 0.031, 1.6474, 0.1367, 1.0461,
+        2.1072, 0.0793, 0.3246, 1.587, 0.2272, 2.8755,
+    ],
+};
+
+impl Fsrs {
+    const DECAY: f32 = -0.5;
+    /// Chosen so that R(t = S) = 0.9.
+    const FACTOR: f32 = 19.0 / 81.0;
+    /// A correct answer slower than this is treated as effortful (capped at "good").
+    const SLOW_MS: u32 = 8_000;
+
+    /// Continuous grade in 1..=4. Outcomes below 0.5 are lapses (1..2), the
+    /// rest spread over hard..easy (2..4).
+    fn grade(outcome: f32, latency_ms: u32) -> f32 {
+        let o = outcome.clamp(0.0, 1.0);
+        let g = if o < 0.5 { 1.0 + 2.0 * o } else { 2.0 + 4.0 * (o - 0.5) };
+        if latency_ms >= Fsrs::SLOW_MS {
+            g.min(3.0)
+        } else {
+            g
         }
     }
 
-    /// Predicted recall probability at `now` (decides what's due).
-    pub fn retrievability(&self, now: Millis) -> f32 {
-        if self.last_seen == 0 {
+    /// Piecewise-linear in the grade between the four FSRS anchor values.
+    fn by_grade(g: f32, at: [f32; 4]) -> f32 {
+        let g = g.clamp(1.0, 4.0);
+        let i = ((g - 1.0).floor() as usize).min(2);
+        let t = g - 1.0 - i as f32;
+        at[i] + (at[i + 1] - at[i]) * t
+    }
+
+    fn init_difficulty(&self, g: f32) -> f32 {
+        (self.w[4] - (g - 3.0) * self.w[5]).clamp(1.0, 10.0)
+    }
+}
+
+impl Scheduler for Fsrs {
+    fn retrievability(&self, m: &Memory, now: Millis) -> f32 {
+        if m.last_seen == 0 {
             return 0.0;
         }
-        let days = now.saturating_sub(self.last_seen) as f32 / 86_400_000.0;
-        0.5f32.powf(days / self.half_life_days.max(1e-3))
+        let t = m.elapsed_days(now);
+        (1.0 + Fsrs::FACTOR * t / m.stability_days.max(1e-3)).powf(Fsrs::DECAY)
     }
 
-    /// Expand the half-life on success, contract on failure (HLR-flavoured).
-    pub fn review(&mut self, now: Millis, success: bool) {
-        self.reps = self.reps.saturating_add(1);
-        let factor = if success { 1.8 } else { 0.5 };
-        self.half_life_days = (self.half_life_days * factor).clamp(0.02, 365.0);
-        self.last_seen = now;
+    fn review(&self, m: &mut Memory, now: Millis, outcome: f32, latency_ms: u32) {
+        let w = &self.w;
+        let g = Fsrs::grade(outcome, latency_ms);
+        let lapse = g < 2.0;
+
+        if m.reps == 0 || m.last_seen == 0 {
+            m.stability_days = Fsrs::by_grade(g, [w[0], w[1], w[2], w[3]]);
+            m.difficulty = self.init_difficulty(g);
+        } else {
+            let r = self.retrievability(m, now);
+            let (s, d) = (m.stability_days, m.difficulty);
+            m.stability_days = if lapse {
+                w[11] * d.powf(-w[12]) * ((s + 1.0).powf(w[13]) - 1.0) * (w[14] * (1.0 - r)).exp()
+            } else {
+                let modifier = Fsrs::by_grade(g, [w[15], w[15], 1.0, w[16]]);
+                s * (1.0
+                    + w[8].exp()
+                        * (11.0 - d)
+                        * s.powf(-w[9])
+                        * ((w[10] * (1.0 - r)).exp() - 1.0)
+                        * modifier)
+            };
+            // Difficulty drifts with the grade, mean-reverting toward a "good" first answer.
+            let next = d - w[6] * (g - 3.0);
+            m.difficulty = (w[7] * self.init_difficulty(3.0) + (1.0 - w[7]) * next).clamp(1.0, 10.0);
+        }
+
+        m.stability_days = m.stability_days.clamp(0.01, 36_500.0);
+        m.reps = m.reps.saturating_add(1);
+        if lapse {
+            m.lapses = m.lapses.saturating_add(1);
+        }
+        m.last_seen = now;
     }
 }
 
 /// Items whose predicted recall has fallen below `target`, most overdue first.
-pub fn due_items(mems: &HashMap<ItemId, Memory>, now: Millis, target: f32) -> Vec<ItemId> {
+pub fn due_items(
+    mems: &HashMap<ItemId, Memory>,
+    sched: &dyn Scheduler,
+    now: Millis,
+    target: f32,
+) -> Vec<ItemId> {
     let mut due: Vec<(f32, ItemId)> = mems
         .iter()
         .filter_map(|(id, m)| {
-            let r = m.retrievability(now);
+            let r = sched.retrievability(m, now);
             (r < target).then(|| (r, id.clone()))
         })
         .collect();
     due.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
     due.into_iter().map(|(_, id)| id).collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: Millis = 86_400_000;
+
+    fn reviewed(kind: SchedulerKind, outcomes: &[f32]) -> Memory {
+        let s = kind.get();
+        let mut m = Memory::seed();
+        for (i, &o) in outcomes.iter().enumerate() {
+            s.review(&mut m, DAY * (1 + i as Millis), o, 1_500);
+        }
+        m
+    }
+
+    #[test]
+    fn better_outcomes_mean_longer_stability_for_both_schedulers() {
+        for kind in [SchedulerKind::Hlr, SchedulerKind::Fsrs] {
+            let weak = reviewed(kind, &[1.0, 0.6]).stability_days;
+            let strong = reviewed(kind, &[1.0, 0.9]).stability_days;
+            let failed = reviewed(kind, &[1.0, 0.1]).stability_days;
+            assert!(failed < weak && weak < strong, "{kind:?}: {failed} {weak} {strong}");
+            assert_eq!(reviewed(kind, &[1.0, 0.1]).lapses, 1);
+        }
+    }
+
+    #[test]
+    fn fsrs_stability_is_the_ninety_percent_interval() {
+        let m = reviewed(SchedulerKind::Fsrs, &[1.0, 1.0]);
+        let at = m.last_seen + (m.stability_days * DAY as f32) as Millis;
+        let r = FSRS_DEFAULT.retrievability(&m, at);
+        assert!((r - 0.9).abs() < 1e-3, "R(S) = {r}");
+        assert_eq!(FSRS_DEFAULT.retrievability(&Memory::seed(), at), 0.0);
+    }
+
+    #[test]
+    fn slow_correct_answers_grade_no_higher_than_good() {
+        assert_eq!(Fsrs::grade(1.0, 500), 4.0);
+        assert_eq!(Fsrs::grade(1.0, 12_000), 3.0);
+        assert_eq!(Fsrs::grade(0.0, 0), 1.0);
+    }
+}
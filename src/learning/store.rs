@@ -1,22 +1,193 @@
 //! Persistence: an append-only JSONL log of evidence. The model is re-derived
-//! by folding it. No Dioxus here — just the filesystem.
+//! by folding it — from the latest `Snapshot` forward, so startup needn't
+//! replay everything. `compact` moves events a snapshot already covers out to
+//! `archive/`, so the live log stays short; a compacted log opens with a
+//! header line giving how many records were archived before it, so positions
+//! in the whole history stay fixed. Also the user's fitted `Config`.
+//! No Dioxus here — just the filesystem.
 
 use std::fs;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use super::evidence::Evidence;
+use serde::{Deserialize, Serialize};
 
-/// Load every evidence line; malformed lines are skipped, a missing file is empty.
+use super::evidence::{Evidence, Millis};
+use super::model::{Config, LearnerModel};
+use crate::paths::write_atomic;
+
+/// File and folder names under the data root.
+pub const LOG_FILE: &str = "evidence.jsonl";
+pub const CONFIG_FILE: &str = "learner_config.json";
+pub const SNAPSHOT_DIR: &str = "snapshots";
+pub const ARCHIVE_DIR: &str = "archive";
+
+/// Bump when `LearnerModel`'s serialized shape changes; older snapshots are
+/// then ignored and the log refolded.
+pub const SNAPSHOT_VERSION: u32 = 3;
+
+/// Snapshots kept on disk; older ones are pruned after each save.
+const KEEP_SNAPSHOTS: usize = 3;
+
+/// A folded model and where in the live log it got to.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// `SNAPSHOT_VERSION` when written (0 = a pre-versioning file).
+    #[serde(default)]
+    pub version: u32,
+    pub taken_at: Millis,
+    /// Records of the whole history folded in, archived ones included — so it
+    /// still points at the same record after `compact` shortens the log.
+    pub offset: usize,
+    pub model: LearnerModel,
+}
+
+impl Snapshot {
+    /// A snapshot of `model` folded from a live log of `log`, which starts
+    /// `base` records into the history.
+    pub fn new(taken_at: Millis, base: usize, log: &[Evidence], model: LearnerModel) -> Self {
+        Snapshot {
+            version: SNAPSHOT_VERSION,
+            taken_at,
+            offset: base + log.len(),
+            model,
+        }
+    }
+
+    /// How many records of a live log (`log_len` long, starting `base` into
+    /// the history) this snapshot has already folded, or `None` if it lies
+    /// outside that log (or is from another version).
+    fn position(&self, base: usize, log_len: usize) -> Option<usize> {
+        if self.version != SNAPSHOT_VERSION {
+            return None;
+        }
+        self.offset.checked_sub(base).filter(|&n| n <= log_len)
+    }
+
+    /// Rebuild the model for `log` under `cfg`: resume from this snapshot if it
+    /// was folded with the same config from a prefix of the history up to
+    /// `log`, else refold the whole history — the first `base` `archived`
+    /// events, then `log`.
+    pub fn resume(
+        snapshot: Option<Snapshot>,
+        cfg: Config,
+        base: usize,
+        log: &[Evidence],
+        archived: impl FnOnce() -> Vec<Evidence>,
+    ) -> LearnerModel {
+        if let Some(s) = snapshot.filter(|s| s.model.config() == cfg) {
+            if let Some(pos) = s.position(base, log.len()) {
+                let mut m = s.model;
+                for e in &log[pos..] {
+                    m.apply(e);
+                }
+                return m;
+            }
+        }
+        let mut all = archived();
+        // never more than the records before the log, whatever was passed
+        all.truncate(base);
+        all.extend_from_slice(log);
+        LearnerModel::from_log_with(cfg, &all)
+    }
+}
+
+/// First line of a compacted log.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LogHeader {
+    /// Records archived before this log's first one.
+    base: usize,
+}
+
+/// A parsed log, plus the (1-based) line numbers that didn't parse.
+#[derive(Clone, Debug, Default)]
+pub struct LogLoad {
+    /// Records of the history before this log (its header; 0 if none).
+    pub base: usize,
+    pub evidence: Vec<Evidence>,
+    pub corrupt: Vec<usize>,
+}
+
+/// Load every evidence line, noting malformed ones; a missing file is empty.
+pub fn read_log(path: &Path) -> LogLoad {
+    let mut out = LogLoad::default();
+    let Ok(s) = fs::read_to_string(path) else {
+        return out;
+    };
+    for (i, l) in s.lines().enumerate() {
+        if l.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<Evidence>(l) {
+            Ok(e) => out.evidence.push(e),
+            Err(_) => match serde_json::from_str::<LogHeader>(l) {
+                Ok(h) if i == 0 => out.base = h.base,
+                _ => out.corrupt.push(i + 1),
+            },
+        }
+    }
+    if !out.corrupt.is_empty() {
+        eprintln!(
+            "{}: skipped {} corrupt line(s): {:?}",
+            path.display(),
+            out.corrupt.len(),
+            out.corrupt
+        );
+    }
+    out
+}
+
+/// Load every evidence line; malformed lines are skipped (see `read_log`).
 pub fn load_log(path: &Path) -> Vec<Evidence> {
-    match fs::read_to_string(path) {
-        Ok(s) => s
-            .lines()
-            .filter(|l| !l.trim().is_empty())
-            .filter_map(|l| serde_json::from_str::<Evidence>(l).ok())
-            .collect(),
-        Err(_) => Vec::new(),
+    read_log(path).evidence
+}
+
+/// `dir/evidence-<start>-<end>.jsonl`: the history's records `start..end`.
+fn archive_path(dir: &Path, start: usize, end: usize) -> PathBuf {
+    dir.join(format!("evidence-{start:010}-{end:010}.jsonl"))
+}
+
+/// Every archive in `dir` with the record range its name gives, by start.
+fn archives(dir: &Path) -> Vec<(usize, usize, PathBuf)> {
+    let mut out: Vec<(usize, usize, PathBuf)> = sorted_files(dir, "evidence-", ".jsonl")
+        .into_iter()
+        .filter_map(|p| {
+            let name = p.file_stem()?.to_str()?.strip_prefix("evidence-")?;
+            let (start, end) = name.split_once('-')?;
+            Some((start.parse().ok()?, end.parse().ok()?, p))
+        })
+        .collect();
+    out.sort_by_key(|&(start, end, _)| (start, end));
+    out
+}
+
+/// The first `base` records of the history, from the archives in `dir`.
+/// Archives starting at or past `base` were written by a compaction that never
+/// got to shorten the log, so they're skipped; overlapping ones are read once.
+pub fn load_archive(dir: &Path, base: usize) -> Vec<Evidence> {
+    let mut out = Vec::new();
+    let mut pos = 0;
+    for (start, end, p) in archives(dir) {
+        if start >= base || end <= pos {
+            continue;
+        }
+        if start > pos {
+            eprintln!("{}: records {pos}..{start} are missing", dir.display());
+        }
+        let upto = end.min(base) - start;
+        out.extend(load_log(&p).into_iter().take(upto).skip(pos.saturating_sub(start)));
+        pos = end.min(base);
     }
+    out
+}
+
+/// The archived events followed by the live log: the user's whole history.
+pub fn load_history(log: &Path, archive_dir: &Path) -> Vec<Evidence> {
+    let live = read_log(log);
+    let mut all = load_archive(archive_dir, live.base);
+    all.extend(live.evidence);
+    all
 }
 
 /// Append one evidence record as a JSON line, creating parent dirs as needed.
@@ -24,8 +195,7 @@ pub fn append(path: &Path, e: &Evidence) -> std::io::Result<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
-    let line = serde_json::to_string(e)
-        .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))?;
+    let line = serde_json::to_string(e).map_err(std::io::Error::other)?;
     let mut f = fs::OpenOptions::new()
         .create(true)
         .append(true)
@@ -33,3 +203,244 @@ pub fn append(path: &Path, e: &Evidence) -> std::io::Result<()> {
     writeln!(f, "{line}")?;
     Ok(())
 }
+
+/// The persisted per-user config, if there is a readable one.
+pub fn load_config(path: &Path) -> Option<Config> {
+    let s = fs::read_to_string(path).ok()?;
+    match serde_json::from_str(&s) {
+        Ok(cfg) => Some(cfg),
+        Err(e) => {
+            eprintln!("ignoring unreadable {}: {e}", path.display());
+            None
+        }
+    }
+}
+
+/// Write a config (pretty JSON), creating parent dirs as needed.
+pub fn save_config(path: &Path, cfg: &Config) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(cfg).map_err(std::io::Error::other)?;
+    fs::write(path, json)
+}
+
+/// Files in `dir` named `{prefix}…{suffix}`, sorted by name. Names carry a
+/// zero-padded timestamp or record number, so that's oldest first.
+fn sorted_files(dir: &Path, prefix: &str, suffix: &str) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|rd| {
+            rd.flatten()
+                .map(|e| e.path())
+                .filter(|p| {
+                    p.file_name()
+                        .and_then(|n| n.to_str())
+                        .is_some_and(|n| n.starts_with(prefix) && n.ends_with(suffix))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+/// The newest readable snapshot of the current version in `dir`. A corrupt
+/// or outdated newest file falls back to the one before it.
+pub fn load_latest_snapshot(dir: &Path) -> Option<Snapshot> {
+    sorted_files(dir, "snapshot-", ".json").iter().rev().find_map(|p| {
+        let s = fs::read_to_string(p).ok()?;
+        match serde_json::from_str::<Snapshot>(&s) {
+            Ok(snap) if snap.version == SNAPSHOT_VERSION => Some(snap),
+            Ok(snap) => {
+                eprintln!("ignoring {}: snapshot version {}", p.display(), snap.version);
+                None
+            }
+            Err(e) => {
+                eprintln!("ignoring unreadable {}: {e}", p.display());
+                None
+            }
+        }
+    })
+}
+
+/// Write `snapshot-<taken_at>.json` into `dir` and prune all but the newest
+/// `KEEP_SNAPSHOTS`.
+pub fn save_snapshot(dir: &Path, snap: &Snapshot) -> std::io::Result<()> {
+    let json = serde_json::to_string(snap).map_err(std::io::Error::other)?;
+    write_atomic(&dir.join(format!("snapshot-{:013}.json", snap.taken_at)), &json)?;
+    let files = sorted_files(dir, "snapshot-", ".json");
+    for old in &files[..files.len().saturating_sub(KEEP_SNAPSHOTS)] {
+        let _ = fs::remove_file(old);
+    }
+    Ok(())
+}
+
+/// Move every log record the latest snapshot has folded into
+/// `archive_dir/evidence-<base>-<end>.jsonl`, named for its range in the
+/// whole history, leaving only the tail in `log` under a header with the new
+/// base. The snapshot's offset is into the whole history, so it needn't move.
+/// Corrupt lines go along with their neighbours. Returns how many records
+/// were archived.
+pub fn compact(log: &Path, snapshot_dir: &Path, archive_dir: &Path) -> std::io::Result<usize> {
+    let Some(snap) = load_latest_snapshot(snapshot_dir) else {
+        return Ok(0);
+    };
+    let live = read_log(log);
+    let Some(folded) = snap
+        .position(live.base, live.evidence.len())
+        .filter(|&n| n > 0)
+    else {
+        return Ok(0);
+    };
+    let text = fs::read_to_string(log)?;
+    let lines: Vec<&str> = text
+        .lines()
+        .filter(|l| serde_json::from_str::<LogHeader>(l).is_err())
+        .collect();
+
+    // Cut right after the line holding the `folded`-th record.
+    let mut seen = 0;
+    let cut = lines
+        .iter()
+        .position(|l| {
+            seen += serde_json::from_str::<Evidence>(l).is_ok() as usize;
+            seen == folded
+        })
+        .map_or(0, |i| i + 1);
+    let join = |ls: &[&str]| ls.iter().map(|l| format!("{l}\n")).collect::<String>();
+    let header = serde_json::to_string(&LogHeader {
+        base: live.base + folded,
+    })
+    .map_err(std::io::Error::other)?;
+
+    // Archive first, then swap in the shortened log with its new base in one
+    // rename: a crash in between leaves an archive starting at the log's
+    // base, which `load_archive` skips and this replaces.
+    for (start, _, stale) in archives(archive_dir) {
+        if start >= live.base {
+            fs::remove_file(stale)?;
+        }
+    }
+    let end = live.base + folded;
+    write_atomic(&archive_path(archive_dir, live.base, end), &join(&lines[..cut]))?;
+    write_atomic(log, &format!("{header}\n{}", join(&lines[cut..])))?;
+    Ok(folded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::learning::evidence::Source;
+    use crate::learning::skill::Skill;
+
+    fn ev(i: u64) -> Evidence {
+        Evidence::new(1_000 + i * 60_000, "ka", format!("ka:word:{i}"), Skill::ScriptSound, 1.0, 0.0, 900, Source::Wpm)
+    }
+
+    #[test]
+    fn corrupt_lines_are_reported_not_fatal() {
+        let dir = crate::paths::TestDir::new("store-corrupt");
+        let log = dir.join(LOG_FILE);
+        append(&log, &ev(0)).unwrap();
+        fs::OpenOptions::new().append(true).open(&log).unwrap().write_all(b"{\"at\": oops\n\n").unwrap();
+        append(&log, &ev(1)).unwrap();
+
+        let loaded = read_log(&log);
+        assert_eq!(loaded.evidence, vec![ev(0), ev(1)]);
+        assert_eq!(loaded.corrupt, vec![2]);
+    }
+
+    #[test]
+    fn compaction_keeps_the_model_and_the_history() {
+        let dir = crate::paths::TestDir::new("store-compact");
+        let (log, snaps, archive) = (dir.join(LOG_FILE), dir.join(SNAPSHOT_DIR), dir.join(ARCHIVE_DIR));
+        let cfg = Config::default();
+        let all: Vec<Evidence> = (0..10).map(ev).collect();
+        for e in &all[..6] {
+            append(&log, e).unwrap();
+        }
+        let head = load_log(&log);
+        save_snapshot(&snaps, &Snapshot::new(5, 0, &head, LearnerModel::from_log_with(cfg, &head))).unwrap();
+        for e in &all[6..] {
+            append(&log, e).unwrap();
+        }
+
+        assert_eq!(compact(&log, &snaps, &archive).unwrap(), 6);
+        let live = read_log(&log);
+        assert_eq!((live.base, &live.evidence[..]), (6, &all[6..]));
+        assert!(live.corrupt.is_empty(), "the header isn't a corrupt line");
+        assert_eq!(load_history(&log, &archive), all);
+
+        let resumed = Snapshot::resume(load_latest_snapshot(&snaps), cfg, live.base, &live.evidence, Vec::new);
+        assert_eq!(resumed, LearnerModel::from_log_with(cfg, &all));
+        // Nothing new to archive.
+        assert_eq!(compact(&log, &snaps, &archive).unwrap(), 0);
+    }
+
+    #[test]
+    fn repeated_events_dont_confuse_the_snapshot_position() {
+        let dir = crate::paths::TestDir::new("store-repeats");
+        let (log, snaps, archive) = (dir.join(LOG_FILE), dir.join(SNAPSHOT_DIR), dir.join(ARCHIVE_DIR));
+        let cfg = Config::default();
+        let all = vec![ev(0); 6];
+        for e in &all {
+            append(&log, e).unwrap();
+        }
+        let head = &all[..2];
+        save_snapshot(&snaps, &Snapshot::new(5, 0, head, LearnerModel::from_log_with(cfg, head))).unwrap();
+
+        assert_eq!(compact(&log, &snaps, &archive).unwrap(), 2);
+        let live = read_log(&log);
+        assert_eq!((live.base, live.evidence.len()), (2, 4));
+        let resumed = Snapshot::resume(load_latest_snapshot(&snaps), cfg, live.base, &live.evidence, Vec::new);
+        assert_eq!(resumed, LearnerModel::from_log_with(cfg, &all));
+    }
+
+    #[test]
+    fn an_interrupted_compaction_is_replaced_by_the_next() {
+        let dir = crate::paths::TestDir::new("store-interrupted");
+        let (log, snaps, archive) = (dir.join(LOG_FILE), dir.join(SNAPSHOT_DIR), dir.join(ARCHIVE_DIR));
+        let cfg = Config::default();
+        let all: Vec<Evidence> = (0..20).map(ev).collect();
+        for e in &all[..10] {
+            append(&log, e).unwrap();
+        }
+        let head = &all[..8];
+        save_snapshot(&snaps, &Snapshot::new(5, 0, head, LearnerModel::from_log_with(cfg, head))).unwrap();
+
+        // Archive r0..r7, then crash before the log is shortened.
+        let unshortened = fs::read_to_string(&log).unwrap();
+        assert_eq!(compact(&log, &snaps, &archive).unwrap(), 8);
+        fs::write(&log, unshortened).unwrap();
+        assert_eq!(load_history(&log, &archive), all[..10]);
+
+        for e in &all[10..] {
+            append(&log, e).unwrap();
+        }
+        let head = &all[..18];
+        save_snapshot(&snaps, &Snapshot::new(6, 0, head, LearnerModel::from_log_with(cfg, head))).unwrap();
+        assert_eq!(compact(&log, &snaps, &archive).unwrap(), 18);
+        assert_eq!(load_history(&log, &archive), all);
+
+        let live = read_log(&log);
+        assert_eq!((live.base, live.evidence.len()), (18, 2));
+        let refolded = Snapshot::resume(None, cfg, live.base, &live.evidence, || load_archive(&archive, live.base));
+        assert_eq!(refolded, LearnerModel::from_log_with(cfg, &all));
+    }
+
+    #[test]
+    fn a_snapshot_survives_an_interrupted_compaction_but_not_a_version_bump() {
+        let cfg = Config::default();
+        let all: Vec<Evidence> = (0..8).map(ev).collect();
+        let full = LearnerModel::from_log_with(cfg, &all);
+        let mut snap = Snapshot::new(1, 0, &all[..5], LearnerModel::from_log_with(cfg, &all[..5]));
+        assert_eq!(Snapshot::resume(Some(snap.clone()), cfg, 0, &all, Vec::new), full);
+        // Archived, but the log was never shortened: the archive is trimmed.
+        let archived = || all[..5].to_vec();
+        assert_eq!(Snapshot::resume(None, cfg, 0, &all, archived), full);
+
+        snap.version = 0;
+        let refolded = Snapshot::resume(Some(snap), cfg, 5, &all[5..], archived);
+        assert_eq!(refolded, full);
+    }
+}
@@ -2,16 +2,22 @@
 //#![windows_subsystem = "windows"]
 mod components;
 use views::{
-    AlphabetPage, DashboardPage, DictionaryPage, GrammarPage, Home, Navbar, ReadingPage, TypingPage,
+    AlphabetPage, ConjugatePage, DashboardPage, DictionaryPage, GrammarPage, Home, Navbar,
+    ReadingPage, SessionPage, TypingPage,
 };
 pub mod assets;
 pub mod audio;
 mod calibration;
 mod grammar;
+mod ingest;
+mod keystrokes;
+pub mod langpack;
+mod layouts;
 pub mod learner;
 mod learning;
 pub mod models;
 pub mod paths;
+mod search;
 mod settings;
 mod views;
 
@@ -35,6 +41,9 @@ enum Route {
 		#[route("/")]
 		Home {},
 
+		#[route("/session")]
+		SessionPage {},
+
 		#[route("/dictionary")]
         DictionaryPage {},
 
@@ -46,9 +55,8 @@ enum Route {
 		TypingPage {},
 		#[route("/grammar")]
 		 GrammarPage {},
-		// Future tabs – just uncomment when you need them
-		// #[route("/conjugate")]
-		// Conjugate {},
+		#[route("/conjugate")]
+		ConjugatePage {},
 }
 
 // ---------------------------------------------------------------------
@@ -70,7 +78,11 @@ fn App() -> Element {
     let lang = use_signal(|| settings.peek().default_language.clone());
     use_context_provider(|| lang);
 
+    crate::langpack::provide_registry();
+    crate::langpack::provide_lexicon_revision();
     crate::learner::provide_learner();
+    crate::keystrokes::provide_keystrokes();
+    crate::calibration::provide_calibration();
 
     rsx! {
         // In addition to element and text (which we will see later), rsx can contain other components. In this case,
@@ -0,0 +1,295 @@
+// src/models/inflect/georgian.rs
+//
+// Georgian noun / adjective declension from the citation (nominative) form.
+//
+// - Consonant stems drop the nominative `-ი` (`კაცი` → `კაც-`) and take the
+//   full endings. Stems the pack marks `syncope` lose their last vowel before
+//   a vowel-initial ending (`მეგობარი` → `მეგობრის`, `მეგობრები`); most
+//   look-alikes keep it (`ქართველი` → `ქართველის`), so it's never guessed.
+// - `-ა` / `-ე` stems truncate the vowel in the genitive and instrumental
+//   (`დედა` → `დედის`); `-ა` stems also lose it before the plural `-ებ-`.
+// - `-ო` / `-უ` stems never truncate (`რადიო` → `რადიოს`, `რადიოთი`).
+//
+// The plural is always the modern `-ებ-` plural. Adjectives also get the
+// attributive ("with noun") column. Irregulars are left to hand-authoring —
+// any authored cell wins over these.
+
+use crate::models::lexicon::{case_rows, CaseForms, Declension, LexEntry};
+
+/// Nominative … vocative endings after a consonant (the `-ებ-` plural too).
+const CONSONANT_ENDINGS: [&str; 7] = ["ი", "მა", "ს", "ის", "ით", "ად", "ო"];
+/// Which of those start with a vowel (and so trigger syncope).
+const VOWEL_INITIAL: [bool; 7] = [true, false, false, true, true, true, true];
+/// Attributive endings of a consonant-stem adjective (`ლამაზ-ი კაცი`,
+/// `ლამაზ კაცს`, …).
+const ATTRIBUTIVE_ENDINGS: [&str; 7] = ["ი", "მა", "", "ი", "ი", "", "ო"];
+const VOWELS: [char; 5] = ['ა', 'ე', 'ი', 'ო', 'უ'];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Stem {
+    Consonant,
+    /// `-ა` / `-ე`: truncates in the genitive and instrumental.
+    Truncating(char),
+    /// `-ო` / `-უ`.
+    Full,
+}
+
+fn is_georgian_word(w: &str) -> bool {
+    !w.is_empty() && w.chars().all(|c| ('\u{10D0}'..='\u{10FF}').contains(&c))
+}
+
+/// A `syncope` stem with its last vowel dropped (`მეგობარ` → `მეგობრ`), if
+/// that vowel sits right before the final consonant.
+fn syncopated(stem: &str, syncope: bool) -> Option<String> {
+    let mut chars: Vec<char> = stem.chars().collect();
+    let n = chars.len();
+    if !syncope || n < 3 || !VOWELS.contains(&chars[n - 2]) || VOWELS.contains(&chars[n - 1]) {
+        return None;
+    }
+    chars.remove(n - 2);
+    Some(chars.into_iter().collect())
+}
+
+fn classify(word: &str) -> (Stem, &str) {
+    match word.chars().last() {
+        Some('ი') => (Stem::Consonant, word.strip_suffix('ი').unwrap_or(word)),
+        Some(v @ ('ა' | 'ე')) => (Stem::Truncating(v), word),
+        Some('ო' | 'უ') => (Stem::Full, word),
+        _ => (Stem::Consonant, word),
+    }
+}
+
+/// Singular forms, nominative … vocative.
+fn singular(stem: Stem, base: &str, syncope: bool) -> [String; 7] {
+    match stem {
+        Stem::Consonant => {
+            let short = syncopated(base, syncope);
+            std::array::from_fn(|i| {
+                let s = match (&short, VOWEL_INITIAL[i]) {
+                    (Some(short), true) if i > 0 => short.as_str(),
+                    _ => base,
+                };
+                format!("{s}{}", CONSONANT_ENDINGS[i])
+            })
+        }
+        Stem::Truncating(v) => {
+            let cut = base.strip_suffix(v).unwrap_or(base);
+            [
+                base.to_string(),
+                format!("{base}მ"),
+                format!("{base}ს"),
+                format!("{cut}ის"),
+                format!("{cut}ით"),
+                format!("{base}დ"),
+                format!("{base}ვ"),
+            ]
+        }
+        Stem::Full => [
+            base.to_string(),
+            format!("{base}მ"),
+            format!("{base}ს"),
+            format!("{base}ს"),
+            format!("{base}თი"),
+            format!("{base}დ"),
+            base.to_string(),
+        ],
+    }
+}
+
+/// Plural forms: the `-ებ-` stem plus consonant endings.
+fn plural(stem: Stem, base: &str, syncope: bool) -> [String; 7] {
+    let pl = match stem {
+        Stem::Consonant => format!("{}ებ", syncopated(base, syncope).as_deref().unwrap_or(base)),
+        Stem::Truncating('ა') => format!("{}ებ", base.strip_suffix('ა').unwrap_or(base)),
+        _ => format!("{base}ებ"),
+    };
+    std::array::from_fn(|i| format!("{pl}{}", CONSONANT_ENDINGS[i]))
+}
+
+/// Attributive forms: consonant stems take the reduced endings, vowel stems
+/// don't change.
+fn attributive(stem: Stem, base: &str) -> [String; 7] {
+    match stem {
+        Stem::Consonant => std::array::from_fn(|i| format!("{base}{}", ATTRIBUTIVE_ENDINGS[i])),
+        _ => std::array::from_fn(|_| base.to_string()),
+    }
+}
+
+/// The generated paradigm for a noun or adjective, or `None` for other parts
+/// of speech and multi-word / non-Georgian heads.
+pub fn decline(e: &LexEntry) -> Option<Declension> {
+    let pos = e.pos.as_deref()?;
+    let adjective = pos.eq_ignore_ascii_case("adjective");
+    if !adjective && !pos.eq_ignore_ascii_case("noun") {
+        return None;
+    }
+    let word = e.head();
+    if !is_georgian_word(word) {
+        return None;
+    }
+
+    let (stem, base) = classify(word);
+    let sg = singular(stem, base, e.syncope);
+    let pl = plural(stem, base, e.syncope);
+    let attr = adjective.then(|| attributive(stem, base));
+    let cases = case_rows("georgian").iter().enumerate().map(|(i, row)| {
+        let forms = CaseForms {
+            singular: sg[i].clone(),
+            plural: pl[i].clone(),
+            with_noun: attr.as_ref().map(|a| a[i].clone()).unwrap_or_default(),
+            ..Default::default()
+        };
+        (row.key.to_string(), forms)
+    });
+    Some(Declension {
+        cases: cases.collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at<'a>(d: &'a Declension, case: &str) -> &'a CaseForms {
+        d.forms(case).unwrap()
+    }
+
+    fn noun(word: &str) -> Declension {
+        let e = LexEntry {
+            word: word.into(),
+            pos: Some("noun".into()),
+            ..Default::default()
+        };
+        decline(&e).unwrap()
+    }
+
+    fn syncopating(word: &str) -> Declension {
+        let e = LexEntry {
+            word: word.into(),
+            pos: Some("noun".into()),
+            syncope: true,
+            ..Default::default()
+        };
+        decline(&e).unwrap()
+    }
+
+    fn col(d: &Declension, plural: bool) -> Vec<String> {
+        case_rows("georgian")
+            .iter()
+            .map(|row| {
+                let f = at(d, &row.key);
+                if plural {
+                    f.plural.clone()
+                } else {
+                    f.singular.clone()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn consonant_stem_matches_the_authored_table() {
+        let d = noun("კაცი");
+        assert_eq!(
+            col(&d, false),
+            ["კაცი", "კაცმა", "კაცს", "კაცის", "კაცით", "კაცად", "კაცო"]
+        );
+        assert_eq!(
+            col(&d, true),
+            [
+                "კაცები",
+                "კაცებმა",
+                "კაცებს",
+                "კაცების",
+                "კაცებით",
+                "კაცებად",
+                "კაცებო"
+            ]
+        );
+    }
+
+    #[test]
+    fn syncope_only_before_vowel_endings() {
+        let d = syncopating("მეგობარი");
+        assert_eq!(
+            col(&d, false),
+            [
+                "მეგობარი",
+                "მეგობარმა",
+                "მეგობარს",
+                "მეგობრის",
+                "მეგობრით",
+                "მეგობრად",
+                "მეგობრო"
+            ]
+        );
+        assert_eq!(at(&d, "nominative").plural, "მეგობრები");
+        assert_eq!(at(&syncopating("წყალი"), "genitive").singular, "წყლის");
+    }
+
+    #[test]
+    fn unmarked_stems_keep_their_vowel() {
+        let d = noun("ქართველი");
+        assert_eq!(at(&d, "genitive").singular, "ქართველის");
+        assert_eq!(at(&d, "nominative").plural, "ქართველები");
+        assert_eq!(at(&noun("ხელი"), "genitive").singular, "ხელის");
+    }
+
+    #[test]
+    fn vowel_stems_truncate_or_not() {
+        let d = noun("დედა");
+        assert_eq!(
+            col(&d, false),
+            ["დედა", "დედამ", "დედას", "დედის", "დედით", "დედად", "დედავ"]
+        );
+        assert_eq!(at(&d, "nominative").plural, "დედები");
+        assert_eq!(at(&noun("მდინარე"), "nominative").plural, "მდინარეები");
+        let r = noun("რადიო");
+        assert_eq!(
+            (
+                at(&r, "genitive").singular.as_str(),
+                at(&r, "instrumental").singular.as_str()
+            ),
+            ("რადიოს", "რადიოთი")
+        );
+    }
+
+    #[test]
+    fn only_adjectives_get_attributive_forms() {
+        let adj = LexEntry {
+            word: "ლამაზი".into(),
+            pos: Some("adjective".into()),
+            ..Default::default()
+        };
+        let d = decline(&adj).unwrap();
+        assert_eq!(at(&d, "dative").with_noun, "ლამაზ");
+        assert_eq!(at(&d, "genitive").with_noun, "ლამაზი");
+        assert!(at(&noun("კაცი"), "dative").with_noun.is_empty());
+        let verb = LexEntry {
+            word: "კეთება".into(),
+            pos: Some("verb".into()),
+            ..Default::default()
+        };
+        assert!(decline(&verb).is_none());
+    }
+
+    #[test]
+    fn authored_cells_win_and_the_entry_is_marked() {
+        use crate::models::lexicon::Lexicon;
+        let mut authored = LexEntry {
+            word: "კაცი".into(),
+            pos: Some("noun".into()),
+            ..Default::default()
+        };
+        let mut d = Declension::default();
+        d.forms_mut("vocative").singular = "კაცო!".into();
+        authored.declension = Some(d);
+        let mut lex = Lexicon::from_entries(vec![authored]);
+        lex.fill_declensions("georgian");
+        let e = &lex.all()[0];
+        assert!(e.declension_generated);
+        let d = e.declension.as_ref().unwrap();
+        assert_eq!(at(d, "vocative").singular, "კაცო!");
+        assert_eq!(at(d, "genitive").singular, "კაცის");
+    }
+}
@@ -0,0 +1,20 @@
+// src/models/inflect/mod.rs
+//
+// Rule-based inflection. Generates the paradigms hand-authoring leaves empty;
+// `Lexicon::fill_declensions` merges them under whatever cells were written by
+// hand. One submodule per language, picked by pack code.
+
+pub mod georgian;
+pub mod russian;
+
+use super::lexicon::{Declension, LexEntry};
+
+/// The generated declension for `e`, if `lang` has a generator and `e` is
+/// something it declines.
+pub fn declension(lang: &str, e: &LexEntry) -> Option<Declension> {
+    match lang {
+        "georgian" => georgian::decline(e),
+        "russian" => russian::decline(e),
+        _ => None,
+    }
+}
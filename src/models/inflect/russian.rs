@@ -0,0 +1,544 @@
+// src/models/inflect/russian.rs
+//
+// Russian noun / adjective declension from the citation form.
+//
+// - Nouns pick a paradigm by ending: masculine hard (`стол`) / `-й` / `-ь`,
+//   feminine `-а` / `-я` / `-ия` / `-ь`, neuter `-о` / `-е` / `-ие` / `-мя`.
+//   `-ь` is feminine unless the entry says `"gender": "masculine"`.
+// - Spelling rules: `ы` → `и` after к г х ж ш ч щ, and an unstressed `о` → `е`
+//   after ж ш ч щ ц. Stress comes from the head word's acute mark when it has
+//   one (`коне́ц` → `концо́м`), and is otherwise taken to be on the stem.
+// - Fleeting vowels: `-ец` loses its `е` (`отец` → `отца`), and a bare
+//   genitive plural breaks up a final cluster (`девушка` → `девушек`,
+//   `окно` → `окон`).
+// - Animate nouns (`LexEntry::animate`) take the genitive in the accusative.
+//
+// Adjectives fill masculine / feminine / neuter / plural; their accusative is
+// the inanimate one. Stress marks are dropped from every generated form so the
+// table matches ordinary text. Irregulars (`человек` → `люди`, `мать`) and the
+// stressed `-а` plurals (`дом` → `дома`) are for hand-authoring.
+
+use crate::models::lexicon::{case_rows, CaseForms, Declension, Gender, LexEntry};
+
+const STRESS: char = '\u{301}';
+const VELAR: [char; 3] = ['к', 'г', 'х'];
+const HUSHING: [char; 4] = ['ж', 'ш', 'ч', 'щ'];
+const VOWELS: [char; 10] = ['а', 'е', 'ё', 'и', 'о', 'у', 'ы', 'э', 'ю', 'я'];
+
+/// Six forms in `case_rows("russian")` order: nom, gen, dat, acc, ins, prep.
+type Row = [String; 6];
+
+fn is_russian_word(w: &str) -> bool {
+    !w.is_empty()
+        && w.chars()
+            .all(|c| c == STRESS || ('\u{0400}'..='\u{04FF}').contains(&c))
+}
+
+fn last(s: &str) -> Option<char> {
+    s.chars().last()
+}
+
+fn drop_last(s: &str, n: usize) -> String {
+    let len = s.chars().count();
+    s.chars().take(len.saturating_sub(n)).collect()
+}
+
+/// `ы`, or `и` where the spelling rule forbids `ы`.
+fn y(stem: &str) -> &'static str {
+    match last(stem) {
+        Some(c) if VELAR.contains(&c) || HUSHING.contains(&c) => "и",
+        _ => "ы",
+    }
+}
+
+/// `о`, or `е` where an unstressed `о` can't follow.
+fn o(stem: &str, stressed: bool) -> &'static str {
+    match last(stem) {
+        Some(c) if !stressed && (HUSHING.contains(&c) || c == 'ц') => "е",
+        _ => "о",
+    }
+}
+
+/// True when the acute mark sits on the word's last vowel.
+fn end_stressed(word: &str) -> bool {
+    let chars: Vec<char> = word.chars().collect();
+    chars
+        .iter()
+        .rposition(|c| VOWELS.contains(c))
+        .is_some_and(|i| chars.get(i + 1) == Some(&STRESS))
+}
+
+/// The bare genitive plural of a feminine / neuter stem, with a fleeting vowel
+/// where the stem ends in an unpronounceable cluster.
+fn bare(stem: &str) -> String {
+    let chars: Vec<char> = stem.chars().collect();
+    let n = chars.len();
+    if n < 3 || VOWELS.contains(&chars[n - 1]) || VOWELS.contains(&chars[n - 2]) {
+        return stem.to_string();
+    }
+    let (c1, c2) = (chars[n - 2], chars[n - 1]);
+    let fill = match c2 {
+        'к' | 'ц' if c1 == 'ь' || c1 == 'й' || HUSHING.contains(&c1) => 'е',
+        'к' => 'о',
+        'ц' => 'е',
+        'н' | 'л' | 'м' if c1 == 'ь' => 'е',
+        'н' | 'л' | 'м' if VELAR.contains(&c1) => 'о',
+        'н' | 'л' | 'м' if !"йрлмн".contains(c1) => 'е',
+        _ => return stem.to_string(),
+    };
+    // a soft sign or й becomes the vowel itself: `письмо` → `писем`
+    let keep = if c1 == 'ь' || c1 == 'й' {
+        n - 2
+    } else {
+        n - 1
+    };
+    let mut out: String = chars[..keep].iter().collect();
+    out.push(fill);
+    out.push(c2);
+    out
+}
+
+fn row(nom: &str, stem: &str, endings: [&str; 5], acc: Option<&str>) -> Row {
+    let [g, d, a, i, p] = endings;
+    let acc = acc.map_or_else(|| format!("{stem}{a}"), str::to_string);
+    [
+        nom.to_string(),
+        format!("{stem}{g}"),
+        format!("{stem}{d}"),
+        acc,
+        format!("{stem}{i}"),
+        format!("{stem}{p}"),
+    ]
+}
+
+/// Singular and plural of a noun, or `None` if no paradigm fits its ending.
+fn noun(word: &str, gender: Option<Gender>, animate: bool, stressed: bool) -> Option<(Row, Row)> {
+    let end = last(word)?;
+    if ["ый", "ая", "яя", "ое", "ее"]
+        .iter()
+        .any(|a| word.ends_with(a))
+    {
+        // substantivised adjective (`молодая`, `будущее`)
+        return None;
+    }
+
+    // `masculine`: the singular accusative follows animacy too
+    let (sg, pl, masculine) = match end {
+        'я' if word.ends_with("мя") && gender != Some(Gender::Feminine) => {
+            let s = drop_last(word, 1);
+            let sg = row(word, &s, ["ени", "ени", "я", "енем", "ени"], Some(word));
+            let pl = row(
+                &format!("{s}ена"),
+                &s,
+                ["ён", "енам", "ена", "енами", "енах"],
+                None,
+            );
+            (sg, pl, false)
+        }
+        'а' => {
+            let s = drop_last(word, 1);
+            let (yy, oo) = (y(&s), o(&s, stressed));
+            let sg = row(word, &s, [yy, "е", "у", &format!("{oo}й"), "е"], None);
+            let gen_pl = bare(&s);
+            let pl = row(&format!("{s}{yy}"), &s, ["", "ам", yy, "ами", "ах"], None);
+            (sg, with_gen(pl, gen_pl), false)
+        }
+        'я' => {
+            let s = drop_last(word, 1);
+            let i_stem = last(&s) == Some('и');
+            let dp = if i_stem { "и" } else { "е" };
+            let sg = row(word, &s, ["и", dp, "ю", "ей", dp], None);
+            let gen_pl = if i_stem {
+                format!("{s}й")
+            } else if last(&s) == Some('ь') {
+                format!("{}ей", drop_last(&s, 1))
+            } else {
+                format!("{}ь", bare(&s))
+            };
+            let pl = row(&format!("{s}и"), &s, ["", "ям", "и", "ями", "ях"], None);
+            (sg, with_gen(pl, gen_pl), false)
+        }
+        'ь' if gender == Some(Gender::Masculine) => {
+            let s = drop_last(word, 1);
+            let sg = row(word, &s, ["я", "ю", "", "ем", "е"], Some(word));
+            let pl = row(&format!("{s}и"), &s, ["ей", "ям", "и", "ями", "ях"], None);
+            (sg, pl, true)
+        }
+        'ь' => {
+            let s = drop_last(word, 1);
+            let hush = last(&s).is_some_and(|c| HUSHING.contains(&c));
+            let (d, i, p) = if hush {
+                ("ам", "ами", "ах")
+            } else {
+                ("ям", "ями", "ях")
+            };
+            let sg = row(word, &s, ["и", "и", "ь", "ью", "и"], Some(word));
+            let pl = row(&format!("{s}и"), &s, ["ей", d, "и", i, p], None);
+            (sg, pl, false)
+        }
+        'о' => {
+            let s = drop_last(word, 1);
+            let sg = row(word, &s, ["а", "у", "", "ом", "е"], Some(word));
+            let pl = row(&format!("{s}а"), &s, ["", "ам", "а", "ами", "ах"], None);
+            (sg, with_gen(pl, bare(&s)), false)
+        }
+        'е' => {
+            let s = drop_last(word, 1);
+            match last(&s) {
+                Some('и') => (
+                    row(word, &s, ["я", "ю", "", "ем", "и"], Some(word)),
+                    row(&format!("{s}я"), &s, ["й", "ям", "я", "ями", "ях"], None),
+                    false,
+                ),
+                Some(c) if c == 'ц' || HUSHING.contains(&c) => (
+                    row(word, &s, ["а", "у", "", "ем", "е"], Some(word)),
+                    with_gen(
+                        row(&format!("{s}а"), &s, ["", "ам", "а", "ами", "ах"], None),
+                        bare(&s),
+                    ),
+                    false,
+                ),
+                _ => (
+                    row(word, &s, ["я", "ю", "", "ем", "е"], Some(word)),
+                    row(&format!("{s}я"), &s, ["ей", "ям", "я", "ями", "ях"], None),
+                    false,
+                ),
+            }
+        }
+        'й' => {
+            let s = drop_last(word, 1);
+            let p = if last(&s) == Some('и') { "и" } else { "е" };
+            let sg = row(word, &s, ["я", "ю", "", "ем", p], Some(word));
+            let pl = row(&format!("{s}и"), &s, ["ев", "ям", "и", "ями", "ях"], None);
+            (sg, pl, true)
+        }
+        c if !VOWELS.contains(&c) => {
+            let s = fleeting_ec(word);
+            let (yy, oo) = (y(&s), o(&s, stressed));
+            let gen_pl = match last(&s) {
+                Some(c) if HUSHING.contains(&c) => "ей",
+                _ => {
+                    if oo == "е" {
+                        "ев"
+                    } else {
+                        "ов"
+                    }
+                }
+            };
+            let sg = row(word, &s, ["а", "у", "", &format!("{oo}м"), "е"], Some(word));
+            let pl = row(
+                &format!("{s}{yy}"),
+                &s,
+                [gen_pl, "ам", yy, "ами", "ах"],
+                None,
+            );
+            (sg, pl, true)
+        }
+        _ => return None,
+    };
+    Some(match (animate, masculine) {
+        (false, _) => (sg, pl),
+        (true, false) => (sg, animate_acc(pl)),
+        (true, true) => (animate_acc(sg), animate_acc(pl)),
+    })
+}
+
+/// `отец` → `отц`, `палец` → `пальц`; other stems unchanged.
+fn fleeting_ec(word: &str) -> String {
+    let Some(s) = word.strip_suffix("ец") else {
+        return word.to_string();
+    };
+    match last(s) {
+        Some(c) if VOWELS.contains(&c) => word.to_string(),
+        Some('л') => format!("{s}ьц"),
+        _ if s.chars().any(|c| VOWELS.contains(&c)) => format!("{s}ц"),
+        _ => word.to_string(),
+    }
+}
+
+fn with_gen(mut r: Row, gen_pl: String) -> Row {
+    if r[3] == r[1] {
+        r[3] = gen_pl.clone();
+    }
+    r[1] = gen_pl;
+    r
+}
+
+/// The accusative borrows the genitive where it would equal the nominative.
+fn animate_acc(mut r: Row) -> Row {
+    if r[3] == r[0] {
+        r[3] = r[1].clone();
+    }
+    r
+}
+
+/// Masculine, feminine, neuter and plural rows of an adjective.
+fn adjective(word: &str, stressed_oi: bool) -> Option<[Row; 4]> {
+    let s = drop_last(word, 2);
+    let ending: String = word.chars().skip(s.chars().count()).collect();
+    if !["ый", "ий", "ой"].contains(&ending.as_str()) || s.is_empty() {
+        return None;
+    }
+    let hard_only = last(&s).is_some_and(|c| VELAR.contains(&c) || HUSHING.contains(&c));
+    let soft = ending == "ий" && !hard_only;
+    let yy = if soft { "и" } else { y(&s) };
+    let oo = if soft { "е" } else { o(&s, stressed_oi) };
+    let (fem_nom, fem_acc) = if soft {
+        ("яя", "юю")
+    } else {
+        ("ая", "ую")
+    };
+
+    let masc = row(
+        word,
+        &s,
+        [
+            &format!("{oo}го"),
+            &format!("{oo}му"),
+            "",
+            &format!("{yy}м"),
+            &format!("{oo}м"),
+        ],
+        Some(word),
+    );
+    let fem = row(
+        &format!("{s}{fem_nom}"),
+        &s,
+        [
+            &format!("{oo}й"),
+            &format!("{oo}й"),
+            fem_acc,
+            &format!("{oo}й"),
+            &format!("{oo}й"),
+        ],
+        None,
+    );
+    let neut_nom = format!("{s}{oo}е");
+    let neut = row(
+        &neut_nom,
+        &s,
+        [
+            &format!("{oo}го"),
+            &format!("{oo}му"),
+            "",
+            &format!("{yy}м"),
+            &format!("{oo}м"),
+        ],
+        Some(&neut_nom),
+    );
+    let pl_nom = format!("{s}{yy}е");
+    let pl = row(
+        &pl_nom,
+        &s,
+        [
+            &format!("{yy}х"),
+            &format!("{yy}м"),
+            "",
+            &format!("{yy}ми"),
+            &format!("{yy}х"),
+        ],
+        Some(&pl_nom),
+    );
+    Some([masc, fem, neut, pl])
+}
+
+/// The generated paradigm for a noun or adjective, or `None` for other parts
+/// of speech, multi-word / non-Cyrillic heads and endings no paradigm fits.
+pub fn decline(e: &LexEntry) -> Option<Declension> {
+    let pos = e.pos.as_deref()?.to_lowercase();
+    let marked = e.head();
+    if !is_russian_word(marked) {
+        return None;
+    }
+    let stressed = end_stressed(marked);
+    let word: String = marked.chars().filter(|&c| c != STRESS).collect();
+
+    let cells: Vec<CaseForms> = match pos.as_str() {
+        "noun" => {
+            let (sg, pl) = noun(&word, e.gender, e.animate, stressed)?;
+            (0..6)
+                .map(|i| CaseForms {
+                    singular: sg[i].clone(),
+                    plural: pl[i].clone(),
+                    ..Default::default()
+                })
+                .collect()
+        }
+        "adjective" | "adj" => {
+            let [m, f, n, pl] = adjective(&word, word.ends_with("ой"))?;
+            (0..6)
+                .map(|i| CaseForms {
+                    masculine: m[i].clone(),
+                    feminine: f[i].clone(),
+                    neuter: n[i].clone(),
+                    plural: pl[i].clone(),
+                    ..Default::default()
+                })
+                .collect()
+        }
+        _ => return None,
+    };
+    let cases = case_rows("russian")
+        .iter()
+        .zip(cells)
+        .map(|(row, forms)| (row.key.to_string(), forms));
+    Some(Declension {
+        cases: cases.collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(word: &str, pos: &str) -> LexEntry {
+        LexEntry {
+            word: word.into(),
+            pos: Some(pos.into()),
+            ..Default::default()
+        }
+    }
+
+    fn col(d: &Declension, pick: fn(&CaseForms) -> &String) -> Vec<String> {
+        case_rows("russian")
+            .iter()
+            .map(|row| pick(d.forms(&row.key).unwrap()).clone())
+            .collect()
+    }
+
+    #[test]
+    fn masculine_nouns_by_stem_and_animacy() {
+        let d = decline(&entry("стол", "noun")).unwrap();
+        assert_eq!(
+            col(&d, |f| &f.singular),
+            ["стол", "стола", "столу", "стол", "столом", "столе"]
+        );
+        assert_eq!(
+            col(&d, |f| &f.plural),
+            ["столы", "столов", "столам", "столы", "столами", "столах"]
+        );
+
+        let mut father = entry("оте́ц", "noun");
+        father.animate = true;
+        let d = decline(&father).unwrap();
+        assert_eq!(
+            col(&d, |f| &f.singular),
+            ["отец", "отца", "отцу", "отца", "отцом", "отце"]
+        );
+        assert_eq!(d.forms("accusative").unwrap().plural, "отцов");
+        assert_eq!(
+            decline(&entry("немец", "noun"))
+                .unwrap()
+                .forms("instrumental")
+                .unwrap()
+                .singular,
+            "немцем"
+        );
+
+        let d = decline(&entry("ме́сяц", "noun")).unwrap();
+        assert_eq!(d.forms("instrumental").unwrap().singular, "месяцем");
+        let d = decline(&entry("случай", "noun")).unwrap();
+        assert_eq!(d.forms("genitive").unwrap().plural, "случаев");
+    }
+
+    #[test]
+    fn soft_sign_follows_gender() {
+        let d = decline(&entry("дверь", "noun")).unwrap();
+        assert_eq!(
+            col(&d, |f| &f.singular),
+            ["дверь", "двери", "двери", "дверь", "дверью", "двери"]
+        );
+        let mut rouble = entry("рубль", "noun");
+        rouble.gender = Some(Gender::Masculine);
+        let d = decline(&rouble).unwrap();
+        assert_eq!(
+            col(&d, |f| &f.singular),
+            ["рубль", "рубля", "рублю", "рубль", "рублем", "рубле"]
+        );
+        assert_eq!(
+            decline(&entry("ночь", "noun"))
+                .unwrap()
+                .forms("dative")
+                .unwrap()
+                .plural,
+            "ночам"
+        );
+    }
+
+    #[test]
+    fn feminine_and_neuter_plurals() {
+        let d = decline(&entry("книга", "noun")).unwrap();
+        assert_eq!(
+            col(&d, |f| &f.singular),
+            ["книга", "книги", "книге", "книгу", "книгой", "книге"]
+        );
+        assert_eq!(
+            col(&d, |f| &f.plural),
+            ["книги", "книг", "книгам", "книги", "книгами", "книгах"]
+        );
+        assert_eq!(
+            decline(&entry("девушка", "noun"))
+                .unwrap()
+                .forms("genitive")
+                .unwrap()
+                .plural,
+            "девушек"
+        );
+        assert_eq!(
+            decline(&entry("история", "noun"))
+                .unwrap()
+                .forms("prepositional")
+                .unwrap()
+                .singular,
+            "истории"
+        );
+        assert_eq!(
+            decline(&entry("окно", "noun"))
+                .unwrap()
+                .forms("genitive")
+                .unwrap()
+                .plural,
+            "окон"
+        );
+        assert_eq!(
+            decline(&entry("движение", "noun"))
+                .unwrap()
+                .forms("genitive")
+                .unwrap()
+                .plural,
+            "движений"
+        );
+        let d = decline(&entry("вре́мя", "noun")).unwrap();
+        assert_eq!(d.forms("genitive").unwrap().singular, "времени");
+        assert_eq!(d.forms("nominative").unwrap().plural, "времена");
+    }
+
+    #[test]
+    fn adjectives_fill_three_genders_and_plural() {
+        let d = decline(&entry("но́вый", "adjective")).unwrap();
+        let nom = d.forms("nominative").unwrap();
+        assert_eq!(
+            (
+                nom.masculine.as_str(),
+                nom.feminine.as_str(),
+                nom.neuter.as_str(),
+                nom.plural.as_str()
+            ),
+            ("новый", "новая", "новое", "новые")
+        );
+        assert!(nom.singular.is_empty());
+        assert_eq!(d.forms("genitive").unwrap().masculine, "нового");
+
+        let d = decline(&entry("хороший", "adjective")).unwrap();
+        assert_eq!(d.forms("genitive").unwrap().masculine, "хорошего");
+        assert_eq!(d.forms("instrumental").unwrap().feminine, "хорошей");
+        let d = decline(&entry("большой", "adjective")).unwrap();
+        assert_eq!(d.forms("genitive").unwrap().feminine, "большой");
+        assert_eq!(d.forms("nominative").unwrap().plural, "большие");
+        let d = decline(&entry("последний", "adjective")).unwrap();
+        assert_eq!(d.forms("accusative").unwrap().feminine, "последнюю");
+        assert_eq!(d.forms("genitive").unwrap().plural, "последних");
+        let d = decline(&entry("русский", "adjective")).unwrap();
+        assert_eq!(d.forms("genitive").unwrap().masculine, "русского");
+    }
+}
@@ -0,0 +1,376 @@
+// src/models/lemma.rs
+//
+// Lemmatizer: maps a word as it appears in running text back to its `Lexicon`
+// head word, with the morphology it was parsed as and a confidence.
+//
+// Three passes, most to least certain:
+//   1. the head word itself, or any cell of the entry's declension table
+//      (authored or `models::inflect`-generated);
+//   2. Georgian postposition clitics (`სახლში` = dative `სახლს` + `-ში`):
+//      strip the clitic, restore the host case form, look that up in pass 1;
+//   3. a guess: strip a known case ending and try the citation endings on the
+//      stem. Only used when nothing better matched.
+//
+// Lookups fold case and Russian stress marks, so sentence-initial capitals and
+// accented heads (`челове́к`) still match.
+
+use std::collections::HashMap;
+
+use super::lexicon::{Column, Inventory, LexEntry, Lexicon};
+
+/// A Georgian postposition that fuses onto a noun in a fixed host case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Clitic {
+    pub suffix: &'static str,
+    /// Case key of the host form.
+    pub host: &'static str,
+    /// What stripping `suffix` removed from the host form (`სახლს` + `ში` →
+    /// `სახლში` drops the `ს`).
+    pub restore: &'static str,
+    pub gloss: &'static str,
+}
+
+const fn clitic(
+    suffix: &'static str,
+    host: &'static str,
+    restore: &'static str,
+    gloss: &'static str,
+) -> Clitic {
+    Clitic {
+        suffix,
+        host,
+        restore,
+        gloss,
+    }
+}
+
+/// Longest first, so `-ისთვის` wins over anything it ends with.
+const GEORGIAN_CLITICS: [Clitic; 9] = [
+    clitic("ისთვის", "genitive", "ის", "for"),
+    clitic("იდან", "instrumental", "ით", "from"),
+    clitic("ავით", "dative", "", "like"),
+    clitic("ივით", "genitive", "ის", "like"),
+    clitic("ამდე", "adverbial", "ად", "up to"),
+    // vowel stems keep the dative -ს before -თან (`დედასთან`), consonant stems drop it
+    clitic("სთან", "dative", "ს", "at, with"),
+    clitic("თან", "dative", "ს", "at, with"),
+    clitic("ში", "dative", "ს", "in"),
+    clitic("ზე", "dative", "ს", "on"),
+];
+
+/// Case endings stripped by the guessing pass, longest first.
+const GEORGIAN_ENDINGS: [&str; 13] = [
+    "ებმა",
+    "ების",
+    "ებით",
+    "ებად",
+    "ებს",
+    "ები",
+    "ებო",
+    "ის",
+    "ით",
+    "ად",
+    "მა",
+    "ს",
+    "ო",
+];
+const GEORGIAN_CITATION: [&str; 4] = ["ი", "", "ა", "ე"];
+
+const RUSSIAN_ENDINGS: [&str; 34] = [
+    "ами", "ями", "ого", "его", "ому", "ему", "ыми", "ими", "ов", "ев", "ей", "ий", "ам", "ям",
+    "ах", "ях", "ом", "ем", "ой", "ую", "юю", "ая", "яя", "ое", "ее", "ые", "ие", "ых", "их", "ым",
+    "им", "ью", "а", "я",
+];
+const RUSSIAN_SHORT_ENDINGS: [&str; 5] = ["у", "ю", "е", "ы", "и"];
+const RUSSIAN_CITATION: [&str; 11] = ["", "а", "я", "о", "е", "ь", "й", "ый", "ий", "ой", "ть"];
+
+/// Confidence of a head-word match, an authored cell and a generated one.
+const HEAD: f32 = 1.0;
+const AUTHORED: f32 = 0.95;
+const GENERATED: f32 = 0.85;
+/// Multiplier for going through a clitic.
+const VIA_CLITIC: f32 = 0.9;
+/// Confidence of a suffix-stripping guess, split across the candidates.
+const GUESS: f32 = 0.5;
+
+fn clitics(lang: &str) -> &'static [Clitic] {
+    match lang {
+        "georgian" => &GEORGIAN_CLITICS,
+        _ => &[],
+    }
+}
+
+/// Endings to strip and citation endings to try, for the guessing pass.
+fn guess_tables(lang: &str) -> (Vec<&'static str>, &'static [&'static str]) {
+    match lang {
+        "georgian" => (GEORGIAN_ENDINGS.to_vec(), &GEORGIAN_CITATION),
+        "russian" => {
+            let mut endings = RUSSIAN_ENDINGS.to_vec();
+            endings.extend(RUSSIAN_SHORT_ENDINGS);
+            (endings, &RUSSIAN_CITATION)
+        }
+        _ => (Vec::new(), &[]),
+    }
+}
+
+/// Lowercase and drop combining stress marks.
+fn fold(s: &str) -> String {
+    s.trim()
+        .chars()
+        .filter(|&c| c != '\u{301}')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// One reading of a surface form.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Analysis {
+    pub entry: LexEntry,
+    /// Case key and column of the table cell it matched; `None` for the
+    /// citation form and for guesses.
+    pub form: Option<(String, Column)>,
+    pub clitic: Option<Clitic>,
+    /// 0–1: how sure the parse is.
+    pub confidence: f32,
+    /// Found by stripping endings rather than from a table.
+    pub guessed: bool,
+}
+
+impl Analysis {
+    /// The head word the form belongs to.
+    pub fn lemma(&self) -> &str {
+        &self.entry.word
+    }
+
+    /// "Dative · plural + -ში (in)", "citation form", or "guess: inflected form".
+    pub fn morphology(&self, inventory: &Inventory) -> String {
+        let mut out = match &self.form {
+            Some((case, column)) => {
+                let label = inventory.case_label(case);
+                format!("{label} · {}", column.label().to_lowercase())
+            }
+            None if self.guessed => "guess: inflected form".to_string(),
+            None => "citation form".to_string(),
+        };
+        if let Some(c) = self.clitic {
+            out.push_str(&format!(" + -{} ({})", c.suffix, c.gloss));
+        }
+        out
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Hit {
+    entry: usize,
+    form: Option<(String, Column)>,
+    confidence: f32,
+}
+
+/// Surface-form index over one language's lexicon.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Lemmatizer {
+    lang: String,
+    entries: Vec<LexEntry>,
+    forms: HashMap<String, Vec<Hit>>,
+}
+
+impl Lemmatizer {
+    pub fn new(lang: &str, lexicon: &Lexicon) -> Self {
+        let entries = lexicon.all().to_vec();
+        let mut forms: HashMap<String, Vec<Hit>> = HashMap::new();
+        let mut add = |surface: &str, hit: Hit| {
+            let hits = forms.entry(fold(surface)).or_default();
+            if !hits
+                .iter()
+                .any(|h| h.entry == hit.entry && h.form == hit.form)
+            {
+                hits.push(hit);
+            }
+        };
+        for (i, e) in entries.iter().enumerate() {
+            for head in e.word.split('/') {
+                if !head.trim().is_empty() {
+                    add(
+                        head,
+                        Hit {
+                            entry: i,
+                            form: None,
+                            confidence: HEAD,
+                        },
+                    );
+                }
+            }
+            let Some(d) = &e.declension else { continue };
+            let confidence = if e.declension_generated {
+                GENERATED
+            } else {
+                AUTHORED
+            };
+            for (case, f) in &d.cases {
+                for column in Column::ALL {
+                    let surface = column.of(f).trim();
+                    if !surface.is_empty() {
+                        let form = Some((case.clone(), column));
+                        add(
+                            surface,
+                            Hit {
+                                entry: i,
+                                form,
+                                confidence,
+                            },
+                        );
+                    }
+                }
+            }
+        }
+        Self {
+            lang: lang.to_string(),
+            entries,
+            forms,
+        }
+    }
+
+    fn analysis(&self, hit: &Hit, clitic: Option<Clitic>, confidence: f32) -> Analysis {
+        Analysis {
+            entry: self.entries[hit.entry].clone(),
+            form: hit.form.clone(),
+            clitic,
+            confidence,
+            guessed: false,
+        }
+    }
+
+    /// Every reading of `surface`, most confident first; empty if none.
+    pub fn analyze(&self, surface: &str) -> Vec<Analysis> {
+        let word = fold(surface);
+        if word.is_empty() {
+            return Vec::new();
+        }
+
+        let mut out: Vec<Analysis> = self
+            .forms
+            .get(&word)
+            .into_iter()
+            .flatten()
+            .map(|h| self.analysis(h, None, h.confidence))
+            .collect();
+
+        for &c in clitics(&self.lang) {
+            let Some(stem) = word.strip_suffix(c.suffix) else {
+                continue;
+            };
+            if stem.is_empty() {
+                continue;
+            }
+            let host = format!("{stem}{}", c.restore);
+            for h in self.forms.get(&host).into_iter().flatten() {
+                if h.form.as_ref().is_some_and(|(case, _)| case == c.host) {
+                    out.push(self.analysis(h, Some(c), h.confidence * VIA_CLITIC));
+                }
+            }
+        }
+
+        if out.is_empty() {
+            out = self.guess(&word);
+        }
+        out.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        out
+    }
+
+    /// The most confident reading, if any.
+    pub fn best(&self, surface: &str) -> Option<Analysis> {
+        self.analyze(surface).into_iter().next()
+    }
+
+    fn guess(&self, word: &str) -> Vec<Analysis> {
+        let (endings, citation) = guess_tables(&self.lang);
+        let mut found: Vec<usize> = Vec::new();
+        for ending in endings {
+            let Some(stem) = word.strip_suffix(ending) else {
+                continue;
+            };
+            // keep at least two letters of stem, or short words match anything
+            if stem.chars().count() < 2 {
+                continue;
+            }
+            for c in citation {
+                let candidate = format!("{stem}{c}");
+                for h in self.forms.get(&candidate).into_iter().flatten() {
+                    if h.form.is_none() && !found.contains(&h.entry) {
+                        found.push(h.entry);
+                    }
+                }
+            }
+            if !found.is_empty() {
+                break;
+            }
+        }
+        let confidence = GUESS / found.len().max(1) as f32;
+        found
+            .into_iter()
+            .map(|i| Analysis {
+                entry: self.entries[i].clone(),
+                form: None,
+                clitic: None,
+                confidence,
+                guessed: true,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lexicon(lang: &str, words: &[(&str, &str)]) -> Lemmatizer {
+        let entries = words
+            .iter()
+            .enumerate()
+            .map(|(i, (w, pos))| LexEntry {
+                rank: i as u32 + 1,
+                word: w.to_string(),
+                pos: Some(pos.to_string()),
+                ..Default::default()
+            })
+            .collect();
+        let mut lex = Lexicon::from_entries(entries);
+        lex.fill_declensions(lang);
+        Lemmatizer::new(lang, &lex)
+    }
+
+    #[test]
+    fn table_forms_and_clitics_reach_the_head_word() {
+        let l = lexicon("georgian", &[("სახლი", "noun"), ("დედა", "noun")]);
+        let a = l.best("სახლის").unwrap();
+        assert_eq!(a.lemma(), "სახლი");
+        assert_eq!(
+            a.morphology(&Inventory::builtin("georgian")),
+            "Genitive · singular"
+        );
+        assert!(a.confidence > 0.8 && !a.guessed);
+
+        let a = l.best("სახლებში").unwrap();
+        assert_eq!(a.lemma(), "სახლი");
+        assert_eq!(
+            a.morphology(&Inventory::builtin("georgian")),
+            "Dative · plural + -ში (in)"
+        );
+        assert_eq!(l.best("დედასთან").unwrap().lemma(), "დედა");
+        assert_eq!(l.best("დედისთვის").unwrap().clitic.unwrap().gloss, "for");
+        assert!(l.best("ფანჯარა").is_none());
+    }
+
+    #[test]
+    fn russian_folds_case_and_stress_and_guesses_last() {
+        let l = lexicon("russian", &[("кни́га", "noun"), ("читать", "verb")]);
+        let all = l.analyze("Книги");
+        assert!(all.len() >= 2, "gen sg and nom/acc pl");
+        assert!(all.iter().all(|a| a.lemma() == "кни́га"));
+
+        // not in any table: found by stripping -ю and trying citation endings
+        let a = l.best("читаю").unwrap();
+        assert_eq!(a.lemma(), "читать");
+        assert!(a.guessed && a.confidence <= GUESS);
+    }
+}
@@ -10,21 +10,95 @@
 // `#[serde(default)]`, so old JSON stays valid; they're forward hooks for the
 // dictionary / audio / OCR-ingestion work.
 
-use serde::{Deserialize, Serialize};
-use std::collections::BTreeSet;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// One meaning of a head word.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Sense {
+    /// English glosses, each an accepted answer on its own ("way", "road").
+    pub glosses: Vec<String>,
+    /// Only where this sense's part of speech differs from the entry's.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pos: Option<String>,
+    /// Register or usage ("colloquial", "of time").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<String>,
+}
+
+/// `s` split on any of `seps` outside parentheses, pieces trimmed, empties dropped.
+fn split_top_level(s: &str, seps: &[char]) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut depth = 0i32;
+    let mut cur = String::new();
+    for c in s.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = (depth - 1).max(0),
+            _ => {}
+        }
+        if depth == 0 && seps.contains(&c) {
+            out.push(std::mem::take(&mut cur));
+        } else {
+            cur.push(c);
+        }
+    }
+    out.push(cur);
+    out.into_iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect()
+}
+
+impl Sense {
+    /// Senses from a one-line gloss (the old `en` format): `;` separates
+    /// senses, `,` and `/` the glosses of one sense. Separators inside
+    /// parentheses don't count, so `"beat (of a heart, drums)"` stays whole.
+    pub fn parse(gloss: &str) -> Vec<Sense> {
+        split_top_level(gloss, &[';'])
+            .into_iter()
+            .map(|sense| Sense {
+                glosses: split_top_level(&sense, &[',', '/']),
+                ..Default::default()
+            })
+            .filter(|s| !s.glosses.is_empty())
+            .collect()
+    }
+
+    /// "way, road, path".
+    pub fn gloss(&self) -> String {
+        self.glosses.join(", ")
+    }
+}
+
+/// Accepts either a list of senses or an old-style one-line gloss string.
+fn senses_or_gloss<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<Sense>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+        Gloss(String),
+        Senses(Vec<Sense>),
+    }
+    Ok(match Repr::deserialize(d)? {
+        Repr::Gloss(g) => Sense::parse(&g),
+        Repr::Senses(s) => s,
+    })
+}
 
 /// One lexical entry (a head word plus its metadata).
-///
-/// `en` is kept singular for now to stay compatible with the current data and
-/// call sites; it becomes `glosses: Vec<String>` in the dictionary phase.
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct LexEntry {
     /// Frequency rank within the language (1 = most common).
     /// Subsets like "top 1000" are derived by sorting/filtering on this.
     pub rank: u32,
 
-    /// L1 gloss / English meaning.
-    pub en: String,
+    /// English meanings, most common first. Packs may still give the old
+    /// one-line `"en": "face; person"` gloss instead (`Sense::parse`).
+    #[serde(default, alias = "en", deserialize_with = "senses_or_gloss")]
+    pub senses: Vec<Sense>,
 
     /// L2 head form (dictionary form).
     pub word: String,
@@ -38,7 +112,8 @@ pub struct LexEntry {
     pub example: Option<String>,
 
     // ── forward hooks (all optional; absent in current JSON) ──────────────
-    /// Pronunciation asset path, for the listening drill.
+    /// Recording filename under the pack's `pronunciation/words/`, played by
+    /// the listening drill in place of espeak.
     #[serde(default)]
     pub audio: Option<String>,
 
@@ -50,11 +125,37 @@ pub struct LexEntry {
     #[serde(default)]
     pub user_added: bool,
 
-    /// Full nominal declension. For Georgian this is the 7-case paradigm
-    /// (singular / plural / attributive "with noun"). Optional + `serde(default)`,
-    /// so entries without it — i.e. all current data — stay valid.
+    /// Full nominal declension over the pack's `Inventory::cases`: for Georgian
+    /// the 7-case paradigm (singular / plural / attributive "with noun"), for
+    /// Russian the six cases by number, or by gender for adjectives.
+    /// Optional + `serde(default)`, so entries without it stay valid.
     #[serde(default)]
     pub declension: Option<Declension>,
+
+    /// True when some of `declension` came from `models::inflect` rather than
+    /// the data file. Set on load; authored cells are never overwritten.
+    #[serde(default)]
+    pub declension_generated: bool,
+
+    /// Grammatical gender, where the ending alone doesn't settle it.
+    #[serde(default)]
+    pub gender: Option<Gender>,
+
+    /// Names a person or animal. Russian animate nouns take the genitive form
+    /// in the accusative.
+    #[serde(default)]
+    pub animate: bool,
+
+    /// Georgian stem that drops its last vowel before a vowel-initial ending
+    /// (`მეგობარი` → `მეგობრის`). Only some stems do (`ქართველი` →
+    /// `ქართველის`), so the pack marks them.
+    #[serde(default)]
+    pub syncope: bool,
+
+    /// Verb paradigm: Georgian screeves, or a Russian verb's tenses plus its
+    /// aspect partner. Absent for non-verbs and unauthored verbs.
+    #[serde(default)]
+    pub conjugation: Option<Conjugation>,
 }
 
 impl LexEntry {
@@ -63,76 +164,141 @@ impl LexEntry {
     pub fn head(&self) -> &str {
         self.word.split('/').next().unwrap_or(&self.word).trim()
     }
+
+    /// Every sense on one line, `;`-separated ("face; person"): the old `en`
+    /// format, for compact displays.
+    pub fn gloss(&self) -> String {
+        self.senses
+            .iter()
+            .map(Sense::gloss)
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
 }
 
-/// The seven Georgian grammatical cases, in canonical paradigm order.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum GeoCase {
-    Nominative,
-    Ergative,
-    Dative,
-    Genitive,
-    Instrumental,
-    Adverbial,
-    Vocative,
-}
-
-impl GeoCase {
-    /// All cases in display order.
-    pub const ALL: [GeoCase; 7] = [
-        GeoCase::Nominative,
-        GeoCase::Ergative,
-        GeoCase::Dative,
-        GeoCase::Genitive,
-        GeoCase::Instrumental,
-        GeoCase::Adverbial,
-        GeoCase::Vocative,
-    ];
+/// One row of a declension table: a case, keyed by the name the lexicon JSON
+/// stores it under. Packs list theirs in the manifest (`Inventory`).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaseRow {
+    pub key: Cow<'static, str>,
+    /// English case name, for the table's first column.
+    pub label: Cow<'static, str>,
+    /// One-line "what this case is for", shown as a hover tooltip.
+    #[serde(default)]
+    pub blurb: Cow<'static, str>,
+}
 
-    /// English case name (proper case), for the table's first column.
-    pub fn label(self) -> &'static str {
-        match self {
-            GeoCase::Nominative => "Nominative",
-            GeoCase::Ergative => "Ergative",
-            GeoCase::Dative => "Dative",
-            GeoCase::Genitive => "Genitive",
-            GeoCase::Instrumental => "Instrumental",
-            GeoCase::Adverbial => "Adverbial",
-            GeoCase::Vocative => "Vocative",
-        }
+const fn case(key: &'static str, label: &'static str, blurb: &'static str) -> CaseRow {
+    CaseRow {
+        key: Cow::Borrowed(key),
+        label: Cow::Borrowed(label),
+        blurb: Cow::Borrowed(blurb),
     }
+}
 
-    /// One-line "what this case is for", shown as a hover tooltip.
-    pub fn blurb(self) -> &'static str {
-        match self {
-            GeoCase::Nominative => {
-                "The dictionary form. Marks the subject with present/future-series \
-                 verbs and with intransitives."
-            }
-            GeoCase::Ergative => {
-                "Marks the subject of a transitive verb in the aorist (past) series — \
-                 Georgian's split ergativity."
-            }
-            GeoCase::Dative => {
-                "The 'to / for' case: the indirect object, and the direct object in \
-                 some tense series."
-            }
-            GeoCase::Genitive => "Possession or association — the 'of' case (whose? of what?).",
-            GeoCase::Instrumental => {
-                "'By means of' — the tool, material, or means by which something is done."
-            }
-            GeoCase::Adverbial => {
-                "Transformation or manner — 'as', 'into', 'in the form of'; also the \
-                 translative."
-            }
-            GeoCase::Vocative => {
-                "Direct address — calling out to someone or something ('O friend!')."
-            }
-        }
+/// The seven Georgian grammatical cases, in canonical paradigm order.
+static GEORGIAN_CASES: [CaseRow; 7] = [
+    case(
+        "nominative",
+        "Nominative",
+        "The dictionary form. Marks the subject with present/future-series \
+         verbs and with intransitives.",
+    ),
+    case(
+        "ergative",
+        "Ergative",
+        "Marks the subject of a transitive verb in the aorist (past) series — \
+         Georgian's split ergativity.",
+    ),
+    case(
+        "dative",
+        "Dative",
+        "The 'to / for' case: the indirect object, and the direct object in \
+         some tense series.",
+    ),
+    case(
+        "genitive",
+        "Genitive",
+        "Possession or association — the 'of' case (whose? of what?).",
+    ),
+    case(
+        "instrumental",
+        "Instrumental",
+        "'By means of' — the tool, material, or means by which something is done.",
+    ),
+    case(
+        "adverbial",
+        "Adverbial",
+        "Transformation or manner — 'as', 'into', 'in the form of'; also the \
+         translative.",
+    ),
+    case(
+        "vocative",
+        "Vocative",
+        "Direct address — calling out to someone or something ('O friend!').",
+    ),
+];
+
+/// The six Russian cases, in the order Russian grammars teach them.
+static RUSSIAN_CASES: [CaseRow; 6] = [
+    case(
+        "nominative",
+        "Nominative",
+        "The dictionary form: the subject, and the complement of 'is'.",
+    ),
+    case(
+        "genitive",
+        "Genitive",
+        "'Of', absence (нет + genitive), quantities, and after из, от, у, без.",
+    ),
+    case(
+        "dative",
+        "Dative",
+        "'To / for' someone: the indirect object; also after к and по.",
+    ),
+    case(
+        "accusative",
+        "Accusative",
+        "The direct object, and motion 'into / onto' after в and на. Animate \
+         masculine and plural nouns borrow the genitive form.",
+    ),
+    case(
+        "instrumental",
+        "Instrumental",
+        "'By means of' or 'with' (с); also after становиться and быть in the past.",
+    ),
+    case(
+        "prepositional",
+        "Prepositional",
+        "Only after a preposition: location with в / на, 'about' with о.",
+    ),
+];
+
+/// The built-in declension rows for a shipped language, in table order; empty
+/// if none. The generators in `inflect` fill exactly these; everything else
+/// reads the pack's `Inventory`, which falls back to them.
+pub fn case_rows(lang: &str) -> &'static [CaseRow] {
+    match lang {
+        "georgian" => &GEORGIAN_CASES,
+        "russian" => &RUSSIAN_CASES,
+        _ => &[],
     }
 }
 
-/// The three column forms a Georgian word takes per case.
+/// Grammatical gender. Russian only; drives which paradigm a noun takes when
+/// its ending is ambiguous (masculine `день` vs feminine `дверь`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+/// The forms a word takes in one case. Which fields are used depends on the
+/// language and the part of speech: Georgian fills singular / plural and, for
+/// adjectives, the attributive "with noun" form; a Russian noun fills singular
+/// / plural; a Russian adjective fills the three genders plus plural.
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct CaseForms {
     #[serde(default)]
@@ -142,53 +308,400 @@ pub struct CaseForms {
     /// Attributive form, used when the word directly modifies a following noun.
     #[serde(default, rename = "with_noun")]
     pub with_noun: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub masculine: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub feminine: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub neuter: String,
 }
 
 impl CaseForms {
     pub fn is_empty(&self) -> bool {
-        self.singular.trim().is_empty()
-            && self.plural.trim().is_empty()
-            && self.with_noun.trim().is_empty()
+        Column::ALL.iter().all(|&c| c.of(self).trim().is_empty())
     }
 }
 
-/// A full case × number paradigm. The field names are the JSON keys the lexicon
-/// stores, so the data file reads exactly like the matrix it represents.
+/// A column of a declension table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    Singular,
+    Masculine,
+    Feminine,
+    Neuter,
+    Plural,
+    WithNoun,
+}
+
+impl Column {
+    /// All columns in display order. A table shows the ones any case fills.
+    pub const ALL: [Column; 6] = [
+        Column::Singular,
+        Column::Masculine,
+        Column::Feminine,
+        Column::Neuter,
+        Column::Plural,
+        Column::WithNoun,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Column::Singular => "Singular",
+            Column::Masculine => "Masculine",
+            Column::Feminine => "Feminine",
+            Column::Neuter => "Neuter",
+            Column::Plural => "Plural",
+            Column::WithNoun => "With noun",
+        }
+    }
+
+    /// This column's cell in `f`.
+    pub fn of(self, f: &CaseForms) -> &str {
+        match self {
+            Column::Singular => &f.singular,
+            Column::Masculine => &f.masculine,
+            Column::Feminine => &f.feminine,
+            Column::Neuter => &f.neuter,
+            Column::Plural => &f.plural,
+            Column::WithNoun => &f.with_noun,
+        }
+    }
+
+    fn of_mut(self, f: &mut CaseForms) -> &mut String {
+        match self {
+            Column::Singular => &mut f.singular,
+            Column::Masculine => &mut f.masculine,
+            Column::Feminine => &mut f.feminine,
+            Column::Neuter => &mut f.neuter,
+            Column::Plural => &mut f.plural,
+            Column::WithNoun => &mut f.with_noun,
+        }
+    }
+}
+
+/// A full case × column paradigm, keyed by case (`Inventory::cases` fixes which cases
+/// exist and their order). Serialized as a plain object, so the data file reads
+/// exactly like the matrix it represents.
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct Declension {
+    pub cases: BTreeMap<String, CaseForms>,
+}
+
+impl Declension {
+    /// Borrow the forms for a case, if it has any.
+    pub fn forms(&self, case: &str) -> Option<&CaseForms> {
+        self.cases.get(case)
+    }
+
+    /// The forms for a case, created empty if missing.
+    pub fn forms_mut(&mut self, case: &str) -> &mut CaseForms {
+        self.cases.entry(case.to_string()).or_default()
+    }
+
+    /// True if at least one cell is non-empty (gate for showing the table).
+    pub fn any_filled(&self) -> bool {
+        self.cases.values().any(|f| !f.is_empty())
+    }
+
+    /// Columns that have a form in at least one case, in display order.
+    pub fn columns(&self) -> Vec<Column> {
+        Column::ALL
+            .into_iter()
+            .filter(|&c| self.cases.values().any(|f| !c.of(f).trim().is_empty()))
+            .collect()
+    }
+
+    /// Every non-empty form in the table, in no particular order.
+    pub fn all_forms(&self) -> impl Iterator<Item = &str> {
+        self.cases
+            .values()
+            .flat_map(|f| Column::ALL.into_iter().map(move |c| c.of(f).trim()))
+            .filter(|s| !s.is_empty())
+    }
+
+    /// Copy `other`'s cells into the ones left blank here. Returns true if any
+    /// cell was filled.
+    pub fn fill_from(&mut self, other: &Declension) -> bool {
+        let mut filled = false;
+        for (case, from) in &other.cases {
+            let to = self.forms_mut(case);
+            for c in Column::ALL {
+                let (dst, src) = (c.of_mut(to), c.of(from));
+                if dst.trim().is_empty() && !src.trim().is_empty() {
+                    *dst = src.to_string();
+                    filled = true;
+                }
+            }
+        }
+        filled
+    }
+}
+
+/// Grammatical person × number, in paradigm order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Person {
+    Sg1,
+    Sg2,
+    Sg3,
+    Pl1,
+    Pl2,
+    Pl3,
+}
+
+impl Person {
+    pub const ALL: [Person; 6] = [
+        Person::Sg1,
+        Person::Sg2,
+        Person::Sg3,
+        Person::Pl1,
+        Person::Pl2,
+        Person::Pl3,
+    ];
+
+    /// Short key, also the JSON key in `PersonForms` and item ids.
+    pub fn key(self) -> &'static str {
+        match self {
+            Person::Sg1 => "1sg",
+            Person::Sg2 => "2sg",
+            Person::Sg3 => "3sg",
+            Person::Pl1 => "1pl",
+            Person::Pl2 => "2pl",
+            Person::Pl3 => "3pl",
+        }
+    }
+}
+
+/// The built-in subject pronouns for a shipped language, in `Person::ALL`
+/// order; empty if none.
+pub fn pronouns(lang: &str) -> &'static [&'static str] {
+    match lang {
+        "georgian" => &["მე", "შენ", "ის", "ჩვენ", "თქვენ", "ისინი"],
+        "russian" => &["я", "ты", "он / она", "мы", "вы", "они"],
+        _ => &[],
+    }
+}
+
+/// One tense row: a form per person.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PersonForms {
+    #[serde(default, rename = "1sg")]
+    pub sg1: String,
+    #[serde(default, rename = "2sg")]
+    pub sg2: String,
+    #[serde(default, rename = "3sg")]
+    pub sg3: String,
+    #[serde(default, rename = "1pl")]
+    pub pl1: String,
+    #[serde(default, rename = "2pl")]
+    pub pl2: String,
+    #[serde(default, rename = "3pl")]
+    pub pl3: String,
+}
+
+impl PersonForms {
+    pub fn get(&self, p: Person) -> &str {
+        match p {
+            Person::Sg1 => &self.sg1,
+            Person::Sg2 => &self.sg2,
+            Person::Sg3 => &self.sg3,
+            Person::Pl1 => &self.pl1,
+            Person::Pl2 => &self.pl2,
+            Person::Pl3 => &self.pl3,
+        }
+    }
+}
+
+/// Russian verbal aspect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Aspect {
+    Imperfective,
+    Perfective,
+}
+
+impl Aspect {
+    pub fn label(self) -> &'static str {
+        match self {
+            Aspect::Imperfective => "Imperfective",
+            Aspect::Perfective => "Perfective",
+        }
+    }
+}
+
+/// A verb's paradigm. Rows are keyed by `TenseRow::key`; the pack's
+/// `Inventory::tenses` fixes which rows exist and their order.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Conjugation {
+    /// Russian only.
     #[serde(default)]
-    pub nominative: CaseForms,
-    #[serde(default)]
-    pub ergative: CaseForms,
+    pub aspect: Option<Aspect>,
     #[serde(default)]
-    pub dative: CaseForms,
+    pub tenses: BTreeMap<String, PersonForms>,
+    /// The other half of a Russian aspect pair (`читать` ↔ `прочитать`).
     #[serde(default)]
-    pub genitive: CaseForms,
+    pub partner: Option<Box<AspectPartner>>,
+}
+
+impl Conjugation {
+    /// The form for one cell, if authored.
+    pub fn form(&self, tense: &str, p: Person) -> Option<&str> {
+        let f = self.tenses.get(tense)?.get(p).trim();
+        (!f.is_empty()).then_some(f)
+    }
+}
+
+/// The aspect partner of a Russian verb, with its own paradigm.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AspectPartner {
+    pub word: String,
     #[serde(default)]
-    pub instrumental: CaseForms,
+    pub aspect: Option<Aspect>,
     #[serde(default)]
-    pub adverbial: CaseForms,
+    pub tenses: BTreeMap<String, PersonForms>,
+}
+
+impl AspectPartner {
+    /// The partner as a stand-alone paradigm.
+    pub fn conjugation(&self) -> Conjugation {
+        Conjugation {
+            aspect: self.aspect,
+            tenses: self.tenses.clone(),
+            partner: None,
+        }
+    }
+}
+
+/// One row of a conjugation table. Packs list theirs in the manifest
+/// (`Inventory`).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TenseRow {
+    pub key: Cow<'static, str>,
+    pub label: Cow<'static, str>,
+    /// Heading the row sits under: a Georgian series, or "Tense".
     #[serde(default)]
-    pub vocative: CaseForms,
+    pub group: Cow<'static, str>,
 }
 
-impl Declension {
-    /// Borrow the forms for a given case.
-    pub fn forms(&self, case: GeoCase) -> &CaseForms {
-        match case {
-            GeoCase::Nominative => &self.nominative,
-            GeoCase::Ergative => &self.ergative,
-            GeoCase::Dative => &self.dative,
-            GeoCase::Genitive => &self.genitive,
-            GeoCase::Instrumental => &self.instrumental,
-            GeoCase::Adverbial => &self.adverbial,
-            GeoCase::Vocative => &self.vocative,
+const fn row(key: &'static str, label: &'static str, group: &'static str) -> TenseRow {
+    TenseRow {
+        key: Cow::Borrowed(key),
+        label: Cow::Borrowed(label),
+        group: Cow::Borrowed(group),
+    }
+}
+
+/// The eleven Georgian screeves, by series. Series III ("perfect") verbs invert:
+/// the logical subject is marked like an indirect object.
+static GEORGIAN_SCREEVES: [TenseRow; 11] = [
+    row("present", "Present", "Series I"),
+    row("imperfect", "Imperfect", "Series I"),
+    row("present_subjunctive", "Present subjunctive", "Series I"),
+    row("future", "Future", "Series I"),
+    row("conditional", "Conditional", "Series I"),
+    row("future_subjunctive", "Future subjunctive", "Series I"),
+    row("aorist", "Aorist", "Series II"),
+    row("optative", "Optative", "Series II"),
+    row("perfect", "Perfect", "Series III"),
+    row("pluperfect", "Pluperfect", "Series III"),
+    row("perfect_subjunctive", "Perfect subjunctive", "Series III"),
+];
+
+/// Russian has three tenses; which exist depends on aspect (a perfective verb
+/// has no present, and its "present" endings are the future).
+static RUSSIAN_TENSES: [TenseRow; 3] = [
+    row("present", "Present", "Tense"),
+    row("past", "Past", "Tense"),
+    row("future", "Future", "Tense"),
+];
+
+/// The built-in conjugation rows for a shipped language, in table order;
+/// empty if none. The pack's `Inventory` falls back to them.
+pub fn tense_rows(lang: &str) -> &'static [TenseRow] {
+    match lang {
+        "georgian" => &GEORGIAN_SCREEVES,
+        "russian" => &RUSSIAN_TENSES,
+        _ => &[],
+    }
+}
+
+/// What a language's tables are made of: its cases, its tenses and the
+/// pronouns heading the conjugation columns. A pack declares these in its
+/// manifest; whatever it leaves out comes from the built-in tables.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Inventory {
+    /// Declension rows, in table order.
+    pub cases: Vec<CaseRow>,
+    /// Conjugation rows, in table order.
+    pub tenses: Vec<TenseRow>,
+    /// Subject pronouns, in `Person::ALL` order.
+    pub pronouns: Vec<String>,
+}
+
+impl Inventory {
+    /// The built-in tables for `lang` (all empty for an unknown language).
+    pub fn builtin(lang: &str) -> Self {
+        Inventory {
+            cases: case_rows(lang).to_vec(),
+            tenses: tense_rows(lang).to_vec(),
+            pronouns: pronouns(lang).iter().map(|p| p.to_string()).collect(),
         }
     }
 
-    /// True if at least one cell is non-empty (gate for showing the table).
-    pub fn any_filled(&self) -> bool {
-        GeoCase::ALL.iter().any(|&c| !self.forms(c).is_empty())
+    /// `self`, with anything left empty taken from the built-in tables.
+    pub fn or_builtin(mut self, lang: &str) -> Self {
+        let builtin = Inventory::builtin(lang);
+        if self.cases.is_empty() {
+            self.cases = builtin.cases;
+        }
+        if self.tenses.is_empty() {
+            self.tenses = builtin.tenses;
+        }
+        if self.pronouns.is_empty() {
+            self.pronouns = builtin.pronouns;
+        }
+        self
+    }
+
+    /// The English name of the case stored under `key`, else the key.
+    pub fn case_label<'a>(&'a self, key: &'a str) -> &'a str {
+        self.cases
+            .iter()
+            .find(|r| r.key == key)
+            .map_or(key, |r| &r.label)
+    }
+
+    /// The subject pronoun heading `p`'s column, else its short key.
+    pub fn pronoun(&self, p: Person) -> &str {
+        self.pronouns
+            .get(p as usize)
+            .map_or(p.key(), String::as_str)
+    }
+
+    /// Problems a pack author should hear about; empty means it's fine.
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let mut seen = BTreeSet::new();
+        for key in self.cases.iter().map(|r| &r.key) {
+            if !seen.insert(key.as_ref()) {
+                problems.push(format!("cases list \"{key}\" twice"));
+            }
+        }
+        let mut seen = BTreeSet::new();
+        for key in self.tenses.iter().map(|r| &r.key) {
+            if !seen.insert(key.as_ref()) {
+                problems.push(format!("tenses list \"{key}\" twice"));
+            }
+        }
+        if !self.pronouns.is_empty() && self.pronouns.len() != Person::ALL.len() {
+            problems.push(format!(
+                "pronouns has {} entries, not one per person ({})",
+                self.pronouns.len(),
+                Person::ALL.len()
+            ));
+        }
+        problems
     }
 }
 
@@ -213,6 +726,18 @@ impl Lexicon {
         })
     }
 
+    /// Complete every entry's declension with `models::inflect`'s generated
+    /// forms, keeping hand-authored cells.
+    pub fn fill_declensions(&mut self, lang: &str) {
+        for e in &mut self.entries {
+            let Some(generated) = super::inflect::declension(lang, e) else {
+                continue;
+            };
+            let d = e.declension.get_or_insert_with(Declension::default);
+            e.declension_generated |= d.fill_from(&generated);
+        }
+    }
+
     pub fn len(&self) -> usize {
         self.entries.len()
     }
@@ -286,33 +811,124 @@ impl Lexicon {
         self.entries.iter().map(|e| e.rank).max().unwrap_or(1)
     }
 
-    /// Case-insensitive substring search over L2 head form and L1 gloss.
-    /// This is the dictionary-lookup entry point. Exact head-form matches sort
-    /// first, then by rank.
-    pub fn search(&self, query: &str) -> Vec<LexEntry> {
-        let q = query.trim().to_lowercase();
-        if q.is_empty() {
-            return Vec::new();
-        }
-        let mut hits: Vec<LexEntry> = self
-            .entries
-            .iter()
-            .filter(|e| e.word.to_lowercase().contains(&q) || e.en.to_lowercase().contains(&q))
-            .cloned()
-            .collect();
-        hits.sort_by(|a, b| {
-            let a_exact = (a.word.to_lowercase() == q) as u8;
-            let b_exact = (b.word.to_lowercase() == q) as u8;
-            b_exact.cmp(&a_exact).then(a.rank.cmp(&b.rank))
-        });
-        hits
-    }
-
     /// First entry whose L2 head form matches exactly (case-insensitive).
     pub fn get(&self, lemma: &str) -> Option<&LexEntry> {
         let l = lemma.to_lowercase();
         self.entries.iter().find(|e| e.word.to_lowercase() == l)
     }
+
+    /// Lay the user's overlay over the seeded entries: deleted words go, edits
+    /// replace the editable fields of the word they edit (rank, tables and
+    /// audio stay the pack's), and added words join after the last seeded
+    /// rank. An edit whose seeded word a newer pack no longer has is kept as
+    /// an added word.
+    pub fn apply_user(&mut self, user: &UserLexicon) {
+        self.entries.retain(|e| !user.deleted.contains(&e.word));
+        let mut next_rank = self.entries.iter().map(|e| e.rank).max().unwrap_or(0) + 1;
+        for u in &user.entries {
+            let seeded = u
+                .edits
+                .as_ref()
+                .and_then(|w| self.entries.iter_mut().find(|e| !e.user_added && e.word == *w));
+            match seeded {
+                Some(e) => u.write_to(e),
+                None => {
+                    let mut e = LexEntry {
+                        rank: next_rank,
+                        user_added: true,
+                        ..Default::default()
+                    };
+                    u.write_to(&mut e);
+                    self.entries.push(e);
+                    next_rank += 1;
+                }
+            }
+        }
+    }
+}
+
+// ─── user overlay ──────────────────────────────────────────────────────────
+
+/// A word the user added, or their edit of a seeded one.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserEntry {
+    /// Head word of the seeded entry this edits; `None` for an added word.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edits: Option<String>,
+    pub word: String,
+    #[serde(default, alias = "en", deserialize_with = "senses_or_gloss")]
+    pub senses: Vec<Sense>,
+    #[serde(default)]
+    pub pos: Option<String>,
+    #[serde(default)]
+    pub example: Option<String>,
+}
+
+impl UserEntry {
+    fn write_to(&self, e: &mut LexEntry) {
+        e.word = self.word.clone();
+        e.senses = self.senses.clone();
+        e.pos = self.pos.clone();
+        e.example = self.example.clone();
+    }
+}
+
+/// The user's changes to one language's lexicon, kept apart from the pack so
+/// a pack upgrade doesn't wipe them. Seeded words are referred to by head
+/// word, not rank, since ranks can shift between pack versions.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserLexicon {
+    #[serde(default)]
+    pub entries: Vec<UserEntry>,
+    /// Head words of seeded entries the user deleted.
+    #[serde(default)]
+    pub deleted: Vec<String>,
+}
+
+impl UserLexicon {
+    /// Add a new word (replacing an added word with the same head).
+    pub fn add(&mut self, mut entry: UserEntry) {
+        entry.edits = None;
+        self.entries
+            .retain(|u| u.edits.is_some() || u.word != entry.word);
+        self.entries.push(entry);
+    }
+
+    /// Save `entry` as the new content of `shown`, an entry of the merged
+    /// lexicon: a seeded word gets (or updates) an edit, an added word is
+    /// replaced.
+    pub fn edit(&mut self, shown: &LexEntry, mut entry: UserEntry) {
+        let previous = self.take(shown);
+        entry.edits = match previous {
+            Some(u) => u.edits,
+            None if shown.user_added => None,
+            None => Some(shown.word.clone()),
+        };
+        self.entries.push(entry);
+    }
+
+    /// Remove `shown` from the merged lexicon. A seeded word stays deleted.
+    pub fn delete(&mut self, shown: &LexEntry) {
+        let seeded = match self.take(shown) {
+            Some(u) => u.edits,
+            None if shown.user_added => None,
+            None => Some(shown.word.clone()),
+        };
+        if let Some(w) = seeded {
+            if !self.deleted.contains(&w) {
+                self.deleted.push(w);
+            }
+        }
+    }
+
+    /// Pull out the overlay entry that produced `shown`, if any.
+    fn take(&mut self, shown: &LexEntry) -> Option<UserEntry> {
+        let i = self
+            .entries
+            .iter()
+            .position(|u| u.word == shown.word && u.edits.is_none() == shown.user_added)?;
+        Some(self.entries.remove(i))
+    }
 }
 
 #[cfg(test)]
@@ -323,7 +939,7 @@ mod tests {
         LexEntry {
             rank,
             word: word.into(),
-            en: en.into(),
+            senses: Sense::parse(en),
             pos: pos.map(str::to_string),
             ..Default::default()
         }
@@ -349,11 +965,6 @@ mod tests {
         assert_eq!(fixture().within_rank(3, 2).len(), 2);
     }
 
-    #[test]
-    fn search_prefers_exact_head_form() {
-        assert_eq!(fixture().search("ის").first().unwrap().word, "ის");
-    }
-
     #[test]
     fn pos_tags_are_sorted_and_distinct() {
         assert_eq!(
@@ -362,6 +973,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn user_overlay_adds_edits_and_deletes_by_head_word() {
+        let user_entry = |word: &str, en: &str| UserEntry {
+            word: word.into(),
+            senses: Sense::parse(en),
+            ..Default::default()
+        };
+        let mut user = UserLexicon::default();
+        let seeded = fixture();
+        let hello = seeded.get("გამარჯობა").unwrap().clone();
+        user.edit(&hello, user_entry("გამარჯობათ", "hello (polite)"));
+        user.delete(seeded.get("ის").unwrap());
+        user.add(user_entry("ფანჯარა", "window"));
+
+        let mut lex = fixture();
+        lex.apply_user(&user);
+        assert!(lex.get("ის").is_none());
+        let edited = lex.get("გამარჯობათ").unwrap();
+        assert_eq!((edited.rank, edited.user_added), (1, false));
+        let added = lex.get("ფანჯარა").unwrap();
+        assert_eq!((added.rank, added.user_added), (3, true));
+
+        // editing the merged entries again replaces, never duplicates
+        user.edit(edited, user_entry("გამარჯობა", "hello"));
+        user.edit(added, user_entry("ფანჯარა", "window, pane"));
+        user.delete(&LexEntry {
+            word: "ფანჯარა".into(),
+            user_added: true,
+            ..Default::default()
+        });
+        assert_eq!(user.entries.len(), 1);
+        assert_eq!(user.entries[0].edits.as_deref(), Some("გამარჯობა"));
+        assert_eq!(user.deleted, ["ის"]);
+
+        // survives a pack upgrade that renumbers the seeded list
+        let json = serde_json::to_string(&user).unwrap();
+        let mut upgraded = Lexicon::from_entries(vec![e(7, "გამარჯობა", "hi", None)]);
+        upgraded.apply_user(&serde_json::from_str(&json).unwrap());
+        assert_eq!(upgraded.get("გამარჯობა").unwrap().rank, 7);
+        assert_eq!(upgraded.len(), 1);
+    }
+
+    #[test]
+    fn old_one_line_glosses_become_senses() {
+        let json = r#"[
+            {"rank":1,"en":"table, desk; board (of directors, food)","word":"стол"},
+            {"rank":2,"word":"лицо","senses":[
+                {"glosses":["face"]},
+                {"glosses":["person"],"note":"official","examples":["юридическое лицо"]}
+            ]}
+        ]"#;
+        let lex = Lexicon::from_json(json).unwrap();
+        let table = &lex.all()[0];
+        assert_eq!(table.senses.len(), 2);
+        assert_eq!(table.senses[0].glosses, ["table", "desk"]);
+        assert_eq!(table.senses[1].glosses, ["board (of directors, food)"]);
+        assert_eq!(table.gloss(), "table, desk; board (of directors, food)");
+        assert_eq!(lex.all()[1].senses[1].note.as_deref(), Some("official"));
+    }
+
     #[test]
     fn from_json_parses_minimal_entries() {
         let json = r#"[{"rank":1,"en":"hello","word":"გამარჯობა","pos":"interjection"}]"#;
@@ -378,10 +1049,66 @@ mod tests {
         ]"#;
         let lex = Lexicon::from_json(json).unwrap();
         let d = lex.all()[0].declension.clone().unwrap();
-        assert_eq!(d.nominative.singular, "კაცი");
-        assert_eq!(d.nominative.plural, "კაცები");
-        assert!(d.nominative.with_noun.is_empty());
-        assert!(d.dative.is_empty());
+        let nom = d.forms("nominative").unwrap();
+        assert_eq!(nom.singular, "კაცი");
+        assert_eq!(nom.plural, "კაცები");
+        assert!(nom.with_noun.is_empty());
+        assert!(d.forms("dative").is_none());
         assert!(d.any_filled());
+        assert_eq!(d.columns(), vec![Column::Singular, Column::Plural]);
+    }
+
+    #[test]
+    fn fill_from_keeps_authored_cells() {
+        let mut d = Declension::default();
+        d.forms_mut("genitive").plural = "людей".into();
+        let mut generated = Declension::default();
+        generated.forms_mut("genitive").plural = "человеков".into();
+        generated.forms_mut("genitive").singular = "человека".into();
+        assert!(d.fill_from(&generated));
+        let g = d.forms("genitive").unwrap();
+        assert_eq!((g.singular.as_str(), g.plural.as_str()), ("человека", "людей"));
+        assert!(!d.fill_from(&generated));
+        assert_eq!(case_rows("russian").len(), 6);
+    }
+
+    #[test]
+    fn conjugation_parses_with_an_aspect_partner() {
+        let json = r#"[
+            {"rank":1,"en":"to read","word":"читать","pos":"verb",
+             "conjugation":{"aspect":"imperfective",
+               "tenses":{"present":{"1sg":"читаю","3pl":"читают"}},
+               "partner":{"word":"прочитать","aspect":"perfective",
+                 "tenses":{"future":{"1sg":"прочитаю"}}}}}
+        ]"#;
+        let lex = Lexicon::from_json(json).unwrap();
+        let c = lex.all()[0].conjugation.clone().unwrap();
+        assert_eq!(c.aspect, Some(Aspect::Imperfective));
+        assert_eq!(c.form("present", Person::Pl3), Some("читают"));
+        assert_eq!(c.form("present", Person::Sg2), None);
+        let pf = c.partner.unwrap().conjugation();
+        assert_eq!(pf.form("future", Person::Sg1), Some("прочитаю"));
+        assert_eq!(tense_rows("georgian").len(), 11);
+    }
+
+    #[test]
+    fn a_declared_inventory_wins_and_gaps_fall_back() {
+        let json = r#"{"cases": [{"key": "nominative", "label": "Nominative"},
+                                 {"key": "ergative", "label": "Ergative"}]}"#;
+        let inv: Inventory = serde_json::from_str(json).unwrap();
+        let inv = inv.or_builtin("georgian");
+        assert_eq!(inv.case_label("ergative"), "Ergative");
+        assert_eq!(inv.case_label("dative"), "dative");
+        assert_eq!(inv.tenses, tense_rows("georgian"));
+        assert_eq!(inv.pronoun(Person::Pl1), "ჩვენ");
+        assert!(inv.validate().is_empty());
+
+        let bare = Inventory::builtin("klingon");
+        assert_eq!(bare.pronoun(Person::Sg2), "2sg");
+        let short = Inventory {
+            pronouns: vec!["I".into()],
+            ..Inventory::default()
+        };
+        assert_eq!(short.validate().len(), 1);
     }
 }
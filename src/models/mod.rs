@@ -1,2 +1,6 @@
+pub mod inflect;
+pub mod lemma;
 pub mod letter;
 pub mod lexicon;
+pub mod text;
+pub mod translit;
@@ -0,0 +1,58 @@
+// src/models/text.rs
+//
+// Plain-text helpers shared by the drills, the dictionary search and the
+// reading pipeline: splitting a sentence into words, and edit distance.
+
+/// Levenshtein edit distance over characters (script-agnostic).
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.is_empty() {
+        return b.len();
+    }
+    if b.is_empty() {
+        return a.len();
+    }
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0usize; b.len() + 1];
+    for (i, &ca) in a.iter().enumerate() {
+        cur[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            cur[j + 1] = (prev[j + 1] + 1).min(cur[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
+/// Tokenize an L2 sentence into word surfaces, stripping surrounding punctuation
+/// but keeping letters/marks. Works for Georgian, Cyrillic, and Latin.
+pub fn tokenize(sentence: &str) -> Vec<String> {
+    sentence
+        .split(|c: char| c.is_whitespace())
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()).to_string())
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distance_basics() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("same", "same"), 0);
+    }
+
+    #[test]
+    fn tokenize_strips_punctuation() {
+        assert_eq!(
+            tokenize("კაცი ქუჩაში დადიოდა."),
+            vec!["კაცი", "ქუჩაში", "დადიოდა"]
+        );
+        assert_eq!(tokenize("  hello,  world!  "), vec!["hello", "world"]);
+    }
+}
@@ -0,0 +1,151 @@
+// src/models/translit.rs
+//
+// Latin spellings of head words, so a learner who can't type the script yet
+// can still look a word up. Georgian uses the national romanization (2002,
+// apostrophes marking ejectives: კაცი → k'atsi); Russian gets both the
+// scientific transliteration (щука → ščuka) and GOST 7.79 System B
+// (щука → shhuka), since people type either.
+//
+// Matching goes through `fold`, which drops the apostrophes, primes and
+// diacritics most people leave out anyway, so "katsi", "scuka" and "shhuka"
+// all find their word.
+
+/// Georgian national romanization.
+const GEORGIAN: [(char, &str); 33] = [
+    ('ა', "a"),
+    ('ბ', "b"),
+    ('გ', "g"),
+    ('დ', "d"),
+    ('ე', "e"),
+    ('ვ', "v"),
+    ('ზ', "z"),
+    ('თ', "t"),
+    ('ი', "i"),
+    ('კ', "k'"),
+    ('ლ', "l"),
+    ('მ', "m"),
+    ('ნ', "n"),
+    ('ო', "o"),
+    ('პ', "p'"),
+    ('ჟ', "zh"),
+    ('რ', "r"),
+    ('ს', "s"),
+    ('ტ', "t'"),
+    ('უ', "u"),
+    ('ფ', "p"),
+    ('ქ', "k"),
+    ('ღ', "gh"),
+    ('ყ', "q'"),
+    ('შ', "sh"),
+    ('ჩ', "ch"),
+    ('ც', "ts"),
+    ('ძ', "dz"),
+    ('წ', "ts'"),
+    ('ჭ', "ch'"),
+    ('ხ', "kh"),
+    ('ჯ', "j"),
+    ('ჰ', "h"),
+];
+
+/// Russian: (letter, scientific, GOST 7.79 System B).
+const RUSSIAN: [(char, &str, &str); 33] = [
+    ('а', "a", "a"),
+    ('б', "b", "b"),
+    ('в', "v", "v"),
+    ('г', "g", "g"),
+    ('д', "d", "d"),
+    ('е', "e", "e"),
+    ('ё', "ë", "yo"),
+    ('ж', "ž", "zh"),
+    ('з', "z", "z"),
+    ('и', "i", "i"),
+    ('й', "j", "j"),
+    ('к', "k", "k"),
+    ('л', "l", "l"),
+    ('м', "m", "m"),
+    ('н', "n", "n"),
+    ('о', "o", "o"),
+    ('п', "p", "p"),
+    ('р', "r", "r"),
+    ('с', "s", "s"),
+    ('т', "t", "t"),
+    ('у', "u", "u"),
+    ('ф', "f", "f"),
+    ('х', "x", "x"),
+    ('ц', "c", "cz"),
+    ('ч', "č", "ch"),
+    ('ш', "š", "sh"),
+    ('щ', "šč", "shh"),
+    ('ъ', "ʺ", "``"),
+    ('ы', "y", "y`"),
+    ('ь', "ʹ", "`"),
+    ('э', "è", "e`"),
+    ('ю', "ju", "yu"),
+    ('я', "ja", "ya"),
+];
+
+/// Lowercase, drop stress marks, and strip what people skip when typing
+/// Latin: apostrophes, primes, backticks and diacritics (`č` → `c`).
+/// Script text passes through apart from case and stress.
+pub fn fold(s: &str) -> String {
+    s.trim()
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter_map(|c| match c {
+            '\u{301}' | '\'' | '’' | 'ʼ' | '`' | 'ʹ' | 'ʺ' => None,
+            'č' => Some('c'),
+            'š' => Some('s'),
+            'ž' => Some('z'),
+            'ë' | 'è' => Some('e'),
+            c => Some(c),
+        })
+        .collect()
+}
+
+fn spell(word: &str, letter: impl Fn(char) -> Option<&'static str>) -> String {
+    word.chars()
+        .flat_map(char::to_lowercase)
+        .filter(|&c| c != '\u{301}')
+        .map(|c| letter(c).map_or_else(|| c.to_string(), str::to_string))
+        .collect()
+}
+
+/// `word` in each of the language's romanization schemes, most common first;
+/// empty for a language without one.
+pub fn romanize(lang: &str, word: &str) -> Vec<String> {
+    match lang {
+        "georgian" => vec![spell(word, |c| {
+            GEORGIAN.iter().find(|(l, _)| *l == c).map(|(_, r)| *r)
+        })],
+        "russian" => vec![
+            spell(word, |c| {
+                RUSSIAN.iter().find(|(l, ..)| *l == c).map(|(_, s, _)| *s)
+            }),
+            spell(word, |c| {
+                RUSSIAN.iter().find(|(l, ..)| *l == c).map(|(_, _, g)| *g)
+            }),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn georgian_national_romanization_and_its_fold() {
+        assert_eq!(romanize("georgian", "კაცი"), ["k'atsi"]);
+        assert_eq!(romanize("georgian", "წყალი"), ["ts'q'ali"]);
+        assert_eq!(fold(&romanize("georgian", "კაცი")[0]), "katsi");
+    }
+
+    #[test]
+    fn russian_scientific_and_gost() {
+        assert_eq!(romanize("russian", "Щу́ка"), ["ščuka", "shhuka"]);
+        assert_eq!(romanize("russian", "объём"), ["obʺëm", "ob``yom"]);
+        assert_eq!(fold("ščuka"), "scuka");
+        assert_eq!(fold("лицо́"), "лицо");
+        assert!(romanize("english", "word").is_empty());
+    }
+}
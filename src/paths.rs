@@ -5,8 +5,12 @@
 //!
 //! Use this everywhere instead of hand-rolling $HOME paths, so a Windows build
 //! actually finds a place to write.
+//!
+//! `write_atomic` is the shared way to replace a file there: every subsystem
+//! that saves a whole file goes through it.
 
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 pub fn data_root() -> Option<PathBuf> {
     #[cfg(windows)]
@@ -21,3 +25,40 @@ pub fn data_root() -> Option<PathBuf> {
             .map(|base| base.join("lang-sprint"))
     }
 }
+
+/// Write `contents` via a temp file + rename, so a crash mid-write never
+/// leaves a truncated file behind. Creates parent dirs as needed.
+pub fn write_atomic(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path)
+}
+
+/// A scratch directory for tests, unique to this process and `name`, emptied
+/// on creation and removed on drop.
+#[cfg(test)]
+pub struct TestDir(PathBuf);
+
+#[cfg(test)]
+impl TestDir {
+    pub fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("lang-sprint-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        TestDir(dir)
+    }
+
+    pub fn join(&self, p: impl AsRef<Path>) -> PathBuf {
+        self.0.join(p)
+    }
+}
+
+#[cfg(test)]
+impl Drop for TestDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
@@ -0,0 +1,508 @@
+//! Dictionary lookup for the navbar search, over an inverted index built once
+//! per lexicon. Every searchable text of an entry — head words, their
+//! romanizations (`models::translit`), declension cells, glosses and example
+//! sentences — is split into words, folded (`translit::fold`) and indexed, so
+//! a keystroke is a few map lookups plus a prefix range scan rather than a
+//! pass over every entry. Query words all have to match within one text; any
+//! of them may be a prefix.
+//!
+//! When that leaves room in the results, a query word can also match within a
+//! few typos (`text::levenshtein`), tried only against the words that
+//! share a letter pair with it, and a single inflected word the tables don't
+//! list goes through the lemmatizer's guessing pass. Hits rank by how they
+//! matched, then by field (examples last), then by frequency, and carry the
+//! matched text split into runs for highlighting.
+//!
+//! Its text helpers (`models::text`) are the ones the drills use. The index
+//! itself sits at the crate root rather than in `models`, which the pack
+//! validator shares by path and has no use for a search index.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::models::lemma::Lemmatizer;
+use crate::models::lexicon::{CaseRow, Column, LexEntry, Lexicon};
+use crate::models::text::{levenshtein, tokenize};
+use crate::models::translit::{fold, romanize};
+
+/// Which part of an entry a query matched, in ranking order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Field {
+    Head,
+    Romanized,
+    Form,
+    Gloss,
+    Example,
+}
+
+impl Field {
+    pub fn label(self) -> &'static str {
+        match self {
+            Field::Head => "head word",
+            Field::Romanized => "spelled",
+            Field::Form => "form",
+            Field::Gloss => "meaning",
+            Field::Example => "example",
+        }
+    }
+}
+
+/// How a query matched, best first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Match {
+    Exact,
+    Prefix,
+    /// An inflected form the lemmatizer guessed back to the head word.
+    Guess,
+    /// Within this many edits.
+    Typo(usize),
+}
+
+/// A search result: the entry, and which of its texts matched and how.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hit {
+    pub entry: LexEntry,
+    pub field: Field,
+    pub how: Match,
+    /// Context shown before the text, not searched ("Genitive · plural").
+    pub label: String,
+    /// The matched text in runs; `true` runs are the words that matched.
+    pub parts: Vec<(String, bool)>,
+}
+
+/// One searchable text of an entry.
+#[derive(Clone, Debug, PartialEq)]
+struct Text {
+    entry: usize,
+    field: Field,
+    label: String,
+    body: String,
+}
+
+/// Matching texts: how each matched, and the indexed words it matched on.
+type Found = HashMap<usize, (Match, Vec<String>)>;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Searcher {
+    entries: Vec<LexEntry>,
+    texts: Vec<Text>,
+    /// Folded word -> the texts it occurs in, ascending.
+    index: BTreeMap<String, Vec<usize>>,
+    /// The index's words in order, and which of them contain each letter pair.
+    vocab: Vec<String>,
+    grams: HashMap<(char, char), Vec<usize>>,
+    lemmatizer: Lemmatizer,
+}
+
+/// Typos tolerated in a query word of this many letters; short words get
+/// none, or every three-letter word would match.
+fn typos_allowed(q: &str) -> usize {
+    match q.chars().count() {
+        0..=3 => 0,
+        4..=6 => 1,
+        7..=9 => 2,
+        _ => 3,
+    }
+}
+
+/// Edit distance from `q` to `key`, or to the start of `key` when the query
+/// looks like a misspelled prefix.
+fn distance(q: &str, key: &str) -> usize {
+    let n = q.chars().count();
+    let head: String = key.chars().take(n).collect();
+    levenshtein(q, key).min(levenshtein(q, &head))
+}
+
+/// Adjacent letter pairs of `term`.
+fn bigrams(term: &str) -> impl Iterator<Item = (char, char)> + '_ {
+    term.chars().zip(term.chars().skip(1))
+}
+
+/// The folded words of `text`, as indexed.
+fn terms(text: &str) -> Vec<String> {
+    tokenize(text)
+        .iter()
+        .map(|w| fold(w))
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+/// `body` split into runs, with the words whose folded form is in `matched`
+/// marked. Stress marks stay with their word.
+fn highlight(body: &str, matched: &[String]) -> Vec<(String, bool)> {
+    let mut parts: Vec<(String, bool)> = Vec::new();
+    let mut push = |s: &str, on: bool| {
+        if s.is_empty() {
+            return;
+        }
+        match parts.last_mut() {
+            Some((prev, was)) if *was == on => prev.push_str(s),
+            _ => parts.push((s.to_string(), on)),
+        }
+    };
+    let is_word = |c: char| c.is_alphanumeric() || c == '\u{301}';
+    let mut rest = body;
+    while !rest.is_empty() {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (chunk, after) = rest.split_at(end);
+        let lead = chunk.find(is_word).unwrap_or(chunk.len());
+        let tail = chunk.rfind(is_word).map_or(lead, |i| {
+            i + chunk[i..].chars().next().map_or(0, char::len_utf8)
+        });
+        let core = &chunk[lead..tail];
+        push(&chunk[..lead], false);
+        push(core, !core.is_empty() && matched.contains(&fold(core)));
+        push(&chunk[tail..], false);
+        let space = after.len() - after.trim_start().len();
+        push(&after[..space], false);
+        rest = &after[space..];
+    }
+    parts
+}
+
+impl Searcher {
+    pub fn new(lang: &str, cases: &[CaseRow], lexicon: &Lexicon) -> Self {
+        let entries = lexicon.all().to_vec();
+        let mut texts: Vec<Text> = Vec::new();
+        for (i, e) in entries.iter().enumerate() {
+            let mut add = |field: Field, label: String, body: &str| {
+                if !body.trim().is_empty() {
+                    texts.push(Text {
+                        entry: i,
+                        field,
+                        label,
+                        body: body.trim().to_string(),
+                    });
+                }
+            };
+            for head in e.word.split('/') {
+                add(Field::Head, String::new(), head);
+                for r in romanize(lang, head.trim()) {
+                    add(Field::Romanized, String::new(), &r);
+                }
+            }
+            if let Some(d) = &e.declension {
+                for row in cases {
+                    let Some(f) = d.forms(&row.key) else { continue };
+                    for column in Column::ALL {
+                        let label =
+                            format!("{} · {}", row.label, column.label().to_lowercase());
+                        add(Field::Form, label, column.of(f));
+                    }
+                }
+            }
+            for s in &e.senses {
+                add(Field::Gloss, String::new(), &s.gloss());
+                for ex in &s.examples {
+                    add(Field::Example, String::new(), ex);
+                }
+            }
+            if let Some(ex) = &e.example {
+                add(Field::Example, String::new(), ex);
+            }
+        }
+
+        let mut index: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for (t, text) in texts.iter().enumerate() {
+            for term in terms(&text.body) {
+                let postings = index.entry(term).or_default();
+                if postings.last() != Some(&t) {
+                    postings.push(t);
+                }
+            }
+        }
+        let vocab: Vec<String> = index.keys().cloned().collect();
+        let mut grams: HashMap<(char, char), Vec<usize>> = HashMap::new();
+        for (id, term) in vocab.iter().enumerate() {
+            for g in bigrams(term) {
+                let ids = grams.entry(g).or_default();
+                if ids.last() != Some(&id) {
+                    ids.push(id);
+                }
+            }
+        }
+        Self {
+            entries,
+            texts,
+            index,
+            vocab,
+            grams,
+            lemmatizer: Lemmatizer::new(lang, lexicon),
+        }
+    }
+
+    /// Indexed words that could be within `allowed` typos of `q` or of its
+    /// start. Each typo spoils at most two of the query's letter pairs, and
+    /// `typos_allowed` leaves at least one intact, so a word sharing none of
+    /// them can't match; neither can one too short to reach `q`.
+    fn typo_candidates(&self, q: &str, allowed: usize) -> Vec<&str> {
+        let n = q.chars().count();
+        let mut ids: Vec<usize> = bigrams(q)
+            .filter_map(|g| self.grams.get(&g))
+            .flatten()
+            .copied()
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids.into_iter()
+            .map(|id| self.vocab[id].as_str())
+            .filter(|term| term.chars().count() + allowed >= n)
+            .collect()
+    }
+
+    /// Texts with a word matching query word `q`: how, and the indexed word.
+    /// Typos are only tried when `fuzzy`, and only find what the exact and
+    /// prefix pass didn't.
+    fn lookup(&self, q: &str, fuzzy: bool) -> HashMap<usize, (Match, String)> {
+        let mut found: HashMap<usize, (Match, String)> = HashMap::new();
+        let mut note = |term: &str, how: Match, postings: &[usize]| {
+            for &t in postings {
+                let slot = found.entry(t).or_insert((how, term.to_string()));
+                if how < slot.0 {
+                    *slot = (how, term.to_string());
+                }
+            }
+        };
+        for (term, postings) in self.index.range(q.to_string()..) {
+            if !term.starts_with(q) {
+                break;
+            }
+            let how = if term == q {
+                Match::Exact
+            } else {
+                Match::Prefix
+            };
+            note(term, how, postings);
+        }
+        let allowed = typos_allowed(q);
+        if fuzzy && allowed > 0 {
+            for term in self.typo_candidates(q, allowed) {
+                let d = distance(q, term);
+                if d > 0 && d <= allowed {
+                    note(term, Match::Typo(d), &self.index[term]);
+                }
+            }
+        }
+        found
+    }
+
+    /// Texts matching every word of the folded query; a text matches as
+    /// well as its worst-matching word.
+    fn matching(&self, words: &[String], fuzzy: bool) -> Found {
+        let mut out: Option<Found> = None;
+        for w in words {
+            let found = self.lookup(w, fuzzy);
+            out = Some(match out {
+                None => found
+                    .into_iter()
+                    .map(|(t, (how, term))| (t, (how, vec![term])))
+                    .collect(),
+                Some(prev) => prev
+                    .into_iter()
+                    .filter_map(|(t, (how, mut terms))| {
+                        let (h, term) = found.get(&t)?;
+                        terms.push(term.clone());
+                        Some((t, (how.max(*h), terms)))
+                    })
+                    .collect(),
+            });
+        }
+        out.unwrap_or_default()
+    }
+
+    fn entries_in(&self, found: &Found) -> usize {
+        let mut e: Vec<usize> = found.keys().map(|&t| self.texts[t].entry).collect();
+        e.sort_unstable();
+        e.dedup();
+        e.len()
+    }
+
+    /// Entries matching `query`, best first; at most `limit` of them.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<Hit> {
+        let words = terms(query);
+        if words.is_empty() {
+            return Vec::new();
+        }
+        let mut found = self.matching(&words, false);
+        if self.entries_in(&found) < limit {
+            for (t, m) in self.matching(&words, true) {
+                found.entry(t).or_insert(m);
+            }
+        }
+        if let [word] = words.as_slice() {
+            for a in self.lemmatizer.analyze(query) {
+                if !a.guessed {
+                    continue;
+                }
+                let head = self.texts.iter().position(|t| {
+                    t.field == Field::Head && self.entries[t.entry].word == a.entry.word
+                });
+                if let Some(t) = head {
+                    found.entry(t).or_insert((Match::Guess, vec![word.clone()]));
+                }
+            }
+        }
+
+        // the best-matching text of each entry
+        let mut best: HashMap<usize, (Match, Field, usize, Vec<String>)> = HashMap::new();
+        for (t, (how, matched)) in found {
+            let text = &self.texts[t];
+            if best
+                .get(&text.entry)
+                .is_none_or(|(h, f, ..)| (how, text.field) < (*h, *f))
+            {
+                best.insert(text.entry, (how, text.field, t, matched));
+            }
+        }
+        let mut ranked: Vec<_> = best
+            .into_values()
+            .map(|(how, field, t, matched)| {
+                let rank = self.entries[self.texts[t].entry].rank;
+                ((field == Field::Example, how, field, rank, t), matched)
+            })
+            .collect();
+        ranked.sort_by_key(|r| r.0);
+        ranked
+            .into_iter()
+            .take(limit)
+            .map(|((_, how, field, _, t), matched)| {
+                let text = &self.texts[t];
+                Hit {
+                    entry: self.entries[text.entry].clone(),
+                    field,
+                    how,
+                    label: text.label.clone(),
+                    parts: highlight(&text.body, &matched),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::lexicon::{case_rows, Declension, Sense};
+
+    fn entry(rank: u32, word: &str, en: &str) -> LexEntry {
+        LexEntry {
+            rank,
+            word: word.to_string(),
+            senses: Sense::parse(en),
+            ..Default::default()
+        }
+    }
+
+    fn lexicon(words: &[(&str, &str)]) -> Lexicon {
+        Lexicon::from_entries(
+            words
+                .iter()
+                .enumerate()
+                .map(|(i, (word, en))| entry(i as u32 + 1, word, en))
+                .collect(),
+        )
+    }
+
+    fn first(s: &Searcher, q: &str) -> String {
+        s.search(q, 5)
+            .first()
+            .map(|h| h.entry.word.clone())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn search_prefers_exact_head_form() {
+        // the commoner word only starts with the query
+        let s = Searcher::new(
+            "georgian",
+            case_rows("georgian"),
+            &lexicon(&[("ისიც", "that too"), ("ის", "he/she/it")]),
+        );
+        assert_eq!(first(&s, "ის"), "ის");
+        assert_eq!(s.search("ის", 5)[0].how, Match::Exact);
+    }
+
+    #[test]
+    fn georgian_by_script_romanization_and_typo() {
+        let s = Searcher::new(
+            "georgian",
+            case_rows("georgian"),
+            &lexicon(&[
+                ("ის", "he/she/it"),
+                ("ისიც", "that too"),
+                ("კაცი", "man"),
+                ("ქალი", "woman"),
+            ]),
+        );
+        assert_eq!(first(&s, "ის"), "ის");
+        assert_eq!(first(&s, "k'atsi"), "კაცი");
+        assert_eq!(first(&s, "katsi"), "კაცი");
+        assert_eq!(first(&s, "katzi"), "კაცი");
+        assert_eq!(first(&s, "kal"), "ქალი");
+        assert!(s.search("zzzz", 5).is_empty());
+    }
+
+    #[test]
+    fn typos_are_only_tried_on_words_sharing_a_letter_pair() {
+        let s = Searcher::new(
+            "russian",
+            case_rows("russian"),
+            &lexicon(&[("стол", "table"), ("окно", "window"), ("столб", "pillar")]),
+        );
+        let tried = s.typo_candidates("stoll", 1);
+        assert!(tried.contains(&"stol") && tried.contains(&"stolb"));
+        assert!(!tried.contains(&"okno") && !tried.contains(&"window"));
+        assert_eq!(first(&s, "stoll"), "стол");
+    }
+
+    #[test]
+    fn russian_by_either_scheme_gloss_and_inflection() {
+        let s = Searcher::new(
+            "russian",
+            case_rows("russian"),
+            &lexicon(&[
+                ("хорошо́", "well, good"),
+                ("лицо́", "face; person"),
+                ("кни́га", "book"),
+            ]),
+        );
+        assert_eq!(first(&s, "xorosho"), "хорошо́");
+        assert_eq!(first(&s, "khorosho"), "хорошо́");
+        assert_eq!(first(&s, "лицо"), "лицо́");
+        assert_eq!(first(&s, "person"), "лицо́");
+        assert_eq!(first(&s, "книги"), "кни́га");
+    }
+
+    #[test]
+    fn finds_table_forms_and_examples_and_marks_the_match() {
+        let mut table = entry(1, "стол", "table");
+        let mut d = Declension::default();
+        d.forms_mut("prepositional").singular = "столе́".into();
+        table.declension = Some(d);
+        let mut house = entry(2, "дом", "house");
+        house.example = Some("Мой дом — на углу́.".into());
+        let s = Searcher::new(
+            "russian",
+            case_rows("russian"),
+            &Lexicon::from_entries(vec![table, house]),
+        );
+
+        let hit = &s.search("столе", 5)[0];
+        assert_eq!((hit.field, hit.how), (Field::Form, Match::Exact));
+        assert_eq!(hit.label, "Prepositional · singular");
+        assert_eq!(hit.parts, [("столе́".to_string(), true)]);
+
+        let hit = &s.search("угл", 5)[0];
+        assert_eq!((hit.field, hit.how), (Field::Example, Match::Prefix));
+        assert_eq!(
+            hit.parts,
+            [
+                ("Мой дом — на ".to_string(), false),
+                ("углу́".to_string(), true),
+                (".".to_string(), false),
+            ]
+        );
+        // every query word has to match within the same text
+        assert_eq!(s.search("мой угл", 5).len(), 1);
+        assert!(s.search("мой table", 5).is_empty());
+    }
+}
@@ -1,5 +1,6 @@
 //! App-wide settings: a tiny serde struct loaded from disk at boot and shared
-//! via context. Audio reads `volume`; the app boots into `default_language`.
+//! via context. Audio reads `volume`; the app boots into `default_language`;
+//! the on-screen keyboard draws `keyboard_layout`.
 //! Anything that needs a setting calls `use_settings()` and reads the field —
 //! the single source of truth. Saved to disk automatically on change.
 
@@ -18,6 +19,9 @@ pub struct Settings {
     pub default_language: String,
     #[serde(default)]
     pub tts_enabled: bool,
+    /// Physical keyboard profile id (`layouts::profiles()`), e.g. "iso".
+    #[serde(default = "default_keyboard_layout")]
+    pub keyboard_layout: String,
 }
 
 fn default_volume() -> f32 {
@@ -26,6 +30,9 @@ fn default_volume() -> f32 {
 fn default_language() -> String {
     "georgian".to_string()
 }
+fn default_keyboard_layout() -> String {
+    crate::layouts::DEFAULT_LAYOUT.to_string()
+}
 
 impl Default for Settings {
     fn default() -> Self {
@@ -33,6 +40,7 @@ impl Default for Settings {
             volume: default_volume(),
             default_language: default_language(),
             tts_enabled: false,
+            keyboard_layout: default_keyboard_layout(),
         }
     }
 }
@@ -1,22 +1,26 @@
 use dioxus::prelude::*;
 
-use crate::{assets::alphabet_json_for, components::Alphabet, models::letter::Letter};
+use crate::{
+    components::{Alphabet, LetterDrill},
+    langpack,
+};
 
 /// Route wrapper for the alphabet. Loads the active language's alphabet from
 /// context and hands it to the `Alphabet` component. Routes take no props, so
 /// the data-loading lives here while the rendering lives in the component.
+/// A toggle swaps the chart for the letter-sound drill.
 #[component]
 pub fn AlphabetPage() -> Element {
     let lang = use_context::<Signal<String>>();
+    let mut drilling = use_signal(|| false);
     let mut load_error = use_signal(|| None::<String>);
 
     let letters = use_resource(move || {
         let lang_name = lang.read().clone();
         async move {
-            match serde_json::from_str::<Vec<Letter>>(alphabet_json_for(&lang_name)) {
+            match langpack::alphabet(&lang_name) {
                 Ok(v) => v,
-                Err(e) => {
-                    let msg = format!("Failed to parse alphabet for {lang_name}: {e}");
+                Err(msg) => {
                     eprintln!("{msg}");
                     load_error.set(Some(msg));
                     Vec::new()
@@ -31,7 +35,23 @@ pub fn AlphabetPage() -> Element {
             if let Some(err) = load_error() {
                 div { class: "bg-red-900 text-red-200 px-4 py-2 text-sm text-center", "{err}" }
             }
-            Alphabet { letters: letters_vec.clone(), lang }
+            div { class: "flex justify-center gap-2 pt-4",
+                button {
+                    class: format!("px-3 py-1 rounded text-sm {}", if !drilling() { "bg-indigo-600 text-white" } else { "bg-gray-700 text-gray-300" }),
+                    onclick: move |_| drilling.set(false),
+                    "Chart"
+                }
+                button {
+                    class: format!("px-3 py-1 rounded text-sm {}", if drilling() { "bg-indigo-600 text-white" } else { "bg-gray-700 text-gray-300" }),
+                    onclick: move |_| drilling.set(true),
+                    "Drill"
+                }
+            }
+            if drilling() {
+                LetterDrill { letters: letters_vec.clone(), lang }
+            } else {
+                Alphabet { letters: letters_vec.clone(), lang }
+            }
         }
     }
 }
@@ -0,0 +1,309 @@
+// src/views/conjugate.rs
+//
+// Verb conjugation: paradigm tables for every verb whose lexicon entry carries
+// a `Conjugation`, and a drill that asks for one cell at a time (verb × tense ×
+// person), logging Skill::Grammar evidence per cell. Rows and pronouns come
+// from the pack's `Inventory` (manifest.json), so the same page serves Georgian
+// screeves and Russian aspect pairs.
+
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::components::drills::typing_engine::{accepted_answers, grade_answer};
+use crate::langpack::use_manifest;
+use crate::learner::{as_probe, conjugation_evidence, now_ms, use_learner};
+use crate::models::lexicon::{Conjugation, LexEntry, Person, TenseRow};
+
+/// One drillable cell.
+#[derive(Clone, PartialEq)]
+struct Cell {
+    /// The verb being conjugated — the entry's, or its aspect partner.
+    shown: String,
+    rank: u32,
+    en: String,
+    row: TenseRow,
+    person: Person,
+    form: String,
+}
+
+/// Every authored cell of `conj`, in table order.
+fn cells_of(
+    tenses: &[TenseRow],
+    e: &LexEntry,
+    shown: &str,
+    conj: &Conjugation,
+    out: &mut Vec<Cell>,
+) {
+    for row in tenses {
+        for p in Person::ALL {
+            if let Some(form) = conj.form(&row.key, p) {
+                out.push(Cell {
+                    shown: shown.to_string(),
+                    rank: e.rank,
+                    en: e.gloss(),
+                    row: row.clone(),
+                    person: p,
+                    form: form.to_string(),
+                });
+            }
+        }
+    }
+}
+
+fn all_cells(tenses: &[TenseRow], verbs: &[LexEntry]) -> Vec<Cell> {
+    let mut out = Vec::new();
+    for e in verbs {
+        let Some(c) = &e.conjugation else { continue };
+        cells_of(tenses, e, &e.word, c, &mut out);
+        if let Some(p) = &c.partner {
+            cells_of(tenses, e, &p.word, &p.conjugation(), &mut out);
+        }
+    }
+    out
+}
+
+#[component]
+pub fn ConjugatePage() -> Element {
+    let lang = use_context::<Signal<String>>();
+    let manifest = use_manifest();
+    let mut practice = use_signal(|| false);
+    let mut selected = use_signal(|| None::<String>);
+
+    let lex_res = crate::langpack::use_lexicon(lang);
+    let verbs = use_memo(move || {
+        let lexicon = lex_res.read().clone().unwrap_or_default();
+        lexicon
+            .by_rank()
+            .into_iter()
+            .filter(|e| e.conjugation.is_some())
+            .collect::<Vec<LexEntry>>()
+    });
+
+    let l = lang();
+    let list = verbs();
+    let current = selected()
+        .and_then(|w| list.iter().find(|e| e.word == w).cloned())
+        .or_else(|| list.first().cloned());
+    let practicing = practice();
+    let chips: Vec<(LexEntry, String)> = list.iter().map(|e| (e.clone(), e.gloss())).collect();
+
+    let tab = |on: bool| -> &'static str {
+        if on {
+            "px-3 py-1.5 rounded text-sm bg-indigo-600 text-white"
+        } else {
+            "px-3 py-1.5 rounded text-sm bg-gray-700 text-gray-300"
+        }
+    };
+
+    rsx! {
+        div { class: "min-h-screen bg-gray-800 text-white p-8",
+            h1 { style: "font-size:1.5rem; font-weight:600; text-align:center; margin-bottom:1rem;", "Conjugation" }
+
+            if !manifest().features.conjugations || list.is_empty() {
+                div { class: "text-center text-sm text-gray-400 py-10",
+                    "No conjugation tables for {manifest().name} yet."
+                }
+            } else {
+                div { class: "flex justify-center gap-2 mb-6",
+                    button { class: tab(!practicing), onclick: move |_| practice.set(false), "Tables" }
+                    button { class: tab(practicing), onclick: move |_| practice.set(true), "Practice" }
+                }
+
+                if practicing {
+                    ConjugationDrill { key: "{l}", lang: l.clone(), verbs: list.clone() }
+                } else {
+                    div { class: "flex flex-wrap justify-center gap-2 mb-6",
+                        for (e, gloss) in chips {
+                            button {
+                                key: "{e.word}",
+                                class: tab(current.as_ref().is_some_and(|c| c.word == e.word)),
+                                onclick: {
+                                    let w = e.word.clone();
+                                    move |_| selected.set(Some(w.clone()))
+                                },
+                                "{e.word}"
+                                span { class: "text-xs opacity-70 ml-1", "{gloss}" }
+                            }
+                        }
+                    }
+                    if let Some(e) = current {
+                        if let Some(c) = e.conjugation.clone() {
+                            ParadigmTable { title: e.word.clone(), conj: c.clone() }
+                            if let Some(p) = c.partner.clone() {
+                                ParadigmTable { title: p.word.clone(), conj: p.conjugation() }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One verb's table: a row per tense / screeve that has any form, grouped
+/// under its series heading.
+#[component]
+fn ParadigmTable(title: String, conj: Conjugation) -> Element {
+    let inventory = use_manifest()().inventory;
+    let rows = inventory
+        .tenses
+        .iter()
+        .filter(|r| Person::ALL.iter().any(|&p| conj.form(&r.key, p).is_some()));
+    let mut groups: Vec<(String, Vec<TenseRow>)> = Vec::new();
+    for r in rows {
+        match groups.last_mut() {
+            Some((g, rs)) if *g == r.group => rs.push(r.clone()),
+            _ => groups.push((r.group.to_string(), vec![r.clone()])),
+        }
+    }
+    let pronouns: Vec<String> = Person::ALL
+        .iter()
+        .map(|&p| inventory.pronoun(p).to_string())
+        .collect();
+    let aspect = conj.aspect.map(|a| a.label()).unwrap_or("");
+
+    rsx! {
+        div { class: "max-w-5xl mx-auto mb-8 overflow-x-auto",
+            div { class: "flex items-baseline gap-2 mb-2",
+                h2 { class: "text-lg font-semibold text-indigo-200", "{title}" }
+                span { class: "text-xs text-gray-400", "{aspect}" }
+            }
+            table { class: "w-full text-sm border-collapse",
+                thead {
+                    tr {
+                        th { class: "text-left p-2 text-gray-400 font-normal", "" }
+                        for (p, pronoun) in Person::ALL.iter().zip(pronouns) {
+                            th { key: "{p.key()}", class: "text-left p-2 text-gray-400 font-normal", "{pronoun}" }
+                        }
+                    }
+                }
+                tbody {
+                    for (group, rs) in groups {
+                        tr { key: "{group}",
+                            td { colspan: "7", class: "pt-3 pb-1 text-xs uppercase tracking-wide text-gray-500", "{group}" }
+                        }
+                        for r in rs {
+                            tr { key: "{r.key}", class: "border-t border-gray-700",
+                                td { class: "p-2 text-gray-300", "{r.label}" }
+                                for p in Person::ALL {
+                                    td { key: "{p.key()}", class: "p-2", "{conj.form(&r.key, p).unwrap_or(\"—\")}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Ask for one random cell at a time; Enter checks, Enter again moves on.
+/// `probe` logs the answers as planner probes (the session's conjugation step).
+#[component]
+pub(super) fn ConjugationDrill(
+    lang: String,
+    verbs: Vec<LexEntry>,
+    #[props(default)] probe: bool,
+) -> Element {
+    let learner = use_learner();
+    let inventory = use_manifest()().inventory;
+    let cells = all_cells(&inventory.tenses, &verbs);
+    let n = cells.len();
+
+    let mut idx = use_signal(move || rand::rng().random_range(0..n.max(1)));
+    let mut typed = use_signal(String::new);
+    let mut feedback = use_signal(|| None::<(bool, String)>);
+    let mut started = use_signal(now_ms);
+    let mut tally = use_signal(|| (0u32, 0u32));
+
+    let Some(cell) = cells.get(idx()).cloned() else {
+        return rsx! {
+            div { class: "text-center text-sm text-gray-400", "No conjugation cells to drill." }
+        };
+    };
+    let (right, asked) = tally();
+
+    let mut advance = move || {
+        let mut rng = rand::rng();
+        let mut next = rng.random_range(0..n);
+        if n > 1 && next == idx() {
+            next = (next + 1) % n;
+        }
+        idx.set(next);
+        typed.set(String::new());
+        feedback.set(None);
+        started.set(now_ms());
+    };
+
+    let check = {
+        let cell = cell.clone();
+        let lang = lang.clone();
+        move || {
+            let accepted = accepted_answers(&cell.form);
+            let grade = grade_answer(&typed(), &accepted);
+            let latency = now_ms().saturating_sub(started()) as u32;
+            let evidence = conjugation_evidence(
+                &lang,
+                &cell.shown,
+                Some(cell.rank),
+                &cell.row.key,
+                cell.person.key(),
+                &cell.form,
+                grade,
+                latency,
+            );
+            learner.emit_all(if probe { as_probe(evidence) } else { evidence });
+            let ok = grade >= 0.85;
+            tally.with_mut(|(r, a)| {
+                *r += ok as u32;
+                *a += 1;
+            });
+            feedback.set(Some((ok, cell.form.clone())));
+        }
+    };
+
+    rsx! {
+        div { class: "max-w-xl mx-auto flex flex-col items-center gap-4",
+            div { class: "text-sm text-gray-400", "{right} / {asked}" }
+            div { class: "w-full rounded-lg bg-gray-900/60 border border-gray-700 p-6 flex flex-col items-center gap-2",
+                span { class: "text-[0.65rem] uppercase tracking-wide text-gray-500", "{cell.row.group} · {cell.row.label}" }
+                span { class: "text-3xl", "{cell.shown}" }
+                span { class: "text-xs text-gray-500", "{cell.en}" }
+                span { class: "text-lg text-indigo-200", "{inventory.pronoun(cell.person)} …" }
+            }
+            input {
+                r#type: "text",
+                value: "{typed}",
+                autocomplete: "off",
+                autocorrect: "off",
+                spellcheck: "false",
+                class: "w-full text-center text-2xl bg-transparent border-b-2 border-gray-600 focus:border-indigo-400 outline-none py-2",
+                oninput: move |e: FormEvent| typed.set(e.value()),
+                onmounted: move |e| {
+                    let el = e.data();
+                    spawn(async move { let _ = el.set_focus(true).await; });
+                },
+                onkeydown: {
+                    let mut check = check.clone();
+                    move |e: KeyboardEvent| {
+                        if e.key().to_string() != "Enter" {
+                            return;
+                        }
+                        if feedback().is_some() {
+                            advance();
+                        } else if !typed().trim().is_empty() {
+                            check();
+                        }
+                    }
+                },
+            }
+            if let Some((ok, form)) = feedback() {
+                if ok {
+                    div { class: "text-sm text-green-400", "✓ {form}" }
+                } else {
+                    div { class: "text-sm text-red-400", "✗ answer: {form}" }
+                }
+            }
+        }
+    }
+}
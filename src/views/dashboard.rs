@@ -34,8 +34,14 @@ fn nice(lang: &str) -> String {
 pub fn DashboardPage() -> Element {
     let active = use_context::<Signal<String>>();
     let learner = use_learner();
+    let mut tune_msg = use_signal(|| None::<String>);
+    // a refit is running (it replays the whole history, off the UI thread)
+    let mut tuning = use_signal(|| false);
+    let mut compact_msg = use_signal(|| None::<String>);
+    let corrupt = learner.corrupt_lines();
     let now = now_ms();
-    let model = learner.model();
+    let model_sig = learner.model();
+    let model = model_sig.read();
 
     let lang = active();
     let lang_display = nice(&lang);
@@ -176,6 +182,11 @@ pub fn DashboardPage() -> Element {
                         p { class: "text-xs text-gray-400",
                             "{events} observations · {items} items · {due} due"
                         }
+                        Link {
+                            to: crate::Route::SessionPage {},
+                            class: "text-xs text-indigo-300 hover:text-indigo-200",
+                            "Start today's session →"
+                        }
 
                     }
 
@@ -252,6 +263,69 @@ pub fn DashboardPage() -> Element {
                      untested axes read \"untested,\" confidence contracts as time passes without a probe, and a \
                      passed probe snaps it back. Reading floors its prerequisites within the same language."
                 }
+
+                // fit the model's tunables to this user's own history (all languages)
+                div { class: "flex items-center gap-3 mt-3",
+                    button {
+                        class: "px-3 py-1.5 rounded text-xs bg-gray-700 hover:bg-gray-600 text-gray-200 disabled:opacity-50",
+                        disabled: tuning(),
+                        onclick: {
+                            let learner = learner.clone();
+                            move |_| {
+                                let learner = learner.clone();
+                                tuning.set(true);
+                                tune_msg.set(Some("Tuning…".to_string()));
+                                spawn(async move {
+                                    let msg = match learner.refit().await {
+                                        Some(f) => format!(
+                                            "Tuned on {} events — prediction log-loss {:.3} → {:.3}.",
+                                            f.events, f.baseline_loss, f.loss
+                                        ),
+                                        None => format!(
+                                            "Need at least {} observations before tuning.",
+                                            crate::learning::fit::MIN_EVENTS
+                                        ),
+                                    };
+                                    tune_msg.set(Some(msg));
+                                    tuning.set(false);
+                                });
+                            }
+                        },
+                        if tuning() { "Tuning…" } else { "Tune to my history" }
+                    }
+                    if let Some(msg) = tune_msg() {
+                        span { class: "text-xs text-gray-400", "{msg}" }
+                    }
+                }
+
+                // archive events the latest snapshot already covers; not while
+                // a fit is replaying the history
+                div { class: "flex items-center gap-3 mt-2",
+                    button {
+                        class: "px-3 py-1.5 rounded text-xs bg-gray-700 hover:bg-gray-600 text-gray-200 disabled:opacity-50",
+                        disabled: tuning(),
+                        onclick: {
+                            let learner = learner.clone();
+                            move |_| {
+                                let msg = match learner.compact() {
+                                    Ok(0) => "Nothing to archive yet.".to_string(),
+                                    Ok(n) => format!("Archived {n} events; the live log is short again."),
+                                    Err(e) => format!("Couldn't compact the log: {e}"),
+                                };
+                                compact_msg.set(Some(msg));
+                            }
+                        },
+                        "Archive old events"
+                    }
+                    if let Some(msg) = compact_msg() {
+                        span { class: "text-xs text-gray-400", "{msg}" }
+                    }
+                }
+                if !corrupt.is_empty() {
+                    p { class: "text-xs text-amber-400 mt-2",
+                        "Skipped {corrupt.len()} corrupt line(s) in the evidence log (lines {corrupt:?})."
+                    }
+                }
             }
         }
     }
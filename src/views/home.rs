@@ -7,6 +7,11 @@ use crate::{components::separator::Separator, views::DashboardPage, Route};
 #[component]
 pub fn Home() -> Element {
     let tiles = [
+        (
+            "Today's session",
+            "A short plan: what's due, what we're unsure of, and a few new words",
+            Route::SessionPage {},
+        ),
         (
             "Alphabet",
             "Learn to pronounce the script, letter by letter",
@@ -17,6 +22,11 @@ pub fn Home() -> Element {
             "Learn the idiosyncrasies of your desired language",
             Route::GrammarPage {},
         ),
+        (
+            "Conjugation",
+            "Verb tables by tense, and a drill that asks for one form at a time",
+            Route::ConjugatePage {},
+        ),
         (
             "Typing test",
             "Drill muscle memory of the keyboard and the meaning of the lexicon. Or test your WPM!",
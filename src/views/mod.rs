@@ -26,8 +26,14 @@ pub use dashboard::DashboardPage;
 mod reading;
 pub use reading::ReadingPage;
 
+mod session;
+pub use session::SessionPage;
+
 mod typing_test;
 pub use typing_test::TypingPage;
 
 mod grammar;
 pub use grammar::GrammarPage;
+
+mod conjugate;
+pub use conjugate::ConjugatePage;
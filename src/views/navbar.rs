@@ -5,49 +5,30 @@ use crate::components::{
     },
     DictSearch, SettingsButton,
 };
+use crate::langpack::{use_manifest, use_registry};
 use crate::Route;
 use dioxus::prelude::*;
-use strum::IntoEnumIterator;
 
 const NAVBAR_CSS: Asset = asset!("/assets/styling/navbar.css");
 
-#[derive(Debug, Clone, Copy, PartialEq, strum::EnumIter, strum::Display)]
-enum Languages {
-    Georgian,
-    Russian,
-}
-
-impl Languages {
-    const fn emoji(&self) -> &'static str {
-        match self {
-            Languages::Georgian => "🇬🇪",
-            Languages::Russian => "🇷🇺",
-        }
-    }
-
-    const fn code(&self) -> &'static str {
-        match self {
-            Languages::Georgian => "georgian",
-            Languages::Russian => "russian",
-        }
-    }
-}
-
 /// Layout chrome rendered on every route: nav links, the language switcher
 /// (now global), and the dictionary search. The active language lives in
-/// context (provided by `App`), so switching here updates every page.
+/// context (provided by `App`), so switching here updates every page. The
+/// language list comes from the pack registry (`langpack.rs`).
 #[component]
 pub fn Navbar() -> Element {
     let mut lang = use_context::<Signal<String>>();
     let route = use_route::<Route>();
     let lang_now = use_context::<Signal<String>>()();
+    let registry = use_registry();
+    let manifest = use_manifest();
 
-    let languages = Languages::iter().enumerate().map(|(i, f)| {
-        let label = format!("{} {f}", f.emoji()); // e.g. "🇬🇪 Georgian"
+    let languages = registry().into_iter().enumerate().map(|(i, m)| {
+        let label = m.label(); // e.g. "🇬🇪 Georgian"
         rsx! {
-            SelectOption::<Languages> {
+            SelectOption::<String> {
                 index: i,
-                value: f,
+                value: m.code,
                 text_value: label.clone(),
                 {label}
                 SelectItemIndicator {}
@@ -55,7 +36,11 @@ pub fn Navbar() -> Element {
         }
     });
     // somewhere before the rsx
-    let current = Languages::iter().find(|l| l.code() == lang_now);
+    let current = registry
+        .read()
+        .iter()
+        .any(|m| m.code == lang_now)
+        .then(|| lang_now.clone());
     rsx! {
         document::Link { rel: "stylesheet", href: NAVBAR_CSS }
         div {
@@ -64,8 +49,14 @@ pub fn Navbar() -> Element {
             class: "fade-in-soft p-2",
 
             Link { to: Route::Home {}, "Dashboard" }
+            Link { to: Route::SessionPage {}, "Today" }
             Link { to: Route::AlphabetPage {  }, "Alphabet" }
-            Link { to: Route::GrammarPage {}, "Grammar" }
+            if manifest().features.grammar {
+                Link { to: Route::GrammarPage {}, "Grammar" }
+            }
+            if manifest().features.conjugations {
+                Link { to: Route::ConjugatePage {}, "Conjugate" }
+            }
             Link { to: Route::TypingPage {  }, "Typing Test" }
             Link { to: Route::ReadingPage {}, "Reading" }
             Link { to: Route::DictionaryPage {}, "Dictionary" }
@@ -76,12 +67,12 @@ pub fn Navbar() -> Element {
 
             }
             div { class: "text-black",
-                Select::<Languages> {
+                Select::<String> {
                     placeholder: "Select a Language...",
                     value:current,
-                    on_value_change: move |value: Option<Languages>| {
-                        if let Some(lang_enum) = value {
-                            lang.set(lang_enum.code().to_string());
+                    on_value_change: move |value: Option<String>| {
+                        if let Some(code) = value {
+                            lang.set(code);
                         }
                     },
 
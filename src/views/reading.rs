@@ -1,69 +1,90 @@
 // src/views/reading.rs
 //
-// Reading / type-the-meaning drill. Paste an L2 sentence; the drill finds the
-// words it knows — citation forms, plus inflected forms of any noun that carries
-// a declension table — and quizzes their meaning. Evidence emitted:
-// VocabRecognition per word, Reading for the finished sentence (which floors
-// Script/Vocab/Grammar through the model). A "just read" toggle logs a lighter
-// Reading signal instead of quizzing.
+// Reading / type-the-meaning drill over the language's reading corpus
+// (`ingest::corpus`). The page serves the next unread sentence on its own,
+// preferring one in the i+1 band of known words (`ingest::comprehension`), and
+// shows how much of it the learner should know, as it does for the unread
+// sentences listed under it; files imported from here
+// (PDF, EPUB, text) top the corpus up, extracted off the UI thread. When the
+// corpus runs dry, or on request, a pasted sentence works the same way.
 //
-// The paste box is the stand-in for the PDF ingest pipeline, which will later
-// hand this same component sentences automatically.
-
-use std::collections::HashMap;
+// The drill runs each word through `models::lemma` (declension tables,
+// Georgian postpositions, then an ending-stripping guess) and quizzes the
+// meaning of the head word, showing how the surface form was parsed. Evidence
+// emitted: VocabRecognition per head word (the same `:rec:` item as the
+// meaning drill), Reading for the finished sentence (which floors
+// Script/Vocab/Grammar through the model). A "just read" toggle logs a lighter
+// Reading signal instead of quizzing. Finishing a corpus sentence either way
+// marks it read. Words the lexicon can't place are listed under the sentence;
+// clicking one opens `WordDetail` to add it to the user's lexicon.
 
 use dioxus::prelude::*;
 
-use crate::assets::freq_json_for;
-use crate::components::drills::typing_engine::{accepted_answers, grade_answer, tokenize};
-use crate::learner::{now_ms, use_learner};
+use std::path::Path;
+
+use crate::components::WordDetail;
+use crate::components::drills::typing_engine::{grade_senses, tokenize};
+use crate::ingest::comprehension::{self, Band, Comprehension};
+use crate::ingest::corpus;
+use crate::langpack::use_manifest;
+use crate::learner::{now_ms, reading_evidence, use_learner};
+use crate::learning::item::word_prior;
 use crate::learning::{Evidence, Skill, Source};
-use crate::models::lexicon::{GeoCase, LexEntry, Lexicon};
+use crate::models::lemma::Lemmatizer;
+use crate::models::lexicon::{LexEntry, Sense};
+
+/// Parses below this are too shaky to quiz on.
+const MIN_CONFIDENCE: f32 = 0.3;
+/// Unread corpus sentences listed, with their scores, under the drill.
+const UPCOMING: usize = 12;
+
+/// How a sentence's known share sits against the i+1 band.
+fn band_label(band: Band) -> &'static str {
+    match band {
+        Band::Below => "a stretch",
+        Band::Within => "i+1",
+        Band::Above => "easy",
+    }
+}
 
 #[derive(Clone, PartialEq)]
 struct QuizTok {
     surface: String,
+    /// The head word the surface form was parsed back to.
+    lemma: String,
+    morphology: String,
+    confidence: f32,
+    rank: u32,
     gloss: String,
-    accepted: Vec<String>,
+    senses: Vec<Sense>,
 }
 
-fn sentence_id(lang: &str, s: &str) -> String {
-    use std::hash::{Hash, Hasher};
-    let mut h = std::collections::hash_map::DefaultHasher::new();
-    s.trim().hash(&mut h);
-    format!("{lang}:sentence:{:x}", h.finish())
+#[component]
+pub fn ReadingPage() -> Element {
+    let lang = use_context::<Signal<String>>();
+    let l = lang();
+    rsx! {
+        Reader { key: "{l}" }
+    }
 }
 
+/// The page proper, remounted per language so the corpus, the sentence and
+/// the lexicon start over together.
 #[component]
-pub fn ReadingPage() -> Element {
+fn Reader() -> Element {
     let lang = use_context::<Signal<String>>();
     let learner = use_learner();
+    let model = learner.model();
+    let manifest = use_manifest();
+    let dir = manifest().direction.as_attr();
 
     // lexicon for the active language
-    let lex_res = use_resource(move || {
-        let l = lang.read().clone();
-        async move { Lexicon::from_json(freq_json_for(&l)).unwrap_or_default() }
-    });
+    let lex_res = crate::langpack::use_lexicon(lang);
 
-    // surface form -> entry, incl. every declined form of nouns that have a table
-    let by_surface = use_memo(move || {
-        let mut map: HashMap<String, LexEntry> = HashMap::new();
+    // surface form -> head word, via every declined form plus clitics and guesses
+    let lemmatizer = use_memo(move || {
         let lexicon = lex_res.read().clone().unwrap_or_default();
-        for e in lexicon.all() {
-            map.entry(e.head().to_string()).or_insert_with(|| e.clone());
-            if let Some(d) = &e.declension {
-                for c in GeoCase::ALL {
-                    let f = d.forms(c);
-                    for form in [&f.singular, &f.plural, &f.with_noun] {
-                        let form = form.trim();
-                        if !form.is_empty() {
-                            map.entry(form.to_string()).or_insert_with(|| e.clone());
-                        }
-                    }
-                }
-            }
-        }
-        map
+        Lemmatizer::new(&lang.read(), &lexicon)
     });
 
     let mut sentence_input = use_signal(String::new);
@@ -75,25 +96,55 @@ pub fn ReadingPage() -> Element {
     let mut scores = use_signal(Vec::<f32>::new);
     let mut meaning_mode = use_signal(|| true);
     let mut started = use_signal(|| 0u64);
+    let mut reading_corpus = use_signal(|| corpus::load(&lang.peek()));
+    // corpus index of the loaded sentence; `None` for a paste
+    let mut current = use_signal(|| None::<usize>);
+    let mut known_share = use_signal(|| None::<Comprehension>);
+    let mut import_path = use_signal(String::new);
+    let mut import_msg = use_signal(|| None::<String>);
+    // a PDF or EPUB can take a while to extract; one import at a time
+    let mut importing = use_signal(|| false);
+    // words of the loaded sentence the lexicon can't place, and the one being added
+    let mut unknown = use_signal(Vec::<String>::new);
+    let mut adding = use_signal(|| None::<LexEntry>);
 
     // ── handlers ────────────────────────────────────────────────────────────
-    let load = move |_| {
-        let s = sentence_input();
-        let map = by_surface.read();
+    let mut start = move |s: String| {
+        sentence_input.set(s.clone());
+        let l = lang.read().clone();
+        let lemmatizer = lemmatizer.read();
+        let pack = manifest.peek();
+        known_share.set(Some(comprehension::score(
+            &l,
+            &s,
+            &lemmatizer,
+            &model.peek(),
+            now_ms(),
+        )));
         let mut q: Vec<QuizTok> = Vec::new();
+        let mut unplaced: Vec<String> = Vec::new();
         for t in tokenize(&s) {
-            if let Some(e) = map.get(&t) {
-                let acc = accepted_answers(&e.en);
-                if !acc.is_empty() && !q.iter().any(|x| x.surface == t) {
-                    q.push(QuizTok {
-                        surface: t.clone(),
-                        gloss: e.en.clone(),
-                        accepted: acc,
-                    });
+            let Some(a) = lemmatizer.best(&t).filter(|a| a.confidence >= MIN_CONFIDENCE) else {
+                let w = t.to_lowercase();
+                if t.chars().any(char::is_alphabetic) && !unplaced.contains(&w) {
+                    unplaced.push(w);
                 }
+                continue;
+            };
+            if !a.entry.senses.is_empty() && !q.iter().any(|x| x.lemma == a.lemma()) {
+                q.push(QuizTok {
+                    surface: t.clone(),
+                    lemma: a.lemma().to_string(),
+                    morphology: a.morphology(&pack.inventory),
+                    confidence: a.confidence,
+                    rank: a.entry.rank,
+                    gloss: a.entry.gloss(),
+                    senses: a.entry.senses.clone(),
+                });
             }
         }
         quiz.set(q);
+        unknown.set(unplaced);
         idx.set(0);
         typed.set(String::new());
         feedback.set(None);
@@ -102,6 +153,116 @@ pub fn ReadingPage() -> Element {
         loaded.set(true);
     };
 
+    let load = move |_| {
+        current.set(None);
+        start(sentence_input());
+    };
+
+    // best unread corpus sentence, or back to the paste box when there's none
+    let mut pull_next = move || {
+        let picked = comprehension::pick(
+            &lang.peek(),
+            &reading_corpus.peek(),
+            &lemmatizer.peek(),
+            &model.peek(),
+            now_ms(),
+        );
+        let next = picked.map(|(i, _)| (i, reading_corpus.peek().sentences[i].text.clone()));
+        match next {
+            Some((i, text)) => {
+                current.set(Some(i));
+                start(text);
+            }
+            None => {
+                current.set(None);
+                quiz.set(Vec::new());
+                loaded.set(false);
+                sentence_input.set(String::new());
+            }
+        }
+    };
+
+    let mut finish = move || {
+        if let Some(i) = current() {
+            reading_corpus.write().mark_read(i);
+            corpus::save(&lang.peek(), &reading_corpus.peek());
+        }
+        pull_next();
+    };
+
+    // serve a sentence once the lexicon is in; adding a word from here
+    // reloads it mid-sentence, and that sentence stays
+    use_effect(move || {
+        let ready = lex_res.read().is_some();
+        if ready && !*loaded.peek() {
+            pull_next();
+        }
+    });
+
+    // a word added from here drops off the unknown list once the lexicon reloads
+    use_effect(move || {
+        let lemmatizer = lemmatizer.read();
+        unknown.write().retain(|w| {
+            lemmatizer
+                .best(w)
+                .is_none_or(|a| a.confidence < MIN_CONFIDENCE)
+        });
+    });
+
+    let import = move |_| {
+        let path = import_path().trim().to_string();
+        if path.is_empty() || importing() {
+            return;
+        }
+        let l = lang.read().clone();
+        importing.set(true);
+        import_msg.set(Some(format!("Importing {path}…")));
+        spawn(async move {
+            let result = {
+                let (l, path) = (l.clone(), path.clone());
+                tokio::task::spawn_blocking(move || crate::ingest::ingest_file(&l, Path::new(&path)))
+                    .await
+                    .unwrap_or_else(|err| Err(format!("import failed: {err}")))
+            };
+            match result {
+                Ok(added) => {
+                    import_msg.set(Some(format!("Added {added} new sentences.")));
+                    import_path.set(String::new());
+                    // the language may have changed while the file was read
+                    if *lang.peek() == l {
+                        reading_corpus.set(corpus::load(&l));
+                        if !loaded() {
+                            pull_next();
+                        }
+                    }
+                }
+                Err(e) => import_msg.set(Some(e)),
+            }
+            importing.set(false);
+        });
+    };
+
+    let paste_own = move |_| {
+        current.set(None);
+        quiz.set(Vec::new());
+        loaded.set(false);
+        sentence_input.set(String::new());
+    };
+
+    let resume_corpus = move |_| pull_next();
+
+    // the next unread sentences, each with how much of it is known
+    let upcoming = use_memo(move || {
+        comprehension::scored_unread(
+            &lang.read(),
+            &reading_corpus.read(),
+            &lemmatizer.read(),
+            &model.read(),
+            now_ms(),
+            UPCOMING,
+        )
+    });
+
     let check = {
         let learner = learner.clone();
         move |_| {
@@ -111,26 +272,33 @@ pub fn ReadingPage() -> Element {
                 return;
             }
             let tok = q[i].clone();
-            let score = grade_answer(&typed(), &tok.accepted);
+            let (score, sense) = grade_senses(&typed(), &tok.senses);
             let now = now_ms();
             let latency = now.saturating_sub(started()) as u32;
             let l = lang.read().clone();
             learner.emit(Evidence::new(
                 now,
                 l.clone(),
-                format!("{l}:word:{}", tok.surface),
+                format!("{l}:rec:{}", tok.lemma),
                 Skill::VocabRecognition,
                 score,
-                0.0,
+                word_prior(Some(tok.rank), &tok.lemma),
                 latency,
                 Source::Reading,
             ));
             let mut sc = scores();
             sc.push(score);
             scores.set(sc);
+            // name the sense that matched when the word has several
+            let shown = match sense {
+                Some(s) if score >= 0.6 && tok.senses.len() > 1 => {
+                    format!("{} (sense {} of {})", tok.senses[s].gloss(), s + 1, tok.senses.len())
+                }
+                _ => tok.gloss.clone(),
+            };
             feedback.set(Some((
                 score >= 0.6,
-                tok.gloss.clone(),
+                shown,
                 (score * 100.0).round() as i32,
             )));
         }
@@ -148,17 +316,7 @@ pub fn ReadingPage() -> Element {
                 } else {
                     sc.iter().sum::<f32>() / sc.len() as f32
                 };
-                let l = lang.read().clone();
-                learner.emit(Evidence::new(
-                    now_ms(),
-                    l.clone(),
-                    sentence_id(&l, &sentence_input()),
-                    Skill::Reading,
-                    avg,
-                    0.0,
-                    0,
-                    Source::Reading,
-                ));
+                learner.emit_all(reading_evidence(&lang.read(), &sentence_input(), avg));
             }
             idx.set(i + 1);
             typed.set(String::new());
@@ -170,38 +328,23 @@ pub fn ReadingPage() -> Element {
     let read_done = {
         let learner = learner.clone();
         move |_| {
-            let l = lang.read().clone();
-            learner.emit(Evidence::new(
-                now_ms(),
-                l.clone(),
-                sentence_id(&l, &sentence_input()),
-                Skill::Reading,
-                0.5, // "encountered", not tested
-                0.0,
-                0,
-                Source::Reading,
-            ));
-            quiz.set(Vec::new());
-            loaded.set(false);
-            sentence_input.set(String::new());
+            learner.emit_all(reading_evidence(&lang.read(), &sentence_input(), 0.5));
+            finish();
         }
     };
 
-    let new_sentence = move |_| {
-        quiz.set(Vec::new());
-        idx.set(0);
-        typed.set(String::new());
-        feedback.set(None);
-        scores.set(Vec::new());
-        loaded.set(false);
-        sentence_input.set(String::new());
-    };
+    let new_sentence = move |_| finish();
 
     // ── precomputed view values (no method calls inside rsx) ─────────────────
     let q = quiz();
     let total = q.len();
     let i = idx();
     let cur = q.get(i).cloned();
+    // flag shaky parses (guesses, mostly) next to the morphology
+    let unsure_pct = cur
+        .as_ref()
+        .filter(|t| t.confidence < 0.8)
+        .map(|t| (t.confidence * 100.0).round() as i32);
     let done = total > 0 && i >= total;
     let mode_meaning = meaning_mode();
     let loaded_v = loaded();
@@ -229,11 +372,45 @@ pub fn ReadingPage() -> Element {
                 format!("{pct}%"),
             )
         });
-    let known: Vec<(String, String)> = q
+    let known: Vec<(String, String, String)> = q
         .iter()
-        .map(|t| (t.surface.clone(), t.gloss.clone()))
+        .map(|t| (t.surface.clone(), t.lemma.clone(), t.gloss.clone()))
         .collect();
 
+    let corpus_total = reading_corpus.read().sentences.len();
+    let unread = reading_corpus.read().unread_count();
+    let source = current().and_then(|i| {
+        reading_corpus
+            .read()
+            .sentences
+            .get(i)
+            .map(|s| s.source.clone())
+    });
+    // "88% known · i+1" for whatever sentence is loaded
+    let known_note = known_share().filter(|c| c.words > 0).map(|c| {
+        let pct = (c.fraction() * 100.0).round() as i32;
+        format!("{pct}% known · {}", band_label(c.band()))
+    });
+    // (corpus index, text, "88% · i+1"), the one up now left out
+    let upcoming_rows: Vec<(usize, String, String)> = upcoming()
+        .into_iter()
+        .filter(|(i, _)| Some(*i) != current())
+        .map(|(i, c)| {
+            let text = reading_corpus.read().sentences[i].text.clone();
+            let note = if c.words == 0 {
+                String::new()
+            } else {
+                let pct = (c.fraction() * 100.0).round() as i32;
+                format!("{pct}% · {}", band_label(c.band()))
+            };
+            (i, text, note)
+        })
+        .collect();
+    let known_label = known_note.clone().unwrap_or_default();
+    let import_note = import_msg();
+    let import_busy = importing();
+    let unknown_words = unknown();
+
     let geo = "'Noto Serif Georgian','Noto Serif',Georgia,serif";
 
     rsx! {
@@ -241,7 +418,7 @@ pub fn ReadingPage() -> Element {
             div { class: "max-w-2xl mx-auto text-center",
                 h1 { class: "text-2xl font-semibold mb-1", "Reading" }
                 p { class: "text-sm text-gray-400 mb-5",
-                    "Paste a sentence in your target language. Known words are quizzed; the rest is context."
+                    "Sentences come from your imported reading, or paste your own. Known words are quizzed; the rest is context."
                 }
 
                 // mode toggle
@@ -258,12 +435,25 @@ pub fn ReadingPage() -> Element {
                     }
                 }
 
+                if let Some(src) = source {
+                    div { class: "flex justify-between text-xs text-gray-500 mb-2",
+                        span { "from {src} · {unread} unread" }
+                        button { class: "underline hover:text-gray-300", onclick: paste_own, "Paste your own" }
+                    }
+                }
+
                 if !loaded_v {
                     // paste + load
                     div { class:"justify-center items-center",
+                        if unread > 0 {
+                            button { class: "mb-3 text-xs text-gray-400 underline hover:text-gray-200", onclick: resume_corpus,
+                                "Back to your reading ({unread} unread)"
+                            }
+                        }
                         textarea {
                             class: "w-full p-3 rounded bg-gray-900 text-white text-lg focus:outline-none focus-visible:ring-2 focus-visible:ring-indigo-500",
                             style: "font-family:{geo};",
+                            dir: "{dir}",
                             placeholder: "კაცი ქუჩაში დადიოდა.",
                             value: "{sentence}",
                             oninput: move |e| sentence_input.set(e.value()),
@@ -277,26 +467,37 @@ pub fn ReadingPage() -> Element {
                 } else if total == 0 {
                     // loaded but nothing recognized
                     div {
-                        p { class: "mb-2", style: "font-family:{geo};", "{sentence}" }
+                        p { class: "mb-2", style: "font-family:{geo};", dir: "{dir}", "{sentence}" }
+                        if let Some(note) = known_note.clone() {
+                            p { class: "text-xs text-gray-500 mb-2", "{note}" }
+                        }
                         p { class: "text-sm text-gray-400 mb-4",
-                            "No dictionary words recognized. Inflected forms are matched only for nouns that have a declension table — try another sentence, or add the words to the dictionary."
+                            "No dictionary words recognized. Inflected forms are traced back through declension tables and common endings — try another sentence, or add the words to the dictionary."
                         }
-                        button { class: "px-4 py-2 rounded bg-gray-700 text-sm", onclick: new_sentence, "New sentence" }
+                        button { class: "px-4 py-2 rounded bg-gray-700 text-sm", onclick: new_sentence, "Next sentence" }
                     }
                 } else {
                     // the sentence, for context
-                    div { class: "p-3 mb-4 rounded bg-gray-900 text-lg", style: "font-family:{geo};", "{sentence}" }
+                    div { class: "p-3 rounded bg-gray-900 text-lg", style: "font-family:{geo};", dir: "{dir}", "{sentence}" }
+                    p { class: "text-xs text-gray-500 text-right mt-1 mb-4", "{known_label}" }
 
                     if mode_meaning {
                         if done {
                             div {
                                 p { class: "text-lg mb-3", "Sentence done — {avg_pct}% across {total} words." }
-                                button { class: "px-4 py-2 rounded bg-indigo-600 hover:bg-indigo-500 text-sm", onclick: new_sentence, "New sentence" }
+                                button { class: "px-4 py-2 rounded bg-indigo-600 hover:bg-indigo-500 text-sm", onclick: new_sentence, "Next sentence" }
                             }
                         } else if let Some(tok) = cur.clone() {
                             div {
                                 p { class: "text-xs text-gray-500", "{progress}" }
-                                p { class: "text-3xl my-3", style: "font-family:{geo};", "{tok.surface}" }
+                                p { class: "text-3xl mt-3", style: "font-family:{geo};", "{tok.surface}" }
+                                p { class: "text-sm text-gray-400 mb-3",
+                                    span { style: "font-family:{geo};", class: "text-indigo-200", "{tok.lemma}" }
+                                    " · {tok.morphology}"
+                                    if let Some(pct) = unsure_pct {
+                                        " ({pct}% sure)"
+                                    }
+                                }
                                 input {
                                     class: "w-full p-2 rounded bg-gray-900 text-white focus:outline-none focus-visible:ring-2 focus-visible:ring-indigo-500",
                                     placeholder: "meaning in English",
@@ -318,9 +519,14 @@ pub fn ReadingPage() -> Element {
                         div {
                             p { class: "text-sm text-gray-400 mb-2", "Words in this sentence you've seen before:" }
                             div { class: "space-y-1 mb-4",
-                                for (surface, gloss) in known.iter().cloned() {
+                                for (surface, lemma, gloss) in known.iter().cloned() {
                                     div { class: "flex justify-between text-sm",
-                                        span { style: "font-family:{geo};", "{surface}" }
+                                        span { style: "font-family:{geo};",
+                                            "{surface}"
+                                            if surface != lemma {
+                                                span { class: "text-gray-500", " → {lemma}" }
+                                            }
+                                        }
                                         span { class: "text-gray-400", "{gloss}" }
                                     }
                                 }
@@ -329,7 +535,80 @@ pub fn ReadingPage() -> Element {
                         }
                     }
                 }
+
+                if loaded_v && !unknown_words.is_empty() {
+                    div { class: "mt-6 text-left",
+                        p { class: "text-xs text-gray-500 mb-2", "Not in your dictionary — click to add:" }
+                        div { class: "flex flex-wrap gap-2",
+                            for w in unknown_words.iter().cloned() {
+                                button {
+                                    key: "{w}",
+                                    class: "px-2 py-1 rounded bg-gray-700 hover:bg-gray-600 text-sm",
+                                    style: "font-family:{geo};",
+                                    onclick: {
+                                        let word = w.clone();
+                                        move |_| adding.set(Some(LexEntry { word: word.clone(), user_added: true, ..Default::default() }))
+                                    },
+                                    "+ {w}"
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if let Some(entry) = adding() {
+                    WordDetail { entry, on_close: move |_| adding.set(None) }
+                }
+
+                // what's coming up in the corpus, scored; click one to read it now
+                if !upcoming_rows.is_empty() {
+                    div { class: "mt-8 text-left",
+                        p { class: "text-xs text-gray-500 mb-2", "Up next in your reading:" }
+                        div { class: "space-y-1",
+                            for (i, text, note) in upcoming_rows {
+                                button {
+                                    key: "{i}",
+                                    class: "w-full flex justify-between gap-3 px-2 py-1 rounded hover:bg-gray-700 text-sm text-left",
+                                    onclick: {
+                                        let text = text.clone();
+                                        move |_| {
+                                            current.set(Some(i));
+                                            start(text.clone());
+                                        }
+                                    },
+                                    span { class: "truncate", style: "font-family:{geo};", dir: "{dir}", "{text}" }
+                                    span { class: "text-xs text-gray-500 shrink-0", "{note}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // import a local file into the corpus
+                div { class: "mt-10 pt-4 border-t border-gray-700 text-left",
+                    p { class: "text-xs text-gray-500 mb-2",
+                        "Reading corpus: {unread} unread of {corpus_total}. Import a PDF, EPUB or text file:"
+                    }
+                    div { class: "flex gap-2",
+                        input {
+                            class: "flex-1 p-2 rounded bg-gray-900 text-white text-sm focus:outline-none focus-visible:ring-2 focus-visible:ring-indigo-500",
+                            placeholder: "/path/to/book.epub",
+                            value: "{import_path}",
+                            oninput: move |e| import_path.set(e.value()),
+                        }
+                        button {
+                            class: "px-3 py-2 rounded bg-gray-700 hover:bg-gray-600 text-sm disabled:opacity-50",
+                            disabled: import_busy,
+                            onclick: import,
+                            if import_busy { "Importing…" } else { "Import" }
+                        }
+                    }
+                    if let Some(note) = import_note {
+                        p { class: "text-xs text-gray-400 mt-2", "{note}" }
+                    }
+                }
             }
         }
     }
 }
+
@@ -0,0 +1,388 @@
+// src/views/session.rs
+//
+// "Today's session". Asks `learning::planner` for a timed plan over the active
+// language and walks it one step at a time. Per-word steps (meaning, say it,
+// type it) run inline; a block embeds its drill — grammar quiz, letter sounds,
+// dictation, conjugation — and a reading block serves the next corpus sentence
+// to read and rate. Probe steps log `Source::Probe`, blocks included.
+
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+use crate::components::drills::typing_engine::{accepted_answers, grade_senses, sense_answers};
+use crate::components::{GrammarQuiz, LetterDrill, ListeningTest};
+use crate::ingest::{comprehension, corpus};
+use crate::learner::{
+    as_probe, meaning_evidence, now_ms, reading_evidence, use_learner, word_drill_evidence,
+};
+use crate::learning::planner::{self, Drill, Step, Why};
+use crate::learning::Evidence;
+use crate::models::lemma::Lemmatizer;
+use crate::models::lexicon::{LexEntry, Sense};
+use crate::Route;
+
+use super::conjugate::ConjugationDrill;
+
+const BUDGETS: [u32; 3] = [5, 10, 20];
+
+fn why_badge(why: Why) -> (&'static str, &'static str) {
+    match why {
+        Why::Uncertain => ("probe", "bg-amber-700 text-amber-100"),
+        Why::Due => ("due", "bg-indigo-700 text-indigo-100"),
+        Why::New => ("new", "bg-green-700 text-green-100"),
+    }
+}
+
+#[component]
+pub fn SessionPage() -> Element {
+    let lang = use_context::<Signal<String>>();
+    let learner = use_learner();
+    let mut minutes = use_signal(|| 10u32);
+    let mut steps = use_signal(Vec::<Step>::new);
+    let mut at = use_signal(|| 0usize);
+
+    let lex_res = crate::langpack::use_lexicon(lang);
+    let by_rank = use_memo(move || {
+        let lexicon = lex_res.read().clone().unwrap_or_default();
+        lexicon.by_rank()
+    });
+    let by_word = use_memo(move || {
+        by_rank
+            .read()
+            .iter()
+            .map(|e| (e.word.clone(), e.clone()))
+            .collect::<HashMap<String, LexEntry>>()
+    });
+
+    let replan = {
+        let learner = learner.clone();
+        move || {
+            let mut words: Vec<(u32, String)> = by_word
+                .peek()
+                .values()
+                .map(|e| (e.rank, e.word.clone()))
+                .collect();
+            words.sort();
+            let plan = planner::plan(
+                &learner.model().peek(),
+                &lang.peek(),
+                now_ms(),
+                &words,
+                minutes.peek().saturating_mul(60),
+            );
+            steps.set(plan);
+            at.set(0);
+        }
+    };
+
+    // plan on open, and again when the language or the lexicon changes
+    use_effect({
+        let mut replan = replan.clone();
+        move || {
+            let _ = (lang(), by_word());
+            replan();
+        }
+    });
+
+    let plan = steps();
+    let i = at();
+    let total = plan.len();
+    let left_min = planner::total_secs(plan.get(i..).unwrap_or(&[])).div_ceil(60);
+    let current = plan.get(i).cloned();
+    let l = lang();
+
+    rsx! {
+        div { class: "min-h-screen bg-gray-800 text-white p-8",
+            h1 { style: "font-size:1.5rem; font-weight:600; text-align:center; margin-bottom:1rem;", "Today's session" }
+
+            div { class: "flex justify-center items-center gap-2 mb-6",
+                for m in BUDGETS {
+                    button {
+                        key: "{m}",
+                        class: if minutes() == m { "px-3 py-1.5 rounded text-sm bg-indigo-600 text-white" } else { "px-3 py-1.5 rounded text-sm bg-gray-700 text-gray-300" },
+                        onclick: {
+                            let mut replan = replan.clone();
+                            move |_| {
+                                minutes.set(m);
+                                replan();
+                            }
+                        },
+                        "{m} min"
+                    }
+                }
+                button {
+                    class: "px-3 py-1.5 rounded text-xs bg-gray-700 hover:bg-gray-600 text-gray-200 ml-4",
+                    onclick: {
+                        let mut replan = replan.clone();
+                        move |_| replan()
+                    },
+                    "Re-plan"
+                }
+            }
+
+            div { class: "max-w-xl mx-auto flex flex-col items-center gap-4",
+                if let Some(step) = current {
+                    {
+                        let (badge, badge_class) = why_badge(step.why);
+                        let n = i + 1;
+                        rsx! {
+                            div { class: "flex justify-between w-full text-sm text-gray-400",
+                                span { "Step {n} / {total} · {step.drill.label()}" }
+                                span { class: "px-2 py-0.5 rounded text-xs {badge_class}", "{badge}" }
+                                span { "~{left_min} min left" }
+                            }
+                        }
+                    }
+                    match (step.drill.per_word(), step.word.as_ref().and_then(|w| by_word.read().get(w).cloned())) {
+                        (true, Some(entry)) => rsx! {
+                            WordStep {
+                                key: "{i}",
+                                step: step.clone(),
+                                entry,
+                                lang: l.clone(),
+                                on_done: move |_| at.set(i + 1),
+                            }
+                        },
+                        (true, None) => rsx! {
+                            // the word left the lexicon since it was logged
+                            button {
+                                class: "px-4 py-2 rounded bg-gray-700 hover:bg-gray-600 text-gray-200",
+                                onclick: move |_| at.set(i + 1),
+                                "Skip — word not in the lexicon"
+                            }
+                        },
+                        (false, _) => rsx! {
+                            {
+                                let probe = step.why == Why::Uncertain;
+                                match step.drill {
+                                    Drill::GrammarQuiz => rsx! {
+                                        GrammarQuiz { key: "{l}-{i}", lang: l.clone(), probe }
+                                    },
+                                    Drill::Letters => rsx! {
+                                        LettersStep { key: "{l}-{i}", probe }
+                                    },
+                                    Drill::Listening => rsx! {
+                                        ListeningTest { key: "{l}-{i}", words: by_rank(), lang, probe }
+                                    },
+                                    Drill::Conjugation => rsx! {
+                                        ConjugationDrill {
+                                            key: "{l}-{i}",
+                                            lang: l.clone(),
+                                            verbs: by_rank().into_iter().filter(|e| e.conjugation.is_some()).collect::<Vec<_>>(),
+                                            probe,
+                                        }
+                                    },
+                                    _ => rsx! {
+                                        ReadingStep { key: "{l}-{i}", probe, on_done: move |_| at.set(i + 1) }
+                                    },
+                                }
+                            }
+                            button {
+                                class: "px-4 py-2 rounded bg-gray-700 hover:bg-gray-600 text-gray-200",
+                                onclick: move |_| at.set(i + 1),
+                                "Next step"
+                            }
+                        },
+                    }
+                } else if total == 0 {
+                    div { class: "text-sm text-gray-400 py-10 text-center",
+                        "Nothing to plan yet — this language has no lexicon."
+                    }
+                } else {
+                    div { class: "w-full rounded-lg bg-gray-900/60 border border-gray-700 p-6 flex flex-col items-center gap-2",
+                        div { class: "text-lg font-semibold", "Done for today" }
+                        div { class: "text-sm text-gray-400", "{total} steps. Re-plan for more, or check the dashboard." }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A letter-sound block over the active alphabet.
+#[component]
+fn LettersStep(probe: bool) -> Element {
+    let lang = use_context::<Signal<String>>();
+    let letters = use_resource(move || {
+        let lang_name = lang.read().clone();
+        async move {
+            crate::langpack::alphabet(&lang_name).unwrap_or_else(|msg| {
+                eprintln!("{msg}");
+                Vec::new()
+            })
+        }
+    });
+    let letters_vec = letters.read().clone().unwrap_or_default();
+
+    rsx! {
+        LetterDrill { letters: letters_vec, lang, probe }
+    }
+}
+
+/// A reading block: the sentence the Reading page would serve next, read and
+/// rated by the learner. Logged as Reading evidence, and the sentence is
+/// marked read.
+#[component]
+fn ReadingStep(probe: bool, on_done: EventHandler<()>) -> Element {
+    let lang = use_context::<Signal<String>>();
+    let learner = use_learner();
+    let model = learner.model();
+    let dir = crate::langpack::use_manifest()().direction.as_attr();
+    let lex_res = crate::langpack::use_lexicon(lang);
+
+    // (corpus index, text) of the best unread sentence, once the lexicon is in
+    let picked = use_memo(move || {
+        let lexicon = lex_res.read().clone()?;
+        let l = lang.peek().clone();
+        let reading = corpus::load(&l);
+        let lemmatizer = Lemmatizer::new(&l, &lexicon);
+        let (i, _) = comprehension::pick(&l, &reading, &lemmatizer, &model.peek(), now_ms())?;
+        Some((i, reading.sentences[i].text.clone()))
+    });
+
+    let rate = move |outcome: f32| {
+        let Some((i, text)) = picked.peek().clone() else {
+            return;
+        };
+        let l = lang.peek().clone();
+        let evidence = reading_evidence(&l, &text, outcome);
+        learner.emit_all(if probe { as_probe(evidence) } else { evidence });
+        let mut reading = corpus::load(&l);
+        reading.mark_read(i);
+        corpus::save(&l, &reading);
+        on_done.call(());
+    };
+    let ratings = [(1.0, "Understood it"), (0.5, "Partly"), (0.0, "Lost")];
+
+    rsx! {
+        if let Some((_, text)) = picked() {
+            p { class: "text-sm text-gray-300", "Read this, then say how much of it you understood." }
+            div { class: "w-full p-3 rounded bg-gray-900 text-lg text-center", dir: "{dir}", "{text}" }
+            div { class: "flex gap-2",
+                for (outcome, label) in ratings {
+                    button {
+                        key: "{label}",
+                        class: "px-4 py-2 rounded bg-indigo-600 hover:bg-indigo-500 text-white",
+                        onclick: {
+                            let rate = rate.clone();
+                            move |_| rate(outcome)
+                        },
+                        "{label}"
+                    }
+                }
+            }
+        } else if lex_res.read().is_some() {
+            p { class: "text-sm text-gray-300",
+                "Nothing unread in your reading corpus — import a book or article on the Reading page."
+            }
+            Link {
+                to: Route::ReadingPage {},
+                class: "px-4 py-2 rounded bg-indigo-600 hover:bg-indigo-500 text-white",
+                "Open Reading"
+            }
+        }
+    }
+}
+
+/// One per-word question. Grades with the shared answer grader and logs the
+/// drill's usual evidence (re-tagged as a probe for uncertainty steps).
+#[component]
+fn WordStep(step: Step, entry: LexEntry, lang: String, on_done: EventHandler<()>) -> Element {
+    let learner = use_learner();
+    let mut typed = use_signal(String::new);
+    let mut result = use_signal(|| None::<(f32, String)>);
+    let started = use_hook(now_ms);
+
+    // meanings are graded sense by sense; the L2 side is one "sense" of alternates
+    let one = |glosses: Vec<String>| vec![Sense { glosses, ..Default::default() }];
+    let (label, prompt, senses) = match step.drill {
+        Drill::Recognition => ("What does this mean?", entry.word.clone(), entry.senses.clone()),
+        Drill::Production => ("How do you say…", entry.gloss(), one(accepted_answers(&entry.word))),
+        _ => ("Type it", entry.word.clone(), one(vec![entry.word.clone()])),
+    };
+    let answer = senses
+        .iter()
+        .map(|s| sense_answers(s).join(", "))
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    let submit = move || {
+        if result().is_some() || typed().trim().is_empty() {
+            return;
+        }
+        let (grade, _) = grade_senses(&typed(), &senses);
+        let latency = now_ms().saturating_sub(started) as u32;
+        let evidence: Vec<Evidence> = match step.drill {
+            Drill::Recognition | Drill::Production => meaning_evidence(
+                &lang,
+                step.drill == Drill::Recognition,
+                &entry.word,
+                Some(entry.rank),
+                grade,
+                latency,
+            ),
+            // the word drill logs a clean copy; here a miss is possible, so grade it
+            _ => word_drill_evidence(&lang, &entry.word, Some(entry.rank), latency)
+                .into_iter()
+                .map(|e| Evidence { outcome: grade, ..e })
+                .collect(),
+        };
+        learner.emit_all(if step.why == Why::Uncertain { as_probe(evidence) } else { evidence });
+        result.set(Some((grade, answer.clone())));
+    };
+
+    rsx! {
+        div { class: "w-full rounded-lg bg-gray-900/60 border border-gray-700 p-6 flex flex-col items-center gap-2",
+            span { class: "text-[0.65rem] uppercase tracking-wide text-gray-500", "{label}" }
+            span { class: "text-3xl", "{prompt}" }
+        }
+        input {
+            r#type: "text",
+            value: "{typed}",
+            autocomplete: "off",
+            autocorrect: "off",
+            spellcheck: "false",
+            disabled: result().is_some(),
+            class: "w-full text-center text-2xl bg-transparent border-b-2 border-gray-600 focus:border-indigo-400 outline-none py-2",
+            oninput: move |e: FormEvent| typed.set(e.value()),
+            onmounted: move |e| {
+                let el = e.data();
+                spawn(async move { let _ = el.set_focus(true).await; });
+            },
+            onkeydown: {
+                let mut submit = submit.clone();
+                move |e: KeyboardEvent| {
+                    if e.key().to_string() == "Enter" {
+                        if result().is_some() {
+                            on_done.call(());
+                        } else {
+                            submit();
+                        }
+                    }
+                }
+            },
+        }
+        if let Some((grade, answer)) = result() {
+            if grade >= 0.85 {
+                div { class: "text-sm text-center text-green-400", "✓ correct" }
+            } else {
+                div { class: "text-sm text-center text-red-400", "✗ answer: {answer}" }
+            }
+            button {
+                class: "px-4 py-2 rounded bg-indigo-600 hover:bg-indigo-500 text-white",
+                onclick: move |_| on_done.call(()),
+                "Next"
+            }
+        } else {
+            button {
+                class: "px-4 py-2 rounded bg-green-600 hover:bg-green-500 text-white",
+                onclick: {
+                    let mut submit = submit.clone();
+                    move |_| submit()
+                },
+                "Check"
+            }
+        }
+    }
+}
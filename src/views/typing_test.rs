@@ -1,9 +1,8 @@
 use dioxus::prelude::*;
 
 use crate::{
-    assets::alphabet_json_for,
     components::{Keyboard, TypingTest},
-    models::letter::Letter,
+    langpack,
 };
 
 /// Route wrapper for the typing test. Loads the active language's letters (the
@@ -17,10 +16,9 @@ pub fn TypingPage() -> Element {
     let letters = use_resource(move || {
         let lang_name = lang.read().clone();
         async move {
-            match serde_json::from_str::<Vec<Letter>>(alphabet_json_for(&lang_name)) {
+            match langpack::alphabet(&lang_name) {
                 Ok(v) => v,
-                Err(e) => {
-                    let msg = format!("Failed to parse alphabet for {lang_name}: {e}");
+                Err(msg) => {
                     eprintln!("{msg}");
                     load_error.set(Some(msg));
                     Vec::new()